@@ -0,0 +1,19 @@
+extern crate nvapi;
+
+use nvapi::GSyncDevice;
+
+fn main() {
+    nvapi::initialize().expect("failed to initialize NVAPI");
+
+    for dev in GSyncDevice::get_sync_devices().unwrap_or_default() {
+        println!("Sync device {:?}", dev.board_id());
+        for (gpu, status) in dev.sync_status_all() {
+            println!("  {:?}, {}", gpu.full_name(), if status.is_synced() { "synced" } else { "not synced" });
+            match gpu.core_count_opt() {
+                Ok(Some(cores)) => println!("    {} cores", cores),
+                Ok(None) => println!("    core count not reported"),
+                Err(e) => println!("    core count unavailable: {}", e),
+            }
+        }
+    }
+}
@@ -151,7 +151,7 @@ impl Gpu {
             ram_bank_count: allowable_result_fallback(self.gpu.ram_bank_count(), 0)?,
             ram_partition_count: allowable_result_fallback(self.gpu.ram_partition_count(), 0)?,
             foundry: allowable_result_fallback(self.gpu.foundry(), Foundry::Unknown)?,
-            core_count: self.gpu.core_count()?,
+            core_count: allowable_result_fallback(self.gpu.core_count(), 0)?,
             shader_pipe_count: self.gpu.shader_pipe_count()?,
             shader_sub_pipe_count: self.gpu.shader_sub_pipe_count()?,
             base_clocks: self.gpu.clock_frequencies(ClockFrequencyType::Base)?,
@@ -8,7 +8,8 @@ pub use gpu::*;
 pub use nvapi::{
     Status, Result,
     sys,
-    initialize, unload, driver_version, interface_version, error_message
+    initialize, unload, is_available, driver_version, interface_version, error_message,
+    system_info, SystemInfo,
 };
 
 pub fn allowable_result_fallback<T>(v: nvapi::Result<T>, fallback: T) -> nvapi::Result<T> {
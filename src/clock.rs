@@ -1,6 +1,6 @@
 use std::collections::BTreeMap;
 use std::convert::Infallible;
-use std::{iter, slice};
+use std::{iter, ops, slice};
 use crate::sys::gpu::{clock, power};
 use crate::sys;
 use serde::{Serialize, Deserialize};
@@ -11,8 +11,55 @@ pub use sys::gpu::clock::PublicClockId as ClockDomain;
 pub use sys::gpu::clock::private::ClockLockMode;
 pub use sys::gpu::power::private::PerfFlags;
 
-impl RawConversion for clock::NV_GPU_CLOCK_FREQUENCIES {
+/// Clock frequencies of the domains present on a GPU, as returned by `PhysicalGpu::clock_frequencies`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Debug, Clone, Default, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub struct ClockFrequencies(pub BTreeMap<ClockDomain, Kilohertz>);
+
+impl ClockFrequencies {
+    pub fn graphics(&self) -> Option<Kilohertz> {
+        self.0.get(&ClockDomain::Graphics).cloned()
+    }
+
+    pub fn memory(&self) -> Option<Kilohertz> {
+        self.0.get(&ClockDomain::Memory).cloned()
+    }
+
+    pub fn video(&self) -> Option<Kilohertz> {
+        self.0.get(&ClockDomain::Video).cloned()
+    }
+
+    pub fn processor(&self) -> Option<Kilohertz> {
+        self.0.get(&ClockDomain::Processor).cloned()
+    }
+}
+
+impl ops::Deref for ClockFrequencies {
     type Target = BTreeMap<ClockDomain, Kilohertz>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<BTreeMap<ClockDomain, Kilohertz>> for ClockFrequencies {
+    fn from(map: BTreeMap<ClockDomain, Kilohertz>) -> Self {
+        ClockFrequencies(map)
+    }
+}
+
+impl IntoIterator for ClockFrequencies {
+    type Item = (ClockDomain, Kilohertz);
+    type IntoIter = <BTreeMap<ClockDomain, Kilohertz> as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl RawConversion for clock::NV_GPU_CLOCK_FREQUENCIES {
+    type Target = ClockFrequencies;
     type Error = Infallible;
 
     fn convert_raw(&self) -> Result<Self::Target, Self::Error> {
@@ -21,7 +68,8 @@ impl RawConversion for clock::NV_GPU_CLOCK_FREQUENCIES {
             .map(|id| (id, &self.domain[id.raw() as usize]))
             .filter(|&(_, ref clock)| clock.bIsPresent.get())
             .map(|(id, clock)| (id, Kilohertz(clock.frequency)))
-            .collect()
+            .collect::<BTreeMap<_, _>>()
+            .into()
         )
     }
 }
@@ -307,6 +355,30 @@ pub struct VfpCurve {
     pub memory: Vec<(usize, VfpEntry<Kilohertz>)>,
 }
 
+impl VfpCurve {
+    /// Finds the graphics point closest to the given operating voltage, preferring the point closest
+    /// to `frequency` among points at the same voltage.
+    pub fn graphics_point_near(&self, frequency: Kilohertz, voltage: Microvolts) -> Option<VfPoint> {
+        self.graphics.iter()
+            .map(|&(index, ref entry)| VfPoint {
+                index,
+                frequency: entry.frequency.into(),
+                voltage: entry.voltage,
+            })
+            .min_by_key(|point| (point.voltage.0.abs_diff(voltage.0), point.frequency.0.abs_diff(frequency.0)))
+    }
+}
+
+/// A graphics point of the VF curve.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct VfPoint {
+    /// Index into the VF curve, as used by `VfpCurve::graphics`
+    pub index: usize,
+    pub frequency: Kilohertz,
+    pub voltage: Microvolts,
+}
+
 impl RawConversion for power::private::NV_GPU_CLOCK_CLIENT_CLK_VF_POINTS_STATUS {
     type Target = VfpCurve;
     type Error = sys::ArgumentRangeError;
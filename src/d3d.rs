@@ -0,0 +1,16 @@
+//! Direct3D interop, for APIs that only work with a device registered through `register_device`.
+
+use std::os::raw::c_void;
+use log::trace;
+use crate::sys::{self, dx};
+
+/// Registers a D3D device with NVAPI, unlocking the device-scoped queries.
+///
+/// # Safety
+///
+/// `device` must point to a live D3D device, i.e. an `IUnknown` such as an `ID3D11Device` or
+/// `ID3D12Device`.
+pub unsafe fn register_device(device: *mut c_void) -> crate::Result<()> {
+    trace!("d3d::register_device({:?})", device);
+    sys::status_result(dx::NvAPI_D3D_RegisterDevice(device as *mut _))
+}
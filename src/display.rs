@@ -0,0 +1,322 @@
+use std::convert::Infallible;
+use std::ptr;
+use log::trace;
+use serde::{Serialize, Deserialize};
+use crate::sys::{self, status_result};
+use crate::sys::dispcontrol::{self, NV_HDR_COLOR_DATA};
+use crate::types::{DisplayId, Percentage, Range, RawConversion};
+
+pub use sys::dispcontrol::{HdrCmd, HdrMode, ColorFormat, DynamicRange, Bpc, HdrCapabilitiesFlags, MonitorCapsType, MonitorCapsGeneric};
+pub use sys::dispcontrol::{DisplayConfigFlags, Scaling, TimingOverride, SpanningOrientation};
+
+/// Digital vibrance levels of a display.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Copy, Clone, Default, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub struct Vibrance {
+    pub current: i32,
+    pub default: i32,
+    pub range: Range<i32>,
+}
+
+impl Vibrance {
+    /// The level at the given percentage of the supported range.
+    pub fn level_at(&self, percentage: Percentage) -> i32 {
+        self.range.min + (self.range.max - self.range.min) * percentage.0.min(100) as i32 / 100
+    }
+}
+
+impl RawConversion for dispcontrol::private::NV_DISPLAY_DVC_INFO_EX {
+    type Target = Vibrance;
+    type Error = Infallible;
+
+    fn convert_raw(&self) -> Result<Self::Target, Self::Error> {
+        Ok(Vibrance {
+            current: self.currentLevel,
+            default: self.defaultLevel,
+            range: Range {
+                min: self.minLevel,
+                max: self.maxLevel,
+            },
+        })
+    }
+}
+
+/// The capability blocks a display advertises, see `Display::monitor_capabilities`.
+///
+/// Blocks the display or connection does not provide, such as the HDMI data blocks of a
+/// DisplayPort monitor, are `None`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct MonitorCapabilities {
+    /// Variable refresh rate and G-SYNC capabilities.
+    pub generic: Option<MonitorCapsGeneric>,
+    /// The raw HDMI video capability data block, `NV_MONITOR_CAPS_VCDB`.
+    pub vcdb: Option<u8>,
+    /// The raw HDMI vendor specific data block, `NV_MONITOR_CAPS_VSDB`.
+    pub vsdb: Option<Vec<u8>>,
+    /// HDR static metadata capabilities.
+    pub hdr: Option<HdrCapabilitiesFlags>,
+}
+
+impl MonitorCapabilities {
+    /// Whether the display advertises variable refresh rate, be it a G-SYNC module or
+    /// G-SYNC Compatible/FreeSync.
+    pub fn supports_vrr(&self) -> bool {
+        self.generic.map(|caps| caps.intersects(MonitorCapsGeneric::SUPPORT_VRR | MonitorCapsGeneric::IS_TRUE_GSYNC)).unwrap_or(false)
+    }
+
+    /// Whether the display accepts HDR10, i.e. the ST2084 (PQ) EOTF.
+    pub fn supports_hdr(&self) -> bool {
+        self.hdr.map(|caps| caps.contains(HdrCapabilitiesFlags::ST2084_EOTF)).unwrap_or(false)
+    }
+}
+
+/// A display target of a `DisplayConfigPath`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Copy, Clone)]
+pub struct DisplayConfigTarget {
+    pub display_id: DisplayId,
+    /// Windows CCD target ID, only used for non-NVIDIA adapters
+    pub target_id: u32,
+    /// Rotation, scaling, refresh rate and timing of the target
+    pub details: dispcontrol::NV_DISPLAYCONFIG_PATH_ADVANCED_TARGET_INFO,
+}
+
+/// A source (desktop surface) and the displays it is shown on, see `Display::get_config`.
+///
+/// Clones list more than one target.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct DisplayConfigPath {
+    pub source_id: u32,
+    /// Resolution and desktop position of the source
+    pub source_mode: dispcontrol::NV_DISPLAYCONFIG_SOURCE_MODE_INFO,
+    pub targets: Vec<DisplayConfigTarget>,
+    pub non_nvidia_adapter: bool,
+}
+
+impl DisplayConfigPath {
+    pub fn position(&self) -> (i32, i32) {
+        (self.source_mode.position.x, self.source_mode.position.y)
+    }
+
+    pub fn resolution(&self) -> (u32, u32) {
+        (self.source_mode.resolution.width, self.source_mode.resolution.height)
+    }
+
+    pub fn is_gdi_primary(&self) -> bool {
+        self.source_mode.bGDIPrimary()
+    }
+}
+
+/// The display ID of the OS primary display.
+///
+/// Returns `NvidiaDeviceNotFound` if the primary display is not driven by an NVIDIA GPU.
+pub fn primary_display_id() -> crate::Result<DisplayId> {
+    trace!("primary_display_id()");
+    let mut display_id = 0;
+    status_result(unsafe { dispcontrol::NvAPI_DISP_GetGDIPrimaryDisplayId(&mut display_id) })
+        .map(|_| DisplayId(display_id))
+}
+
+/// Access to per-display settings, addressed by display ID.
+#[derive(Debug)]
+pub struct Display;
+
+impl Display {
+    /// Whether the display is the OS primary display.
+    pub fn is_primary(display_id: impl Into<DisplayId>) -> crate::Result<bool> {
+        match primary_display_id() {
+            Ok(primary) => Ok(primary == display_id.into()),
+            Err(sys::Status::NvidiaDeviceNotFound) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Gets or sets the HDR configuration of a display.
+    ///
+    /// The version and command of `data` are filled in; with `HdrCmd::Get` the current
+    /// configuration is written back into `data`.
+    pub fn hdr_color_control(display_id: impl Into<DisplayId>, cmd: HdrCmd, data: &mut NV_HDR_COLOR_DATA) -> crate::Result<()> {
+        let display_id = display_id.into();
+        trace!("display.hdr_color_control({}, {:?})", display_id, cmd);
+        data.v1.version = dispcontrol::NV_HDR_COLOR_DATA_VER;
+        data.v1.cmd = cmd.raw();
+        status_result(unsafe { dispcontrol::NvAPI_Disp_HdrColorControl(display_id.0, data) })
+    }
+
+    pub fn hdr_color_data(display_id: impl Into<DisplayId>) -> crate::Result<NV_HDR_COLOR_DATA> {
+        let mut data = NV_HDR_COLOR_DATA::zeroed();
+        Self::hdr_color_control(display_id, HdrCmd::Get, &mut data)
+            .map(|_| data)
+    }
+
+    pub fn hdr_mode(display_id: impl Into<DisplayId>) -> crate::Result<HdrMode> {
+        let display_id = display_id.into();
+        trace!("display.hdr_mode({})", display_id);
+        Self::hdr_color_data(display_id)
+            .and_then(|data| HdrMode::from_raw(data.hdrMode).map_err(From::from))
+    }
+
+    /// Switches the HDR output mode, e.g. `HdrMode::Uhda` for HDR10 or `HdrMode::Off`.
+    ///
+    /// BT.2020 mastering metadata is sent along with the mode, the output color format, range and
+    /// depth are left to the driver.
+    pub fn set_hdr_mode(display_id: impl Into<DisplayId>, mode: HdrMode) -> crate::Result<()> {
+        let display_id = display_id.into();
+        trace!("display.set_hdr_mode({}, {:?})", display_id, mode);
+        let mut data = NV_HDR_COLOR_DATA::zeroed();
+        data.v1.hdrMode = mode.raw();
+        data.v1.static_metadata_descriptor_id = dispcontrol::NV_STATIC_METADATA_TYPE_1;
+        data.v1.mastering_display_data = dispcontrol::NV_HDR_MASTERING_DISPLAY_DATA::bt2020();
+        data.hdrColorFormat = dispcontrol::NV_COLOR_FORMAT_DEFAULT;
+        data.hdrDynamicRange = dispcontrol::NV_DYNAMIC_RANGE_AUTO;
+        data.hdrBpc = dispcontrol::NV_BPC_DEFAULT;
+        Self::hdr_color_control(display_id, HdrCmd::Set, &mut data)
+    }
+
+    /// Returns the raw capability data of the given type, or `None` if the display does not provide it.
+    pub fn monitor_capabilities_raw(display_id: impl Into<DisplayId>, caps_type: MonitorCapsType) -> crate::Result<Option<dispcontrol::NV_MONITOR_CAPABILITIES>> {
+        let display_id = display_id.into();
+        trace!("display.monitor_capabilities_raw({}, {:?})", display_id, caps_type);
+        let mut data = dispcontrol::NV_MONITOR_CAPABILITIES::zeroed();
+        data.version = dispcontrol::NV_MONITOR_CAPABILITIES_VER;
+        data.infoType = caps_type.raw();
+        match status_result(unsafe { dispcontrol::NvAPI_DISP_GetMonitorCapabilities(display_id.0, &mut data) }) {
+            Ok(()) if data.bIsValidInfo != 0 => Ok(Some(data)),
+            Ok(()) | Err(sys::Status::NotSupported) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn hdr_capabilities(display_id: impl Into<DisplayId>) -> crate::Result<dispcontrol::NV_HDR_CAPABILITIES> {
+        let display_id = display_id.into();
+        trace!("display.hdr_capabilities({})", display_id);
+        let mut data = dispcontrol::NV_HDR_CAPABILITIES::zeroed();
+        data.version = dispcontrol::NV_HDR_CAPABILITIES_VER;
+        status_result(unsafe { dispcontrol::NvAPI_Disp_GetHdrCapabilities(display_id.0, &mut data) })
+            .map(|_| data)
+    }
+
+    /// Queries the VRR, HDMI data block and HDR capabilities of a display.
+    pub fn monitor_capabilities(display_id: impl Into<DisplayId>) -> crate::Result<MonitorCapabilities> {
+        let display_id = display_id.into();
+        trace!("display.monitor_capabilities({})", display_id);
+        let hdr = match Self::hdr_capabilities(display_id) {
+            Ok(data) => Some(HdrCapabilitiesFlags::from_bits_truncate(data.flags)),
+            Err(sys::Status::NotSupported) => None,
+            Err(e) => return Err(e),
+        };
+
+        Ok(MonitorCapabilities {
+            generic: Self::monitor_capabilities_raw(display_id, MonitorCapsType::Generic)?
+                .map(|data| MonitorCapsGeneric::from_bits_truncate(data.data[0] as _)),
+            vcdb: Self::monitor_capabilities_raw(display_id, MonitorCapsType::HdmiVcdb)?
+                .map(|data| data.data[0]),
+            vsdb: Self::monitor_capabilities_raw(display_id, MonitorCapsType::HdmiVsdb)?
+                .map(|data| data.data.to_vec()),
+            hdr,
+        })
+    }
+
+    /// The current arrangement of all displays: desktop sources, their positions and the
+    /// displays showing them.
+    pub fn get_config() -> crate::Result<Vec<DisplayConfigPath>> {
+        trace!("display.get_config()");
+        let mut count = 0;
+        status_result(unsafe { dispcontrol::NvAPI_DISP_GetDisplayConfig(&mut count, ptr::null_mut()) })?;
+        if count == 0 {
+            return Ok(Vec::new())
+        }
+
+        // the second call fills in the target count of each path
+        let mut path = dispcontrol::NV_DISPLAYCONFIG_PATH_INFO::zeroed();
+        path.version = dispcontrol::NV_DISPLAYCONFIG_PATH_INFO_VER;
+        let mut paths = vec![path; count as usize];
+        status_result(unsafe { dispcontrol::NvAPI_DISP_GetDisplayConfig(&mut count, paths.as_mut_ptr()) })?;
+        paths.truncate(count as usize);
+
+        // the third call fills in the buffers the paths point into, which must stay in place
+        let mut details = dispcontrol::NV_DISPLAYCONFIG_PATH_ADVANCED_TARGET_INFO::zeroed();
+        details.version = dispcontrol::NV_DISPLAYCONFIG_PATH_ADVANCED_TARGET_INFO_VER;
+        let mut source_modes = vec![dispcontrol::NV_DISPLAYCONFIG_SOURCE_MODE_INFO::zeroed(); paths.len()];
+        let mut details: Vec<Vec<_>> = paths.iter().map(|path| vec![details; path.targetInfoCount as usize]).collect();
+        let mut targets: Vec<Vec<_>> = details.iter_mut().map(|details| details.iter_mut().map(|details| {
+            let mut target = dispcontrol::NV_DISPLAYCONFIG_PATH_TARGET_INFO::zeroed();
+            target.details = details;
+            target
+        }).collect()).collect();
+        for ((path, targets), source_mode) in paths.iter_mut().zip(&mut targets).zip(&mut source_modes) {
+            path.targetInfo = targets.as_mut_ptr();
+            path.sourceModeInfo = source_mode;
+        }
+        status_result(unsafe { dispcontrol::NvAPI_DISP_GetDisplayConfig(&mut count, paths.as_mut_ptr()) })?;
+
+        Ok(paths.iter().zip(&targets).zip(&source_modes).map(|((path, targets), source_mode)| DisplayConfigPath {
+            source_id: path.sourceId,
+            source_mode: *source_mode,
+            targets: targets.iter().map(|target| DisplayConfigTarget {
+                display_id: DisplayId(target.displayId),
+                target_id: target.targetId,
+                details: unsafe { *target.details },
+            }).collect(),
+            non_nvidia_adapter: path.IsNonNVIDIAAdapter(),
+        }).collect())
+    }
+
+    /// Applies a display arrangement as returned by `get_config()`.
+    ///
+    /// Pass `DisplayConfigFlags::VALIDATE_ONLY` to check a configuration without a modeset.
+    pub fn set_config(paths: &[DisplayConfigPath], flags: DisplayConfigFlags) -> crate::Result<()> {
+        trace!("display.set_config({:?})", flags);
+        let mut source_modes: Vec<_> = paths.iter().map(|path| path.source_mode).collect();
+        let mut details: Vec<Vec<_>> = paths.iter()
+            .map(|path| path.targets.iter().map(|target| target.details).collect())
+            .collect();
+        let mut targets: Vec<Vec<_>> = paths.iter().zip(&mut details).map(|(path, details)| {
+            path.targets.iter().zip(details.iter_mut()).map(|(target, details)| {
+                let mut raw = dispcontrol::NV_DISPLAYCONFIG_PATH_TARGET_INFO::zeroed();
+                raw.displayId = target.display_id.0;
+                raw.targetId = target.target_id;
+                raw.details = details;
+                raw
+            }).collect()
+        }).collect();
+        let mut raw_paths: Vec<_> = paths.iter().zip(&mut targets).zip(&mut source_modes).map(|((path, targets), source_mode)| {
+            let mut raw = dispcontrol::NV_DISPLAYCONFIG_PATH_INFO::zeroed();
+            raw.version = dispcontrol::NV_DISPLAYCONFIG_PATH_INFO_VER;
+            raw.sourceId = path.source_id;
+            raw.targetInfoCount = targets.len() as u32;
+            raw.targetInfo = targets.as_mut_ptr();
+            raw.sourceModeInfo = source_mode;
+            raw.flags = path.non_nvidia_adapter as u32;
+            raw
+        }).collect();
+
+        status_result(unsafe { dispcontrol::NvAPI_DISP_SetDisplayConfig(raw_paths.len() as u32, raw_paths.as_mut_ptr(), flags.bits()) })
+    }
+
+    pub fn vibrance(display_id: impl Into<DisplayId>) -> crate::Result<Vibrance> {
+        let display_id = display_id.into();
+        trace!("display.vibrance({})", display_id);
+        let mut data = dispcontrol::private::NV_DISPLAY_DVC_INFO_EX::zeroed();
+        data.version = dispcontrol::private::NV_DISPLAY_DVC_INFO_EX_VER;
+        status_result(unsafe { dispcontrol::private::NvAPI_GetDVCInfoEx(Default::default(), display_id.0, &mut data) })
+            .and_then(|_| data.convert_raw().map_err(From::from))
+    }
+
+    /// Sets the digital vibrance level, which must lie within the range reported by `vibrance()`.
+    pub fn set_vibrance(display_id: impl Into<DisplayId>, level: i32) -> crate::Result<()> {
+        let display_id = display_id.into();
+        trace!("display.set_vibrance({}, {})", display_id, level);
+        let range = Self::vibrance(display_id)?.range;
+        if level < range.min || level > range.max {
+            return Err(sys::Status::InvalidArgument)
+        }
+
+        let mut data = dispcontrol::private::NV_DISPLAY_DVC_INFO_EX::zeroed();
+        data.version = dispcontrol::private::NV_DISPLAY_DVC_INFO_EX_VER;
+        data.currentLevel = level;
+        status_result(unsafe { dispcontrol::private::NvAPI_SetDVCLevelEx(Default::default(), display_id.0, &mut data) })
+    }
+}
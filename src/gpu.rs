@@ -1,27 +1,53 @@
-use std::{ptr, fmt};
+use std::{iter, ptr, fmt};
+use std::sync::{Arc, OnceLock, RwLock};
+use std::collections::BTreeMap;
 use std::convert::Infallible;
 use log::trace;
 use serde::{Serialize, Deserialize};
-use crate::sys::gpu::{self, pstate, clock, power, cooler, thermal, display};
+use crate::sys::gpu::{self, pstate, clock, power, cooler, fan, thermal, display, ecc, illumination};
 use crate::sys::{self, driverapi, i2c};
-use crate::types::{Kibibytes, KilohertzDelta, Kilohertz2Delta, Microvolts, Percentage, Percentage1000, RawConversion};
-use crate::thermal::CoolerLevel;
-use crate::clock::{ClockDomain, VfpMask};
-use crate::pstate::PState;
+use crate::types::{Celsius, CelsiusShifted, DisplayId, Kibibytes, Kilohertz, KilohertzDelta, Kilohertz2Delta, Microvolts, MicrovoltsDelta, Percentage, Percentage1000, RawConversion, Watts};
+use crate::thermal::{CoolerLevel, FanCoolerControl, ThermalHeadroom, ThermalSensor, ThermalTarget, ThermalController};
+use crate::clock::{ClockDomain, ClockFrequencies, PerfFlags, VfpMask, VfPoint};
+use crate::pstate::{PState, UtilizationDomains, VoltageDomain};
 
 #[derive(Debug)]
-pub struct PhysicalGpu(sys::handles::NvPhysicalGpuHandle);
+pub struct PhysicalGpu(sys::handles::NvPhysicalGpuHandle, GpuNames);
+
+/// Names don't change while a handle is valid, so they are only queried once.
+#[derive(Debug, Default)]
+struct GpuNames {
+    short: OnceLock<String>,
+    full: OnceLock<String>,
+}
+
+fn cached_name<F: FnOnce() -> sys::Result<String>>(cell: &OnceLock<String>, query: F) -> sys::Result<String> {
+    if let Some(name) = cell.get() {
+        return Ok(name.clone())
+    }
+
+    // Errors aren't cached, a later call asks the driver again
+    let name = query()?;
+    Ok(cell.get_or_init(|| name).clone())
+}
 
 unsafe impl Send for PhysicalGpu { }
+unsafe impl Sync for PhysicalGpu { }
 
-pub use sys::gpu::{SystemType, PerformanceDecreaseReason};
+pub use sys::gpu::{SystemType, PerformanceDecreaseReason, ArchitectureId, WorkstationFeatures};
+pub use sys::gpu::{HdcpFuseState, HdcpKeySource, HdcpKeySourceState};
 pub use sys::gpu::private::{RamType, RamMaker, Foundry, VendorId as Vendor};
 pub use sys::gpu::clock::ClockFrequencyType;
-pub use sys::gpu::display::{ConnectedIdsFlags, DisplayIdsFlags, MonitorConnectorType};
-pub type ClockFrequencies = <sys::gpu::clock::NV_GPU_CLOCK_FREQUENCIES as RawConversion>::Target;
+pub use sys::gpu::display::{ConnectedIdsFlags, DisplayIdsFlags, MonitorConnectorType, DpLinkRate, DpLaneCount, DpColorFormat, DpBpc};
+pub use sys::gpu::ecc::EccConfiguration;
+pub use sys::gpu::illumination::IlluminationAttribute;
 pub type Utilizations = <pstate::NV_GPU_DYNAMIC_PSTATES_INFO_EX as RawConversion>::Target;
 
 impl PhysicalGpu {
+    pub fn new(handle: sys::handles::NvPhysicalGpuHandle) -> Self {
+        PhysicalGpu(handle, Default::default())
+    }
+
     pub fn handle(&self) -> &sys::handles::NvPhysicalGpuHandle {
         &self.0
     }
@@ -32,10 +58,24 @@ impl PhysicalGpu {
         let mut len = 0;
         match unsafe { gpu::NvAPI_EnumPhysicalGPUs(&mut handles, &mut len) } {
             sys::status::NVAPI_NVIDIA_DEVICE_NOT_FOUND => Ok(Vec::new()),
-            status => sys::status_result(status).map(move |_| handles[..len as usize].iter().cloned().map(PhysicalGpu).collect()),
+            status => sys::status_result(status).map(move |_| handles[..len as usize].iter().cloned().map(PhysicalGpu::new).collect()),
         }
     }
 
+    /// The logical GPU this physical GPU is part of.
+    pub fn logical_gpu(&self) -> sys::Result<LogicalGpu> {
+        trace!("gpu.logical_gpu()");
+        let mut handle = Default::default();
+        sys::status_result(unsafe { gpu::NvAPI_GetLogicalGPUFromPhysicalGPU(self.0, &mut handle) })
+            .map(|_| LogicalGpu(handle))
+    }
+
+    /// Enumerates all physical GPUs, keeping only those matching `pred`.
+    pub fn enumerate_where<F: Fn(&Self) -> bool>(pred: F) -> sys::Result<Vec<Self>> {
+        trace!("gpu.enumerate_where()");
+        Self::enumerate().map(|gpus| gpus.into_iter().filter(|gpu| pred(gpu)).collect())
+    }
+
     pub fn tachometer(&self) -> sys::Result<u32> {
         trace!("gpu.tachometer()");
         let mut out = 0;
@@ -45,22 +85,74 @@ impl PhysicalGpu {
         }
     }
 
+    /// The number of fans, from the fan cooler APIs where supported and the tachometer otherwise.
+    pub fn fan_count(&self) -> sys::Result<usize> {
+        trace!("gpu.fan_count()");
+        match self.fan_coolers_status() {
+            Ok(coolers) => return Ok(coolers.len()),
+            Err(sys::Status::NotSupported) | Err(sys::Status::NoImplementation) => (),
+            Err(e) => return Err(e),
+        }
+
+        match self.tachometer() {
+            Ok(_) => Ok(1),
+            Err(sys::Status::NotSupported) => Ok(0),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// The speed of the fan at `index`, counting up to `fan_count()`.
+    ///
+    /// Uses the fan cooler APIs where supported and the tachometer otherwise, which only reports
+    /// a single fan. Returns 0 for a stopped fan, and for fan 0 of a GPU without a fan so that it
+    /// can be displayed as a single value. Other indices out of range are an `InvalidArgument`.
+    pub fn fan_rpm(&self, index: usize) -> sys::Result<u32> {
+        trace!("gpu.fan_rpm({})", index);
+        match self.fan_coolers_status() {
+            Ok(coolers) => return match coolers.get(index) {
+                Some(cooler) => Ok(cooler.rpm),
+                None if index == 0 => Ok(0),
+                None => Err(sys::Status::InvalidArgument),
+            },
+            Err(sys::Status::NotSupported) | Err(sys::Status::NoImplementation) => (),
+            Err(e) => return Err(e),
+        }
+
+        if index != 0 {
+            return Err(sys::Status::InvalidArgument)
+        }
+
+        match self.tachometer() {
+            Err(sys::Status::NotSupported) => Ok(0),
+            res => res,
+        }
+    }
+
     pub fn short_name(&self) -> sys::Result<String> {
         trace!("gpu.short_name()");
-        let mut str = sys::types::short_string();
-        unsafe {
-            sys::status_result(gpu::private::NvAPI_GPU_GetShortName(self.0, &mut str))
-                .and_then(|_| str.convert_raw().map_err(Into::into))
-        }
+        cached_name(&self.1.short, || {
+            let mut str = sys::types::short_string();
+            unsafe {
+                sys::status_result(gpu::private::NvAPI_GPU_GetShortName(self.0, &mut str))
+                    .and_then(|_| str.convert_raw().map_err(Into::into))
+            }
+        })
     }
 
     pub fn full_name(&self) -> sys::Result<String> {
         trace!("gpu.full_name()");
-        let mut str = sys::types::short_string();
-        unsafe {
-            sys::status_result(gpu::NvAPI_GPU_GetFullName(self.0, &mut str))
-                .and_then(|_| str.convert_raw().map_err(Into::into))
-        }
+        cached_name(&self.1.full, || {
+            let mut str = sys::types::short_string();
+            unsafe {
+                sys::status_result(gpu::NvAPI_GPU_GetFullName(self.0, &mut str))
+                    .and_then(|_| str.convert_raw().map_err(Into::into))
+            }
+        })
+    }
+
+    pub fn model(&self) -> sys::Result<GpuModel> {
+        trace!("gpu.model()");
+        self.full_name().map(|name| GpuModel::parse(&name))
     }
 
     pub fn vbios_version_string(&self) -> sys::Result<String> {
@@ -72,12 +164,34 @@ impl PhysicalGpu {
         }
     }
 
+    /// The numeric video BIOS revision, the `xx.xx.xx.xx` part of `vbios_version_string()`.
+    pub fn vbios_revision(&self) -> sys::Result<u32> {
+        trace!("gpu.vbios_revision()");
+        let mut value = 0;
+        unsafe {
+            sys::status_result(gpu::NvAPI_GPU_GetVbiosRevision(self.0, &mut value))
+                .map(|_| value)
+        }
+    }
+
+    /// The OEM revision of the video BIOS, the `yy` part of `vbios_version_string()`.
+    ///
+    /// Fails with the driver's status, typically `NotSupported`, on boards without one.
+    pub fn vbios_oem_revision(&self) -> sys::Result<u32> {
+        trace!("gpu.vbios_oem_revision()");
+        let mut value = 0;
+        unsafe {
+            sys::status_result(gpu::NvAPI_GPU_GetVbiosOEMRevision(self.0, &mut value))
+                .map(|_| value)
+        }
+    }
+
     pub fn driver_model(&self) -> sys::Result<DriverModel> {
         trace!("gpu.driver_model()");
         let mut value = 0;
         unsafe {
             sys::status_result(gpu::private::NvAPI_GetDriverModel(self.0, &mut value))
-                .map(|_| DriverModel::new(value))
+                .map(|_| DriverModel::from_raw(value))
         }
     }
 
@@ -99,8 +213,23 @@ impl PhysicalGpu {
         }
     }
 
-    pub fn board_number(&self) -> sys::Result<[u8; 0x10]> {
+    /// The board serial number stored in the InfoROM, as printable ASCII.
+    ///
+    /// Decoding stops at the first NUL, and other non-printable bytes are dropped.
+    pub fn board_number(&self) -> sys::Result<String> {
         trace!("gpu.board_number()");
+        self.board_number_raw().map(|raw| {
+            let serial: String = raw.iter()
+                .take_while(|&&b| b != 0)
+                .filter(|b| b.is_ascii_graphic() || **b == b' ')
+                .map(|&b| b as char)
+                .collect();
+            serial.trim().into()
+        })
+    }
+
+    pub fn board_number_raw(&self) -> sys::Result<[u8; 0x10]> {
+        trace!("gpu.board_number_raw()");
         let mut data = gpu::NV_BOARD_INFO::zeroed();
         data.version = gpu::NV_BOARD_INFO_VER;
         unsafe {
@@ -109,6 +238,83 @@ impl PhysicalGpu {
         }
     }
 
+    /// The Windows adapter `LUID` of the GPU, as reported by e.g. `IDXGIAdapter::GetDesc`.
+    ///
+    /// The bytes are in memory order, so `u64::from_le_bytes` yields `HighPart << 32 | LowPart`.
+    pub fn adapter_luid(&self) -> sys::Result<[u8; 8]> {
+        trace!("gpu.adapter_luid()");
+        let mut luid = [0u8; 8];
+        unsafe {
+            sys::status_result(gpu::NvAPI_GPU_GetAdapterIdFromPhysicalGpu(self.0, luid.as_mut_ptr() as *mut _))
+                .map(|_| luid)
+        }
+    }
+
+    /// Whether the GPU is a Quadro/RTX workstation board rather than a GeForce.
+    pub fn is_quadro(&self) -> sys::Result<bool> {
+        trace!("gpu.is_quadro()");
+        let mut status = 0;
+        unsafe {
+            sys::status_result(gpu::NvAPI_GPU_GetQuadroStatus(self.0, &mut status))
+                .map(|_| status != 0)
+        }
+    }
+
+    /// The workstation features currently configured on the GPU.
+    pub fn workstation_features(&self) -> sys::Result<WorkstationFeatures> {
+        trace!("gpu.workstation_features()");
+        let mut configured = 0;
+        unsafe {
+            sys::status_result(gpu::NvAPI_GPU_WorkstationFeatureQuery(self.0, &mut configured, ptr::null_mut()))
+                .map(|_| WorkstationFeatures::from_bits_truncate(configured))
+        }
+    }
+
+    /// Whether the GPU is capable of HDCP, i.e. has its HDCP fuse enabled and keys present.
+    ///
+    /// Fails with `NotSupported` on GPUs without HDCP.
+    pub fn hdcp_status(&self) -> sys::Result<HdcpStatus> {
+        trace!("gpu.hdcp_status()");
+        let mut data = gpu::NV_GPU_GET_HDCP_SUPPORT_STATUS::zeroed();
+        data.version = gpu::NV_GPU_GET_HDCP_SUPPORT_STATUS_VER;
+
+        sys::status_result(unsafe { gpu::NvAPI_GPU_GetHDCPSupportStatus(self.0, &mut data) })
+            .and_then(|_| data.convert_raw().map_err(From::from))
+    }
+
+    pub fn bus_type(&self) -> sys::Result<GpuBusType> {
+        trace!("gpu.bus_type()");
+        let mut ty = gpu::NVAPI_GPU_BUS_TYPE_UNDEFINED;
+        unsafe {
+            sys::status_result(gpu::NvAPI_GPU_GetBusType(self.0, &mut ty))
+                .map(|_| GpuBusType::from_raw(ty as u32))
+        }
+    }
+
+    /// The number of PCIe lanes currently in use downstream from the GPU.
+    pub fn pcie_width(&self) -> sys::Result<u32> {
+        trace!("gpu.pcie_width()");
+        let mut width = 0;
+        unsafe {
+            sys::status_result(gpu::NvAPI_GPU_GetCurrentPCIEDownstreamWidth(self.0, &mut width))
+                .map(|_| width)
+        }
+    }
+
+    /// Compares the current PCIe link width against `max_lanes`, the width the card is built for.
+    ///
+    /// NVAPI does not document a way to query the maximum width, so it must be supplied by the
+    /// caller. Returns `NotSupported` for GPUs that are not attached through PCI Express, such
+    /// as integrated parts.
+    pub fn pcie_link_health(&self, max_lanes: u32) -> sys::Result<PcieLinkHealth> {
+        trace!("gpu.pcie_link_health({})", max_lanes);
+        if self.bus_type()? != GpuBusType::PciExpress {
+            return Err(sys::Status::NotSupported)
+        }
+
+        self.pcie_width().map(|current| PcieLinkHealth::new(current, max_lanes))
+    }
+
     pub fn system_type(&self) -> sys::Result<SystemType> {
         trace!("gpu.system_type()");
         let mut ty = gpu::NV_SYSTEM_TYPE_UNKNOWN;
@@ -118,6 +324,31 @@ impl PhysicalGpu {
         }
     }
 
+    /// Whether the GPU is a notebook GPU, see `SystemType::is_mobile`.
+    pub fn is_mobile(&self) -> sys::Result<bool> {
+        self.system_type().map(|ty| ty.is_mobile())
+    }
+
+    pub fn arch_info(&self) -> sys::Result<ArchInfo> {
+        trace!("gpu.arch_info()");
+        let mut data = gpu::NV_GPU_ARCH_INFO::zeroed();
+        data.version = gpu::NV_GPU_ARCH_INFO_VER;
+        sys::status_result(unsafe { gpu::NvAPI_GPU_GetArchInfo(self.0, &mut data) })
+            .and_then(|_| data.convert_raw().map_err(From::from))
+    }
+
+    /// The CUDA compute capability as `(major, minor)`, derived from the architecture and
+    /// implementation IDs so that CUDA need not be loaded.
+    ///
+    /// Returns `Ok(None)` for GPUs that are not CUDA capable or whose chip is not known.
+    pub fn cuda_compute_capability(&self) -> sys::Result<Option<(u32, u32)>> {
+        trace!("gpu.cuda_compute_capability()");
+        let mut data = gpu::NV_GPU_ARCH_INFO::zeroed();
+        data.version = gpu::NV_GPU_ARCH_INFO_VER;
+        sys::status_result(unsafe { gpu::NvAPI_GPU_GetArchInfo(self.0, &mut data) })
+            .map(|_| cuda_compute_capability(data.architecture, data.implementation))
+    }
+
     pub fn core_count(&self) -> sys::Result<u32> {
         trace!("gpu.core_count()");
         let mut value = 0;
@@ -127,6 +358,11 @@ impl PhysicalGpu {
         }
     }
 
+    /// Like `core_count()`, but `None` when the GPU or driver mode does not report a core count.
+    pub fn core_count_opt(&self) -> sys::Result<Option<u32>> {
+        optional(self.core_count())
+    }
+
     pub fn shader_pipe_count(&self) -> sys::Result<u32> {
         trace!("gpu.shader_pipe_count()");
         let mut value = 0;
@@ -145,6 +381,37 @@ impl PhysicalGpu {
         }
     }
 
+    /// Combines the core and shader pipe counts into one report.
+    ///
+    /// Counts the driver does not provide are `None`; the SM count is derived from the core count
+    /// and the cores per SM of the GPU architecture.
+    pub fn shader_info(&self) -> sys::Result<ShaderInfo> {
+        trace!("gpu.shader_info()");
+        let cuda_cores = self.core_count_opt()?;
+        let cores_per_sm = self.cuda_compute_capability().ok().flatten()
+            .and_then(|(major, minor)| cuda_cores_per_sm(major, minor));
+        Ok(ShaderInfo {
+            cuda_cores,
+            shader_pipes: optional(self.shader_pipe_count())?,
+            shader_sub_pipes: optional(self.shader_sub_pipe_count())?,
+            sm_count: cuda_cores.and_then(|cores| cores_per_sm.map(|per_sm| cores / per_sm)),
+        })
+    }
+
+    /// Queries the memory type, maker and layout in one go.
+    ///
+    /// Values the driver does not provide are `None`.
+    pub fn memory_config(&self) -> sys::Result<MemoryConfig> {
+        trace!("gpu.memory_config()");
+        Ok(MemoryConfig {
+            ram_type: optional(self.ram_type())?,
+            ram_maker: optional(self.ram_maker())?,
+            bus_width: optional(self.ram_bus_width())?,
+            bank_count: optional(self.ram_bank_count())?,
+            partition_count: optional(self.ram_partition_count())?,
+        })
+    }
+
     pub fn ram_type(&self) -> sys::Result<RamType> {
         trace!("gpu.ram_type()");
         let mut value = gpu::private::NV_GPU_RAM_UNKNOWN;
@@ -163,6 +430,19 @@ impl PhysicalGpu {
         }
     }
 
+    /// Effective memory bandwidth in GB/s at the current memory clock.
+    ///
+    /// Returns `None` if the bus width, memory type or memory clock can't be determined.
+    pub fn memory_bandwidth(&self) -> Option<f64> {
+        trace!("gpu.memory_bandwidth()");
+        let bus_width = self.ram_bus_width().ok()?;
+        let ram_type = self.ram_type().ok()?;
+        let clock = self.clock_frequencies(ClockFrequencyType::Current).ok()?
+            .memory()?;
+
+        memory_bandwidth(bus_width, ram_type, clock)
+    }
+
     pub fn ram_bus_width(&self) -> sys::Result<u32> {
         trace!("gpu.ram_bus_width()");
         let mut value = 0;
@@ -191,11 +471,17 @@ impl PhysicalGpu {
     }
 
     pub fn foundry(&self) -> sys::Result<Foundry> {
-        trace!("gpu.foundry()");
+        self.foundry_raw()
+            .and_then(|value| gpu::private::Foundry::from_raw(value).map_err(From::from))
+    }
+
+    /// The undecoded foundry ID, for foundries newer than the known `Foundry` values.
+    pub fn foundry_raw(&self) -> sys::Result<gpu::private::NV_GPU_FOUNDRY> {
+        trace!("gpu.foundry_raw()");
         let mut value = gpu::private::NV_GPU_FOUNDRY_UNKNOWN;
         unsafe {
             sys::status_result(gpu::private::NvAPI_GPU_GetFoundry(self.0, &mut value))
-                .and_then(|_| gpu::private::Foundry::from_raw(value).map_err(From::from))
+                .map(|_| value)
         }
     }
 
@@ -208,6 +494,111 @@ impl PhysicalGpu {
             .and_then(|_| data.convert_raw().map_err(Into::into))
     }
 
+    /// The size of the framebuffer on the board itself.
+    ///
+    /// Unlike `memory_info().dedicated`, which is what the driver makes available to applications,
+    /// this is the raw hardware size.
+    pub fn physical_framebuffer_kb(&self) -> sys::Result<Kibibytes> {
+        trace!("gpu.physical_framebuffer_kb()");
+        let mut size = 0;
+        unsafe {
+            sys::status_result(gpu::NvAPI_GPU_GetPhysicalFrameBufferSize(self.0, &mut size))
+                .map(|_| Kibibytes(size))
+        }
+    }
+
+    /// The addressable framebuffer size, i.e. the physical framebuffer plus system memory
+    /// dedicated to the GPU.
+    pub fn virtual_framebuffer_kb(&self) -> sys::Result<Kibibytes> {
+        trace!("gpu.virtual_framebuffer_kb()");
+        let mut size = 0;
+        unsafe {
+            sys::status_result(gpu::NvAPI_GPU_GetVirtualFrameBufferSize(self.0, &mut size))
+                .map(|_| Kibibytes(size))
+        }
+    }
+
+    pub fn ecc_status(&self) -> sys::Result<EccStatus> {
+        trace!("gpu.ecc_status()");
+        let mut data = ecc::NV_GPU_ECC_STATUS_INFO::zeroed();
+        data.version = ecc::NV_GPU_ECC_STATUS_INFO_VER;
+        sys::status_result(unsafe { ecc::NvAPI_GPU_GetECCStatusInfo(self.0, &mut data) })
+            .and_then(|_| data.convert_raw().map_err(From::from))
+    }
+
+    pub fn ecc_errors(&self) -> sys::Result<EccErrors> {
+        trace!("gpu.ecc_errors()");
+        let mut data = ecc::NV_GPU_ECC_ERROR_INFO::zeroed();
+        data.version = ecc::NV_GPU_ECC_ERROR_INFO_VER;
+        sys::status_result(unsafe { ecc::NvAPI_GPU_GetECCErrorInfo(self.0, &mut data) })
+            .and_then(|_| data.convert_raw().map_err(From::from))
+    }
+
+    pub fn reset_ecc_errors(&self, current: bool, aggregate: bool) -> sys::Result<()> {
+        trace!("gpu.reset_ecc_errors({:?}, {:?})", current, aggregate);
+        sys::status_result(unsafe { ecc::NvAPI_GPU_ResetECCErrorInfo(self.0, current as u8, aggregate as u8) })
+    }
+
+    pub fn ecc_configuration(&self) -> sys::Result<EccConfigurationInfo> {
+        trace!("gpu.ecc_configuration()");
+        let mut data = ecc::NV_GPU_ECC_CONFIGURATION_INFO::zeroed();
+        data.version = ecc::NV_GPU_ECC_CONFIGURATION_INFO_VER;
+        sys::status_result(unsafe { ecc::NvAPI_GPU_GetECCConfigurationInfo(self.0, &mut data) })
+            .and_then(|_| data.convert_raw().map_err(From::from))
+    }
+
+    pub fn illumination_supported(&self, zone: IlluminationAttribute) -> sys::Result<bool> {
+        trace!("gpu.illumination_supported({:?})", zone);
+        let mut data = illumination::NV_GPU_QUERY_ILLUMINATION_SUPPORT_PARM::zeroed();
+        data.version = illumination::NV_GPU_QUERY_ILLUMINATION_SUPPORT_PARM_VER;
+        data.hPhysicalGpu = self.0;
+        data.Attribute = zone.raw();
+        sys::status_result(unsafe { illumination::NvAPI_GPU_QueryIlluminationSupport(&mut data) })
+            .map(|_| data.bSupported != 0)
+    }
+
+    /// The current brightness of each LED zone the GPU supports.
+    pub fn illumination_zones(&self) -> sys::Result<BTreeMap<IlluminationAttribute, Percentage>> {
+        trace!("gpu.illumination_zones()");
+        let mut zones = BTreeMap::new();
+        for zone in IlluminationAttribute::values() {
+            if !self.illumination_supported(zone)? {
+                continue
+            }
+
+            let mut data = illumination::NV_GPU_GET_ILLUMINATION_PARM::zeroed();
+            data.version = illumination::NV_GPU_GET_ILLUMINATION_PARM_VER;
+            data.hPhysicalGpu = self.0;
+            data.Attribute = zone.raw();
+            sys::status_result(unsafe { illumination::NvAPI_GPU_GetIllumination(&mut data) })?;
+            zones.insert(zone, Percentage::from_raw(data.Value)?);
+        }
+
+        Ok(zones)
+    }
+
+    /// Sets the brightness of a single LED zone, leaving all other zones untouched.
+    ///
+    /// Returns `InvalidArgument` for a brightness above 100% and `NotSupported` if the GPU
+    /// lacks the zone.
+    pub fn set_illumination(&self, zone: IlluminationAttribute, brightness: Percentage) -> sys::Result<()> {
+        trace!("gpu.set_illumination({:?}, {:?})", zone, brightness);
+        if brightness.0 > 100 {
+            return Err(sys::Status::InvalidArgument)
+        }
+
+        if !self.illumination_supported(zone)? {
+            return Err(sys::Status::NotSupported)
+        }
+
+        let mut data = illumination::NV_GPU_SET_ILLUMINATION_PARM::zeroed();
+        data.version = illumination::NV_GPU_SET_ILLUMINATION_PARM_VER;
+        data.hPhysicalGpu = self.0;
+        data.Attribute = zone.raw();
+        data.Value = brightness.0;
+        sys::status_result(unsafe { illumination::NvAPI_GPU_SetIllumination(&mut data) })
+    }
+
     pub fn clock_frequencies(&self, clock_type: ClockFrequencyType) -> sys::Result<ClockFrequencies> {
         trace!("gpu.clock_frequencies({:?})", clock_type);
         let mut clocks = clock::NV_GPU_CLOCK_FREQUENCIES::zeroed();
@@ -218,6 +609,26 @@ impl PhysicalGpu {
             .and_then(|_| clocks.convert_raw().map_err(Into::into))
     }
 
+    /// Whether the GPU appears to run without the full driver, e.g. in a basic display fallback mode.
+    ///
+    /// NVAPI has no direct query for this, so a GPU is considered degraded when it reports no base
+    /// clocks or no performance states. Other queries will usually fail with `NotSupported` then.
+    pub fn is_degraded(&self) -> sys::Result<bool> {
+        trace!("gpu.is_degraded()");
+        match self.clock_frequencies(ClockFrequencyType::Base) {
+            Ok(ref clocks) if clocks.values().all(|f| f.0 == 0) => return Ok(true),
+            Ok(_) => (),
+            Err(sys::Status::NotSupported) => return Ok(true),
+            Err(e) => return Err(e),
+        }
+
+        match self.pstates() {
+            Ok(pstates) => Ok(pstates.pstates.is_empty()),
+            Err(sys::Status::NotSupported) => Ok(true),
+            Err(e) => Err(e),
+        }
+    }
+
     pub fn current_pstate(&self) -> sys::Result<PState> {
         trace!("gpu.current_pstate()");
         let mut pstate = 0;
@@ -261,6 +672,53 @@ impl PhysicalGpu {
         sys::status_result(unsafe { pstate::private::NvAPI_GPU_SetPstates20(self.0, &info) })
     }
 
+    /// Applies a single clock offset, e.g. `+150 MHz` on `P0` graphics.
+    ///
+    /// The offset is checked against the delta range the driver reports for that pstate clock.
+    pub fn set_pstate_clock_offset(&self, pstate: PState, domain: ClockDomain, offset: KilohertzDelta) -> sys::Result<()> {
+        trace!("gpu.set_pstate_clock_offset({:?}, {:?}, {:?})", pstate, domain, offset);
+        let pstates = self.pstates()?;
+        let clock = pstates.pstates.iter()
+            .filter(|p| p.id == pstate)
+            .flat_map(|p| p.clocks.iter())
+            .find(|c| c.domain() == domain)
+            .ok_or(sys::Status::InvalidArgument)?;
+
+        let range = clock.frequency_delta().range;
+        if !pstates.editable || !clock.editable() || offset < range.min || offset > range.max {
+            return Err(sys::Status::InvalidArgument)
+        }
+
+        self.set_pstates(Some((pstate, domain, offset)).into_iter())
+    }
+
+    /// Applies a core voltage offset through the Pstates20 overvolt settings, e.g. `+50 mV`.
+    ///
+    /// The offset is clamped to the range the driver reports; the applied offset is returned.
+    /// Returns `NotSupported` if the GPU has no editable core overvolt entry.
+    pub fn set_voltage_offset(&self, offset: MicrovoltsDelta) -> sys::Result<MicrovoltsDelta> {
+        trace!("gpu.set_voltage_offset({:?})", offset);
+        let pstates = self.pstates()?;
+        let core = pstates.overvolt.iter()
+            .find(|v| v.voltage_domain == VoltageDomain::Core)
+            .ok_or(sys::Status::NotSupported)?;
+        if !pstates.editable || !core.editable {
+            return Err(sys::Status::NotSupported)
+        }
+
+        let range = core.voltage_delta.range;
+        let offset = MicrovoltsDelta(offset.0.max(range.min.0).min(range.max.0));
+
+        let mut info = pstate::NV_GPU_PERF_PSTATES20_INFO::zeroed();
+        info.version = pstate::NV_GPU_PERF_PSTATES20_INFO_VER;
+        info.numVoltages = 1;
+        info.voltages[0].domainId = VoltageDomain::Core.raw();
+        info.voltages[0].voltDelta_uV.value = offset.0;
+
+        sys::status_result(unsafe { pstate::private::NvAPI_GPU_SetPstates20(self.0, &info) })
+            .map(|_| offset)
+    }
+
     pub fn dynamic_pstates_info(&self) -> sys::Result<Utilizations> {
         trace!("gpu.dynamic_pstates_info()");
         let mut info = pstate::NV_GPU_DYNAMIC_PSTATES_INFO_EX::zeroed();
@@ -270,6 +728,39 @@ impl PhysicalGpu {
             .and_then(|_| info.convert_raw().map_err(From::from))
     }
 
+    /// Utilization of the GPU, frame buffer, video engine and bus domains.
+    pub fn utilizations(&self) -> sys::Result<UtilizationDomains> {
+        trace!("gpu.utilizations()");
+        let mut info = pstate::NV_GPU_DYNAMIC_PSTATES_INFO_EX::zeroed();
+        info.version = pstate::NV_GPU_DYNAMIC_PSTATES_INFO_EX_VER;
+
+        sys::status_result(unsafe { pstate::NvAPI_GPU_GetDynamicPstatesInfoEx(self.0, &mut info) })
+            .map(|_| UtilizationDomains::from_raw(&info))
+    }
+
+    /// A snapshot of the commonly monitored sensors, for polling once per update.
+    ///
+    /// Each reading is taken on a best-effort basis and is `None` when its query fails.
+    pub fn sensors(&self) -> GpuSensors {
+        trace!("gpu.sensors()");
+        let clocks = self.clock_frequencies(ClockFrequencyType::Current).ok();
+        let fan_level = match self.fan_coolers_status() {
+            Ok(coolers) => coolers.first().map(|cooler| cooler.level),
+            Err(_) => self.cooler_settings(None).ok()
+                .and_then(|coolers| coolers.first().map(|cooler| cooler.current_level)),
+        };
+
+        GpuSensors {
+            temperatures: self.thermal_sensors().ok(),
+            fan_rpm: self.fan_rpm(0).ok(),
+            fan_level,
+            power: self.power_usage().ok().and_then(|usage| usage.first().cloned()),
+            graphics_clock: clocks.as_ref().and_then(|clocks| clocks.graphics()),
+            memory_clock: clocks.as_ref().and_then(|clocks| clocks.memory()),
+            utilizations: self.utilizations().ok(),
+        }
+    }
+
     /// Private and deprecated, use `dynamic_pstates_info()` instead.
     pub fn usages(&self) -> sys::Result<<clock::private::NV_USAGES_INFO as RawConversion>::Target> {
         trace!("gpu.usages()");
@@ -317,6 +808,33 @@ impl PhysicalGpu {
         sys::status_result(unsafe { clock::private::NvAPI_GPU_ClockClientClkVfPointsSetControl(self.0, &data) })
     }
 
+    /// Applies per-point frequency deltas to the V/F curve (aka `NvAPI_GPU_SetClockBoostTable`).
+    ///
+    /// Each delta is checked against `vfp_ranges()` for its clock domain, and points outside of
+    /// `mask` are rejected so that masked-out entries are left untouched.
+    pub fn set_vfp_curve<I: IntoIterator<Item=(ClockDomain, usize, Kilohertz2Delta)>>(&self, mask: [u32; 4], points: I) -> sys::Result<()> {
+        trace!("gpu.set_vfp_curve({:?})", mask);
+        let ranges = self.vfp_ranges()?;
+        let data = clock::private::NV_GPU_CLOCK_CLIENT_CLK_VF_POINTS_CONTROL::zeroed();
+        let (mut clocks, mut memory) = (Vec::new(), Vec::new());
+        for (domain, i, delta) in points {
+            let range = ranges.iter().find(|r| r.domain == domain)
+                .map(|r| r.range)
+                .ok_or(sys::Status::InvalidArgument)?;
+            if delta < range.min || delta > range.max {
+                return Err(sys::Status::InvalidArgument)
+            }
+
+            match domain {
+                ClockDomain::Graphics if i < data.gpuDeltas.len() && VfpMask::get_bit(&mask, i) => clocks.push((i, delta)),
+                ClockDomain::Memory if i < data.memDeltas.len() => memory.push((i, delta)),
+                _ => return Err(sys::Status::InvalidArgument),
+            }
+        }
+
+        self.set_vfp_table(mask, clocks.into_iter(), memory.into_iter())
+    }
+
     pub fn vfp_ranges(&self) -> sys::Result<<clock::private::NV_GPU_CLOCK_CLIENT_CLK_DOMAINS_INFO as RawConversion>::Target> {
         trace!("gpu.vfp_ranges()");
         let mut data = clock::private::NV_GPU_CLOCK_CLIENT_CLK_DOMAINS_INFO::zeroed();
@@ -369,6 +887,44 @@ impl PhysicalGpu {
             .and_then(|_| data.convert_raw().map_err(From::from))
     }
 
+    /// The highest graphics clock the GPU can reach, in MHz.
+    ///
+    /// This is the top of the VF curve where available, and the boost clock otherwise.
+    pub fn max_boost_clock_mhz(&self) -> sys::Result<u32> {
+        trace!("gpu.max_boost_clock_mhz()");
+        let curve_max = self.vfp_mask()
+            .and_then(|mask| self.vfp_curve(mask.mask))
+            .map(|curve| curve.graphics.iter().map(|(_, entry)| Kilohertz::from(entry.frequency)).max());
+        let max = match curve_max {
+            Ok(Some(max)) => max,
+            Ok(None) | Err(sys::Status::NotSupported) | Err(sys::Status::NoImplementation) =>
+                self.clock_frequencies(ClockFrequencyType::Boost)?
+                    .graphics()
+                    .ok_or(sys::Status::NotSupported)?,
+            Err(e) => return Err(e),
+        };
+
+        Ok(max.0 / 1000)
+    }
+
+    /// The point of the VF curve the GPU is currently operating at.
+    ///
+    /// The curve has no notion of an active point, so this is the point closest to the current core
+    /// voltage and graphics clock.
+    pub fn current_vf_point(&self) -> sys::Result<VfPoint> {
+        trace!("gpu.current_vf_point()");
+        let mask = self.vfp_mask()?;
+        let curve = self.vfp_curve(mask.mask)?;
+        let voltage = self.core_voltage()?;
+        let frequency = self.clock_frequencies(ClockFrequencyType::Current)?
+            .graphics()
+            .ok_or(sys::Status::NotSupported)?;
+
+        curve.graphics_point_near(frequency, voltage)
+            .ok_or(sys::Status::NotSupported)
+    }
+
+    /// The current core voltage; convert to `Millivolts` for whole mV.
     pub fn core_voltage(&self) -> sys::Result<<power::private::NV_GPU_CLIENT_VOLT_RAILS_STATUS as RawConversion>::Target> {
         trace!("gpu.core_voltage()");
         let mut data = power::private::NV_GPU_CLIENT_VOLT_RAILS_STATUS::zeroed();
@@ -387,10 +943,12 @@ impl PhysicalGpu {
             .and_then(|_| data.convert_raw().map_err(From::from))
     }
 
+    /// Sets the core voltage boost, keeping the remaining fields of the current control settings.
     pub fn set_core_voltage_boost(&self, value: Percentage) -> sys::Result<()> {
         trace!("gpu.set_core_voltage_boost({:?})", value);
         let mut data = power::private::NV_GPU_CLIENT_VOLT_RAILS_CONTROL::zeroed();
         data.version = power::private::NV_GPU_CLIENT_VOLT_RAILS_CONTROL_VER;
+        sys::status_result(unsafe { power::private::NvAPI_GPU_ClientVoltRailsGetControl(self.0, &mut data) })?;
         data.percent = value.0;
 
         sys::status_result(unsafe { power::private::NvAPI_GPU_ClientVoltRailsSetControl(self.0, &data) })
@@ -405,6 +963,26 @@ impl PhysicalGpu {
             .and_then(|_| data.convert_raw().map_err(From::from))
     }
 
+    /// Approximate board power draw, given the rated board power (TDP) of the card.
+    ///
+    /// NVAPI only reports power relative to the default power limit, so the rated power has to
+    /// come from the caller, e.g. the board specification. Returns `None` when the GPU reports no
+    /// power readings or no default limit.
+    pub fn power_draw_watts(&self, board_power: Watts) -> sys::Result<Option<Watts>> {
+        trace!("gpu.power_draw_watts({:?})", board_power);
+        let usage = match optional(self.power_usage())? {
+            Some(usage) => usage.first().cloned(),
+            None => return Ok(None),
+        };
+        let default_limit = optional(self.power_limit_info())?
+            .and_then(|info| info.entries.first().map(|entry| entry.default_limit))
+            .filter(|limit| limit.0 != 0);
+
+        Ok(usage.and_then(|usage| default_limit.map(|limit|
+            Watts(board_power.0 * usage.0 as f32 / limit.0 as f32)
+        )))
+    }
+
     pub fn power_limit_info(&self) -> sys::Result<<power::private::NV_GPU_POWER_INFO as RawConversion>::Target> {
         trace!("gpu.power_limit_info()");
         let mut data = power::private::NV_GPU_POWER_INFO::zeroed();
@@ -423,15 +1001,22 @@ impl PhysicalGpu {
             .and_then(|_| data.convert_raw().map_err(From::from))
     }
 
+    /// Sets the power limit of each policy entry, e.g. `Percentage1000(110000)` for 110%.
+    ///
+    /// The current status is read back first so that the other fields are written unchanged, and
+    /// each value is clamped to the range reported by `power_limit_info()`.
     pub fn set_power_limit<I: Iterator<Item=Percentage1000>>(&self, values: I) -> sys::Result<()> {
         trace!("gpu.set_power_limit()");
+        let info = self.power_limit_info()?;
+
         let mut data = power::private::NV_GPU_POWER_STATUS::zeroed();
         data.version = power::private::NV_GPU_POWER_STATUS_VER;
-        //data.valid = 1;
-        for (entry, v) in data.entries.iter_mut().zip(values) {
+        sys::status_result(unsafe { power::private::NvAPI_GPU_ClientPowerPoliciesGetStatus(self.0, &mut data) })?;
+
+        let count = (data.count as usize).min(data.entries.len());
+        for ((entry, info), v) in data.entries[..count].iter_mut().zip(&info.entries).zip(values) {
             trace!("gpu.set_power_limit({:?})", v);
-            entry.power = v.0;
-            data.count += 1;
+            entry.power = v.0.max(info.range.min.0).min(info.range.max.0);
         }
 
         sys::status_result(unsafe { power::private::NvAPI_GPU_ClientPowerPoliciesSetStatus(self.0, &data) })
@@ -446,6 +1031,22 @@ impl PhysicalGpu {
             .and_then(|_| data.convert_raw().map_err(From::from))
     }
 
+    /// The index, target and controller chip of each thermal sensor.
+    pub fn thermal_sensor_labels(&self) -> sys::Result<Vec<(u32, ThermalTarget, ThermalController)>> {
+        trace!("gpu.thermal_sensor_labels()");
+        self.thermal_settings(None)
+            .map(|sensors| sensors.into_iter().enumerate()
+                .map(|(i, sensor)| (i as u32, sensor.target, sensor.controller))
+                .collect()
+            )
+    }
+
+    /// The current temperature of each sensor, see `thermal_settings()` for the full sensor details.
+    pub fn thermal_sensors(&self) -> sys::Result<Vec<ThermalSensor>> {
+        self.thermal_settings(None)
+            .map(|sensors| sensors.into_iter().map(From::from).collect())
+    }
+
     pub fn thermal_limit_info(&self) -> sys::Result<<thermal::private::NV_GPU_THERMAL_INFO as RawConversion>::Target> {
         trace!("gpu.thermal_limit_info()");
         let mut data = thermal::private::NV_GPU_THERMAL_INFO::zeroed();
@@ -479,6 +1080,33 @@ impl PhysicalGpu {
         sys::status_result(unsafe { thermal::private::NvAPI_GPU_ClientThermalPoliciesSetStatus(self.0, &data) })
     }
 
+    /// Sets the temperature target of every thermal policy, e.g. `Celsius(83)`.
+    ///
+    /// The current status is read back first so that the other fields are written unchanged, and
+    /// the target is clamped to the range reported by `thermal_limit_info()`. With `priority` the
+    /// temperature target takes precedence over the power limit.
+    pub fn set_thermal_target(&self, target: Celsius, priority: bool) -> sys::Result<()> {
+        trace!("gpu.set_thermal_target({:?}, {:?})", target, priority);
+        let (_, info) = self.thermal_limit_info()?;
+
+        let mut data = thermal::private::NV_GPU_CLIENT_THERMAL_POLICIES_STATUS::zeroed();
+        data.version = thermal::private::NV_GPU_CLIENT_THERMAL_POLICIES_STATUS_VER;
+        sys::status_result(unsafe { thermal::private::NvAPI_GPU_ClientThermalPoliciesGetStatus(self.0, &mut data) })?;
+
+        let target = CelsiusShifted::from(target);
+        let count = (data.flags as usize).min(data.entries.len());
+        for (entry, info) in data.entries[..count].iter_mut().zip(&info) {
+            entry.value = target.0.max(info.temperature_range.min.0).min(info.temperature_range.max.0) as _;
+            if priority {
+                entry.flags |= thermal::private::NV_GPU_CLIENT_THERMAL_POLICIES_FLAGS_PRIORITY;
+            } else {
+                entry.flags &= !thermal::private::NV_GPU_CLIENT_THERMAL_POLICIES_FLAGS_PRIORITY;
+            }
+        }
+
+        sys::status_result(unsafe { thermal::private::NvAPI_GPU_ClientThermalPoliciesSetStatus(self.0, &data) })
+    }
+
     pub fn cooler_settings(&self, index: Option<u32>) -> sys::Result<<cooler::private::NV_GPU_COOLER_SETTINGS as RawConversion>::Target> {
         trace!("gpu.cooler_settings({:?})", index);
         let mut data = cooler::private::NV_GPU_COOLER_SETTINGS::zeroed();
@@ -498,7 +1126,24 @@ impl PhysicalGpu {
             entry.currentPolicy = level.policy.raw();
         }
 
-        sys::status_result(unsafe { cooler::private::NvAPI_GPU_SetCoolerLevels(self.0, index.unwrap_or(cooler::private::NVAPI_COOLER_TARGET_ALL as _), &mut data) })
+        sys::status_result(unsafe { cooler::private::NvAPI_GPU_SetCoolerLevels(self.0, index.unwrap_or(cooler::private::NVAPI_COOLER_TARGET_ALL as _), &data) })
+    }
+
+    /// Sets a single cooler to `level` percent under `policy`, e.g. `CoolerPolicy::Manual` to lock the fan speed.
+    ///
+    /// `level` is clamped to 100%, and `cooler_index` is checked against the coolers reported by `cooler_settings`.
+    /// Only this cooler is targeted; `restore_cooler_settings(&[cooler_index])` reverts it.
+    pub fn set_cooler_level(&self, cooler_index: u32, policy: crate::thermal::CoolerPolicy, level: u32) -> sys::Result<()> {
+        trace!("gpu.set_cooler_level({:?}, {:?}, {:?})", cooler_index, policy, level);
+        let count = self.cooler_settings(None)?.len();
+        if cooler_index as usize >= count {
+            return Err(sys::Status::InvalidArgument)
+        }
+
+        self.set_cooler_levels(Some(cooler_index), iter::once(CoolerLevel {
+            level: Percentage(level.min(100)),
+            policy,
+        }))
     }
 
     pub fn restore_cooler_settings(&self, index: &[u32]) -> sys::Result<()> {
@@ -536,6 +1181,45 @@ impl PhysicalGpu {
         sys::status_result(unsafe { cooler::private::NvAPI_GPU_RestoreCoolerPolicyTable(self.0, ptr, index.len() as u32, policy.raw()) })
     }
 
+    /// Fan status on Turing and later GPUs, where `cooler_settings` is no longer supported.
+    pub fn fan_coolers_status(&self) -> sys::Result<<fan::private::NV_GPU_CLIENT_FAN_COOLERS_STATUS as RawConversion>::Target> {
+        trace!("gpu.fan_coolers_status()");
+        let mut data = fan::private::NV_GPU_CLIENT_FAN_COOLERS_STATUS::zeroed();
+        data.version = fan::private::NV_GPU_CLIENT_FAN_COOLERS_STATUS_VER;
+
+        sys::status_result(unsafe { fan::private::NvAPI_GPU_ClientFanCoolersGetStatus(self.0, &mut data) })
+            .and_then(|_| data.convert_raw().map_err(From::from))
+    }
+
+    pub fn fan_coolers_control(&self) -> sys::Result<<fan::private::NV_GPU_CLIENT_FAN_COOLERS_CONTROL as RawConversion>::Target> {
+        trace!("gpu.fan_coolers_control()");
+        let mut data = fan::private::NV_GPU_CLIENT_FAN_COOLERS_CONTROL::zeroed();
+        data.version = fan::private::NV_GPU_CLIENT_FAN_COOLERS_CONTROL_VER;
+
+        sys::status_result(unsafe { fan::private::NvAPI_GPU_ClientFanCoolersGetControl(self.0, &mut data) })
+            .and_then(|_| data.convert_raw().map_err(From::from))
+    }
+
+    /// Updates the level and mode of the given fan coolers, leaving all others untouched.
+    pub fn set_fan_coolers_control<I: Iterator<Item=FanCoolerControl>>(&self, values: I) -> sys::Result<()> {
+        trace!("gpu.set_fan_coolers_control()");
+        // round-trip the current control so that unknown fields keep their driver values
+        let mut data = fan::private::NV_GPU_CLIENT_FAN_COOLERS_CONTROL::zeroed();
+        data.version = fan::private::NV_GPU_CLIENT_FAN_COOLERS_CONTROL_VER;
+        sys::status_result(unsafe { fan::private::NvAPI_GPU_ClientFanCoolersGetControl(self.0, &mut data) })?;
+
+        for v in values {
+            trace!("gpu.set_fan_cooler_control({:?})", v);
+            let entry = data.entries[..data.count as usize].iter_mut()
+                .find(|entry| entry.coolerId == v.cooler_id)
+                .ok_or(sys::Status::InvalidArgument)?;
+            entry.level = v.level.0;
+            entry.controlMode = v.mode.raw();
+        }
+
+        sys::status_result(unsafe { fan::private::NvAPI_GPU_ClientFanCoolersSetControl(self.0, &data) })
+    }
+
     pub fn perf_info(&self) -> sys::Result<<power::private::NV_GPU_PERF_INFO as RawConversion>::Target> {
         trace!("gpu.perf_info()");
         let mut data = power::private::NV_GPU_PERF_INFO::zeroed();
@@ -581,6 +1265,7 @@ impl PhysicalGpu {
             .and_then(|_| data.convert_raw().map_err(From::from))
     }
 
+    /// The reasons the GPU is currently throttled; iterate the result to list them one by one.
     pub fn performance_decrease(&self) -> sys::Result<PerformanceDecreaseReason> {
         trace!("gpu.performance_decrease()");
 
@@ -590,6 +1275,36 @@ impl PhysicalGpu {
             .map(|_| PerformanceDecreaseReason::from_bits_truncate(data))
     }
 
+    /// Whether the GPU is currently slowed down to protect it from overheating.
+    pub fn thermal_throttle_active(&self) -> sys::Result<bool> {
+        trace!("gpu.thermal_throttle_active()");
+        let decrease = self.performance_decrease()?;
+        let limited = self.perf_status()
+            .map(|status| status.limits.contains(PerfFlags::THERMAL_LIMIT))
+            .unwrap_or(false);
+
+        Ok(decrease.contains(PerformanceDecreaseReason::THERMAL_PROTECTION) || limited)
+    }
+
+    /// The core temperature compared against the first policy of `thermal_limit()`.
+    ///
+    /// Returns `None` when the GPU reports no core sensor or no thermal policy.
+    pub fn thermal_headroom(&self) -> sys::Result<Option<ThermalHeadroom>> {
+        trace!("gpu.thermal_headroom()");
+        let current = self.thermal_sensors()?.into_iter()
+            .find(|sensor| sensor.target == ThermalTarget::Gpu)
+            .map(|sensor| sensor.current);
+        let limit = self.thermal_limit()?.first()
+            .map(|limit| Celsius::from(limit.value));
+        let throttling = self.thermal_throttle_active()?;
+
+        Ok(current.and_then(|current| limit.map(|limit| ThermalHeadroom {
+            current,
+            limit,
+            throttling,
+        })))
+    }
+
     pub fn display_ids_all(&self) -> sys::Result<Vec<<display::NV_GPU_DISPLAYIDS as RawConversion>::Target>> {
         trace!("gpu.display_ids_all()");
         let mut count = 0;
@@ -605,6 +1320,104 @@ impl PhysicalGpu {
             .and_then(|_| data.into_iter().map(|v| v.convert_raw().map_err(From::from)).collect())
     }
 
+    /// The physical outputs of the GPU, with the displays each one drives, ordered by output ID.
+    ///
+    /// GPUs without any display outputs return an empty list.
+    pub fn connectors(&self) -> sys::Result<Vec<Connector>> {
+        trace!("gpu.connectors()");
+        let displays = match self.display_ids_all() {
+            Err(sys::Status::NvidiaDeviceNotFound) => return Ok(Vec::new()),
+            displays => displays?,
+        };
+
+        let mut outputs: BTreeMap<u32, (ConnectorType, Vec<DisplayId>)> = BTreeMap::new();
+        for display in displays {
+            let mut handle = Default::default();
+            let mut output_id = 0;
+            sys::status_result(unsafe { sys::system::NvAPI_SYS_GetGpuAndOutputIdFromDisplayId(display.display_id.0, &mut handle, &mut output_id) })?;
+            outputs.entry(output_id)
+                .or_insert_with(|| (display.connector_type(), Vec::new()))
+                .1.push(display.display_id);
+        }
+
+        Ok(outputs.into_iter().enumerate().map(|(index, (output_id, (connector_type, display_ids)))| Connector {
+            index,
+            connector_type,
+            output_id,
+            display_ids,
+        }).collect())
+    }
+
+    /// The DisplayPort link details of a display.
+    ///
+    /// Returns `Status::NotSupported` for displays that are not driven over DisplayPort.
+    pub fn display_port_info(&self, display_id: DisplayId) -> sys::Result<DisplayPortInfo> {
+        trace!("gpu.display_port_info({})", display_id);
+        let output_id = Self::output_id(display_id)?;
+        let mut data = display::NV_DISPLAY_PORT_INFO::zeroed();
+        data.version = display::NV_DISPLAY_PORT_INFO_VER;
+        sys::status_result(unsafe { display::NvAPI_GetDisplayPortInfo(Default::default(), output_id, &mut data) })?;
+
+        if !data.isDp() {
+            return Err(sys::Status::NotSupported)
+        }
+        data.convert_raw().map_err(From::from)
+    }
+
+    /// The HDMI capabilities of a display.
+    ///
+    /// Returns `Status::NotSupported` for displays that are not HDMI monitors. HDMI 2.x, FRL and
+    /// deep color capabilities are not available, see `HdmiSupportInfo`.
+    pub fn hdmi_support_info(&self, display_id: DisplayId) -> sys::Result<HdmiSupportInfo> {
+        trace!("gpu.hdmi_support_info({})", display_id);
+        let output_id = Self::output_id(display_id)?;
+        let data = sys::with_version_fallback(&[display::NV_HDMI_SUPPORT_INFO_VER2, display::NV_HDMI_SUPPORT_INFO_VER1], |version| {
+            let mut data = display::NV_HDMI_SUPPORT_INFO::zeroed();
+            data.version = version;
+            sys::status_result(unsafe { display::NvAPI_GetHDMISupportInfo(Default::default(), output_id, &mut data) })
+                .map(|_| data)
+        })?;
+
+        if !data.isMonHDMI() {
+            return Err(sys::Status::NotSupported)
+        }
+        data.convert_raw().map_err(From::from)
+    }
+
+    fn output_id(display_id: DisplayId) -> sys::Result<u32> {
+        let mut handle = Default::default();
+        let mut output_id = 0;
+        sys::status_result(unsafe { sys::system::NvAPI_SYS_GetGpuAndOutputIdFromDisplayId(display_id.0, &mut handle, &mut output_id) })
+            .map(|_| output_id)
+    }
+
+    /// Reads the complete EDID of a display, which may take several calls for EDIDs larger than
+    /// `NV_EDID_DATA_SIZE`.
+    pub fn edid(&self, display_output_id: u32) -> sys::Result<Vec<u8>> {
+        trace!("gpu.edid({:#x})", display_output_id);
+        let mut data = display::NV_EDID::zeroed();
+        data.version = display::NV_EDID_VER;
+        sys::status_result(unsafe { display::NvAPI_GPU_GetEDID(self.0, display_output_id, &mut data) })?;
+
+        let size = data.sizeofEDID as usize;
+        let edid_id = data.edidId;
+        let mut edid = Vec::with_capacity(size);
+        loop {
+            let page = (size - edid.len()).min(display::NV_EDID_DATA_SIZE);
+            edid.extend_from_slice(&data.EDID_Data[..page]);
+            if edid.len() >= size {
+                break Ok(edid)
+            }
+
+            data.offset = edid.len() as u32;
+            sys::status_result(unsafe { display::NvAPI_GPU_GetEDID(self.0, display_output_id, &mut data) })?;
+            if data.edidId != edid_id {
+                // The EDID changed while it was being read
+                break Err(sys::Status::Error)
+            }
+        }
+    }
+
     pub fn display_ids_connected(&self, flags: ConnectedIdsFlags) -> sys::Result<Vec<<display::NV_GPU_DISPLAYIDS as RawConversion>::Target>> {
         trace!("gpu.display_ids_connected({:?})", flags);
         let mut count = 0;
@@ -665,6 +1478,88 @@ impl PhysicalGpu {
     }
 }
 
+/// Returns the GPU that drives the given display.
+///
+/// Returns `InvalidDisplayId` if the display ID is not reported by any GPU's `display_ids_all()`.
+pub fn physical_gpu_from_display_id(display_id: impl Into<DisplayId>) -> sys::Result<PhysicalGpu> {
+    let display_id = display_id.into();
+    trace!("physical_gpu_from_display_id({})", display_id);
+    let mut known = false;
+    for gpu in PhysicalGpu::enumerate()? {
+        if gpu.display_ids_all()?.iter().any(|id| id.display_id == display_id) {
+            known = true;
+            break
+        }
+    }
+    if !known {
+        return Err(sys::Status::InvalidDisplayId)
+    }
+
+    let mut handle = Default::default();
+    sys::status_result(unsafe { sys::system::NvAPI_SYS_GetPhysicalGpuFromDisplayId(display_id.0, &mut handle) })
+        .map(|_| PhysicalGpu::new(handle))
+}
+
+static GPUS: RwLock<Option<Arc<[PhysicalGpu]>>> = RwLock::new(None);
+
+/// The physical GPUs of the system, enumerated once and shared for the rest of the process.
+///
+/// GPU handles are invalidated by hotplug, SLI changes and some modesets; call `refresh_gpus()`
+/// after such an event, or when a call fails with `HandleInvalidated`. Enumeration errors are not
+/// cached.
+pub fn gpus() -> sys::Result<Arc<[PhysicalGpu]>> {
+    if let Some(gpus) = &*GPUS.read().unwrap_or_else(|e| e.into_inner()) {
+        return Ok(gpus.clone())
+    }
+
+    refresh_gpus()
+}
+
+/// Re-enumerates the physical GPUs and replaces the list returned by `gpus()`.
+///
+/// Lists handed out earlier are left as they are, and may now hold stale handles.
+pub fn refresh_gpus() -> sys::Result<Arc<[PhysicalGpu]>> {
+    trace!("refresh_gpus()");
+    let gpus: Arc<[PhysicalGpu]> = PhysicalGpu::enumerate()?.into();
+    *GPUS.write().unwrap_or_else(|e| e.into_inner()) = Some(gpus.clone());
+    Ok(gpus)
+}
+
+/// One or more physical GPUs acting in concert, as referenced by `NV_MOSAIC_TOPO_DETAILS::hLogicalGPU`.
+#[derive(Debug)]
+pub struct LogicalGpu(sys::handles::NvLogicalGpuHandle);
+
+unsafe impl Send for LogicalGpu { }
+
+impl LogicalGpu {
+    pub fn new(handle: sys::handles::NvLogicalGpuHandle) -> Self {
+        LogicalGpu(handle)
+    }
+
+    pub fn handle(&self) -> &sys::handles::NvLogicalGpuHandle {
+        &self.0
+    }
+
+    pub fn enumerate() -> sys::Result<Vec<Self>> {
+        trace!("logical_gpu.enumerate()");
+        let mut handles = [Default::default(); sys::types::NVAPI_MAX_LOGICAL_GPUS];
+        let mut len = 0;
+        match unsafe { gpu::NvAPI_EnumLogicalGPUs(&mut handles, &mut len) } {
+            sys::status::NVAPI_NVIDIA_DEVICE_NOT_FOUND => Ok(Vec::new()),
+            status => sys::status_result(status).map(move |_| handles[..len as usize].iter().cloned().map(LogicalGpu).collect()),
+        }
+    }
+
+    /// The physical GPUs that make up this logical GPU.
+    pub fn physical_gpus(&self) -> sys::Result<Vec<PhysicalGpu>> {
+        trace!("logical_gpu.physical_gpus()");
+        let mut handles = [Default::default(); sys::types::NVAPI_MAX_PHYSICAL_GPUS];
+        let mut len = 0;
+        sys::status_result(unsafe { gpu::NvAPI_GetPhysicalGPUsFromLogicalGPU(self.0, &mut handles, &mut len) })
+            .map(move |_| handles[..len as usize].iter().cloned().map(PhysicalGpu::new).collect())
+    }
+}
+
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Copy, Clone, Default, PartialOrd, Ord, PartialEq, Eq, Hash)]
 pub struct PciIdentifiers {
@@ -681,6 +1576,22 @@ impl fmt::Display for PciIdentifiers {
 }
 
 impl PciIdentifiers {
+    /// The vendor ID of the GPU chip itself, 0x10de for NVIDIA.
+    pub fn pci_vendor_id(&self) -> u16 {
+        self.device_id as u16
+    }
+
+    /// The PCI device ID of the GPU chip, e.g. 0x2684 for an AD102 RTX 4090.
+    pub fn pci_device_id(&self) -> u16 {
+        (self.device_id >> 16) as u16
+    }
+
+    /// Whether the GPU chip has the given PCI device ID, for identifying specific SKUs.
+    pub fn matches_device(&self, device_id: u16) -> bool {
+        self.pci_device_id() == device_id
+    }
+
+    /// The vendor of the board, which for NVIDIA chips is the subsystem vendor.
     pub fn vendor_id(&self) -> u16 {
         self.ids().0
     }
@@ -713,6 +1624,420 @@ impl PciIdentifiers {
     }
 }
 
+/// Bus the GPU is attached to, as reported by `NvAPI_GPU_GetBusType`.
+///
+/// Values not known to this crate, including `NVAPI_GPU_BUS_TYPE_UNDEFINED`, are kept as `Unknown`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Copy, Clone, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub enum GpuBusType {
+    Pci,
+    Agp,
+    PciExpress,
+    Fpci,
+    Axi,
+    Unknown(u32),
+}
+
+impl GpuBusType {
+    pub fn from_raw(raw: u32) -> Self {
+        match gpu::BusType::from_raw(raw as _) {
+            Ok(gpu::BusType::Pci) => GpuBusType::Pci,
+            Ok(gpu::BusType::Agp) => GpuBusType::Agp,
+            Ok(gpu::BusType::PciExpress) => GpuBusType::PciExpress,
+            Ok(gpu::BusType::Fpci) => GpuBusType::Fpci,
+            Ok(gpu::BusType::Axi) => GpuBusType::Axi,
+            _ => GpuBusType::Unknown(raw),
+        }
+    }
+}
+
+impl fmt::Display for GpuBusType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            GpuBusType::Pci => write!(f, "PCI"),
+            GpuBusType::Agp => write!(f, "AGP"),
+            GpuBusType::PciExpress => write!(f, "PCI Express"),
+            GpuBusType::Fpci => write!(f, "FPCI"),
+            GpuBusType::Axi => write!(f, "AXI"),
+            GpuBusType::Unknown(raw) => write!(f, "Unknown ({})", raw),
+        }
+    }
+}
+
+/// GPU architecture family, as reported by `NvAPI_GPU_GetArchInfo`.
+///
+/// Architecture IDs that do not belong to a known family are kept as `Unknown`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Copy, Clone, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub enum GpuArchitecture {
+    Kepler,
+    Maxwell,
+    Pascal,
+    Volta,
+    Turing,
+    Ampere,
+    Hopper,
+    Ada,
+    Blackwell,
+    Unknown(u32),
+}
+
+impl GpuArchitecture {
+    pub fn from_raw(raw: u32) -> Self {
+        match ArchitectureId::from_raw(raw as _) {
+            Ok(ArchitectureId::GK100) | Ok(ArchitectureId::GK110) | Ok(ArchitectureId::GK200) => GpuArchitecture::Kepler,
+            Ok(ArchitectureId::GM000) | Ok(ArchitectureId::GM200) => GpuArchitecture::Maxwell,
+            Ok(ArchitectureId::GP100) => GpuArchitecture::Pascal,
+            Ok(ArchitectureId::GV100) | Ok(ArchitectureId::GV110) => GpuArchitecture::Volta,
+            Ok(ArchitectureId::TU100) => GpuArchitecture::Turing,
+            Ok(ArchitectureId::GA100) => GpuArchitecture::Ampere,
+            Ok(ArchitectureId::GH100) => GpuArchitecture::Hopper,
+            Ok(ArchitectureId::AD100) => GpuArchitecture::Ada,
+            Ok(ArchitectureId::GB200) => GpuArchitecture::Blackwell,
+            _ => GpuArchitecture::Unknown(raw),
+        }
+    }
+}
+
+impl fmt::Display for GpuArchitecture {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            GpuArchitecture::Kepler => write!(f, "Kepler"),
+            GpuArchitecture::Maxwell => write!(f, "Maxwell"),
+            GpuArchitecture::Pascal => write!(f, "Pascal"),
+            GpuArchitecture::Volta => write!(f, "Volta"),
+            GpuArchitecture::Turing => write!(f, "Turing"),
+            GpuArchitecture::Ampere => write!(f, "Ampere"),
+            GpuArchitecture::Hopper => write!(f, "Hopper"),
+            GpuArchitecture::Ada => write!(f, "Ada Lovelace"),
+            GpuArchitecture::Blackwell => write!(f, "Blackwell"),
+            GpuArchitecture::Unknown(raw) => write!(f, "Unknown ({:#x})", raw),
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Copy, Clone, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub struct ArchInfo {
+    pub architecture: GpuArchitecture,
+    pub implementation: u32,
+    pub revision: u32,
+}
+
+impl RawConversion for gpu::NV_GPU_ARCH_INFO {
+    type Target = ArchInfo;
+    type Error = Infallible;
+
+    fn convert_raw(&self) -> Result<Self::Target, Self::Error> {
+        Ok(ArchInfo {
+            architecture: GpuArchitecture::from_raw(self.architecture),
+            implementation: self.implementation,
+            revision: self.revision,
+        })
+    }
+}
+
+/// Product brand of a GPU, as derived from its marketing name.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Copy, Clone, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub enum Brand {
+    GeForce,
+    /// Includes workstation RTX cards that no longer carry the Quadro name.
+    Quadro,
+    /// Includes data center cards that no longer carry the Tesla name.
+    Tesla,
+    Unknown,
+}
+
+impl fmt::Display for Brand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Brand::GeForce => write!(f, "GeForce"),
+            Brand::Quadro => write!(f, "Quadro"),
+            Brand::Tesla => write!(f, "Tesla"),
+            Brand::Unknown => write!(f, "Unknown"),
+        }
+    }
+}
+
+/// Brand and series classification of a GPU marketing name such as "NVIDIA GeForce RTX 4090 Ti".
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub struct GpuModel {
+    pub brand: Brand,
+    /// The name without the vendor, brand and Ti/SUPER suffixes, e.g. "RTX 4090".
+    pub series: String,
+    pub is_ti: bool,
+    pub is_super: bool,
+}
+
+impl GpuModel {
+    /// Classifies a name as returned by `PhysicalGpu::full_name()`.
+    pub fn parse(name: &str) -> Self {
+        let mut tokens = name.split_whitespace().peekable();
+        if tokens.peek().map(|t| t.eq_ignore_ascii_case("NVIDIA")).unwrap_or(false) {
+            tokens.next();
+        }
+
+        let brand = match tokens.peek() {
+            Some(t) if t.eq_ignore_ascii_case("GeForce") => { tokens.next(); Brand::GeForce },
+            Some(t) if t.eq_ignore_ascii_case("Quadro") => { tokens.next(); Brand::Quadro },
+            Some(t) if t.eq_ignore_ascii_case("Tesla") => { tokens.next(); Brand::Tesla },
+            Some(t) if t.eq_ignore_ascii_case("TITAN") => Brand::GeForce,
+            Some(t) if t.eq_ignore_ascii_case("RTX") => Brand::Quadro,
+            // A100, H100, L40S, T4...
+            Some(t) if t.len() > 1 && t.starts_with(|c: char| c.is_ascii_uppercase()) && t[1..].starts_with(|c: char| c.is_ascii_digit()) => Brand::Tesla,
+            _ => Brand::Unknown,
+        };
+
+        let (mut is_ti, mut is_super) = (false, false);
+        let series: Vec<_> = tokens.filter(|t| if t.eq_ignore_ascii_case("Ti") {
+            is_ti = true;
+            false
+        } else if t.eq_ignore_ascii_case("SUPER") {
+            is_super = true;
+            false
+        } else {
+            true
+        }).collect();
+
+        GpuModel {
+            brand,
+            series: series.join(" "),
+            is_ti,
+            is_super,
+        }
+    }
+}
+
+/// Computes memory bandwidth in GB/s from the bus width in bits and the memory clock as reported
+/// by `NvAPI_GPU_GetAllClockFrequencies`.
+///
+/// The reported clock of DDR memory types is half the effective data rate, e.g. 10501 MHz for
+/// 21 Gbps GDDR6X. Returns `None` for unknown memory types or a zero bus width or clock.
+pub fn memory_bandwidth(bus_width: u32, ram_type: RamType, clock: Kilohertz) -> Option<f64> {
+    let transfers_per_clock = match ram_type {
+        RamType::Unknown => return None,
+        RamType::SDRAM => 1,
+        _ => 2,
+    };
+
+    if bus_width == 0 || clock.0 == 0 {
+        return None
+    }
+
+    Some(bus_width as f64 / 8.0 * clock.0 as f64 * transfers_per_clock as f64 / 1_000_000.0)
+}
+
+/// Maps an `NV_GPU_ARCHITECTURE_ID` and `NV_GPU_ARCH_IMPLEMENTATION_ID` to the CUDA compute
+/// capability of the chip, e.g. `(0x190, 0x2)` (AD102) to `(8, 9)`.
+///
+/// Returns `None` for architectures that predate CUDA or are not known.
+pub fn cuda_compute_capability(architecture: u32, implementation: u32) -> Option<(u32, u32)> {
+    let architecture = ArchitectureId::from_raw(architecture as _).ok()?;
+    Some(match architecture {
+        ArchitectureId::NV40 | ArchitectureId::NV50 | ArchitectureId::G78 => return None,
+        ArchitectureId::G80 => (1, 0),
+        ArchitectureId::G90 => (1, 1),
+        ArchitectureId::GT200 => (1, 3),
+        ArchitectureId::GF100 => (2, 0),
+        ArchitectureId::GF110 => (2, 1),
+        ArchitectureId::GK100 => (3, 0),
+        ArchitectureId::GK110 | ArchitectureId::GK200 => (3, 5),
+        ArchitectureId::GM000 => (5, 0),
+        ArchitectureId::GM200 => (5, 2),
+        // GP100 is the only chip of its family without the consumer SM layout
+        ArchitectureId::GP100 if implementation == 0 => (6, 0),
+        ArchitectureId::GP100 => (6, 1),
+        ArchitectureId::GV100 => (7, 0),
+        ArchitectureId::GV110 => (7, 2),
+        ArchitectureId::TU100 => (7, 5),
+        ArchitectureId::GA100 if implementation == 0 => (8, 0),
+        ArchitectureId::GA100 => (8, 6),
+        ArchitectureId::GH100 => (9, 0),
+        ArchitectureId::AD100 => (8, 9),
+        ArchitectureId::GB200 => (12, 0),
+    })
+}
+
+/// Treats queries the driver does not implement for a GPU as absent values.
+fn optional<T>(res: sys::Result<T>) -> sys::Result<Option<T>> {
+    match res {
+        Ok(value) => Ok(Some(value)),
+        Err(sys::Status::NotSupported) | Err(sys::Status::NoImplementation) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Memory type and layout of a GPU, see `PhysicalGpu::memory_config`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct MemoryConfig {
+    pub ram_type: Option<RamType>,
+    pub ram_maker: Option<RamMaker>,
+    /// Bus width in bits.
+    pub bus_width: Option<u32>,
+    pub bank_count: Option<u32>,
+    pub partition_count: Option<u32>,
+}
+
+impl fmt::Display for MemoryConfig {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.ram_type {
+            Some(ram_type) => write!(f, "{}", ram_type)?,
+            None => write!(f, "Unknown")?,
+        }
+        if let Some(ram_maker) = self.ram_maker {
+            write!(f, " ({})", ram_maker)?;
+        }
+        if let Some(bus_width) = self.bus_width {
+            write!(f, ", {}-bit", bus_width)?;
+        }
+        if let Some(partitions) = self.partition_count {
+            write!(f, ", {} partitions", partitions)?;
+        }
+        if let Some(banks) = self.bank_count {
+            write!(f, ", {} banks", banks)?;
+        }
+        Ok(())
+    }
+}
+
+/// The number of CUDA cores in each streaming multiprocessor of a compute capability, see
+/// `cuda_compute_capability`.
+pub fn cuda_cores_per_sm(major: u32, minor: u32) -> Option<u32> {
+    Some(match (major, minor) {
+        (2, 0) => 32,
+        (2, 1) => 48,
+        (3, _) => 192,
+        (5, _) => 128,
+        (6, 0) => 64,
+        (6, _) => 128,
+        (7, _) => 64,
+        (8, 0) => 64,
+        (8, _) => 128,
+        (9, _) | (10, _) | (12, _) => 128,
+        _ => return None,
+    })
+}
+
+/// HDCP capability of a GPU, see `PhysicalGpu::hdcp_status`.
+///
+/// NVAPI does not report which HDCP versions are supported or negotiated with a display.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct HdcpStatus {
+    pub fuse_state: HdcpFuseState,
+    pub key_source: HdcpKeySource,
+    pub key_source_state: HdcpKeySourceState,
+}
+
+impl HdcpStatus {
+    pub fn is_supported(&self) -> bool {
+        self.fuse_state == HdcpFuseState::Enabled && self.key_source_state == HdcpKeySourceState::Present
+    }
+}
+
+impl fmt::Display for HdcpStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.is_supported() {
+            true => write!(f, "Supported (keys in {})", self.key_source),
+            false => write!(f, "Unsupported (fuse {}, keys {})", self.fuse_state, self.key_source_state),
+        }
+    }
+}
+
+impl RawConversion for gpu::NV_GPU_GET_HDCP_SUPPORT_STATUS {
+    type Target = HdcpStatus;
+    type Error = sys::ArgumentRangeError;
+
+    fn convert_raw(&self) -> Result<Self::Target, Self::Error> {
+        Ok(HdcpStatus {
+            fuse_state: HdcpFuseState::from_raw(self.hdcpFuseState)?,
+            key_source: HdcpKeySource::from_raw(self.hdcpKeySource)?,
+            key_source_state: HdcpKeySourceState::from_raw(self.hdcpKeySourceState)?,
+        })
+    }
+}
+
+/// Sensor readings of a GPU, see `PhysicalGpu::sensors`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GpuSensors {
+    pub temperatures: Option<Vec<ThermalSensor>>,
+    /// Speed and level of the first fan
+    pub fan_rpm: Option<u32>,
+    pub fan_level: Option<Percentage>,
+    /// Board power draw, relative to the default power limit
+    pub power: Option<Percentage1000>,
+    pub graphics_clock: Option<Kilohertz>,
+    pub memory_clock: Option<Kilohertz>,
+    pub utilizations: Option<UtilizationDomains>,
+}
+
+/// Core and shader counts of a GPU, see `PhysicalGpu::shader_info`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Copy, Clone, Default, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub struct ShaderInfo {
+    pub cuda_cores: Option<u32>,
+    pub shader_pipes: Option<u32>,
+    pub shader_sub_pipes: Option<u32>,
+    /// Streaming multiprocessors, derived from the core count.
+    pub sm_count: Option<u32>,
+}
+
+impl fmt::Display for ShaderInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let fields = [
+            (self.cuda_cores, "CUDA cores"),
+            (self.sm_count, "SMs"),
+            (self.shader_pipes, "shader pipes"),
+            (self.shader_sub_pipes, "shader sub-pipes"),
+        ];
+        let mut first = true;
+        for (value, name) in fields.iter().filter_map(|&(value, name)| value.map(|v| (v, name))) {
+            if !first {
+                write!(f, ", ")?;
+            }
+            first = false;
+            write!(f, "{} {}", value, name)?;
+        }
+        if first {
+            write!(f, "Unknown")?;
+        }
+        Ok(())
+    }
+}
+
+/// Current against expected PCIe link width, see `PhysicalGpu::pcie_link_health`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Copy, Clone, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub struct PcieLinkHealth {
+    pub current_lanes: u32,
+    pub max_lanes: u32,
+    /// The link trained at fewer lanes than expected, often due to a bad riser, cable or slot.
+    pub is_downgraded: bool,
+}
+
+impl PcieLinkHealth {
+    pub fn new(current_lanes: u32, max_lanes: u32) -> Self {
+        PcieLinkHealth {
+            current_lanes,
+            max_lanes,
+            is_downgraded: current_lanes < max_lanes,
+        }
+    }
+}
+
+impl fmt::Display for PcieLinkHealth {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_downgraded {
+            write!(f, "PCIe link running at x{}, expected x{}", self.current_lanes, self.max_lanes)
+        } else {
+            write!(f, "PCIe link running at x{}", self.current_lanes)
+        }
+    }
+}
+
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Copy, Clone, PartialOrd, Ord, PartialEq, Eq, Hash)]
 pub struct MemoryInfo {
@@ -743,57 +2068,436 @@ impl RawConversion for driverapi::NV_DISPLAY_DRIVER_MEMORY_INFO {
 }
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Copy, Clone, PartialOrd, Ord, PartialEq, Eq, Hash)]
-pub struct DriverModel {
-    pub value: u32,
+#[derive(Debug, Copy, Clone, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub struct EccStatus {
+    pub supported: bool,
+    pub configuration: EccConfiguration,
+    pub enabled: bool,
+}
+
+impl RawConversion for ecc::NV_GPU_ECC_STATUS_INFO {
+    type Target = EccStatus;
+    type Error = sys::ArgumentRangeError;
+
+    fn convert_raw(&self) -> Result<Self::Target, Self::Error> {
+        Ok(EccStatus {
+            supported: self.isSupported.get(),
+            configuration: EccConfiguration::from_raw(self.configurationOptions)?,
+            enabled: self.isEnabled.get(),
+        })
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Copy, Clone, Default, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub struct EccErrorCounts {
+    pub single_bit: u64,
+    pub double_bit: u64,
+}
+
+impl RawConversion for ecc::NV_GPU_ECC_ERROR_INFO_COUNTS {
+    type Target = EccErrorCounts;
+    type Error = Infallible;
+
+    fn convert_raw(&self) -> Result<Self::Target, Self::Error> {
+        Ok(EccErrorCounts {
+            single_bit: self.singleBitErrors,
+            double_bit: self.doubleBitErrors,
+        })
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Copy, Clone, Default, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub struct EccErrors {
+    /// Errors since the last boot
+    pub current: EccErrorCounts,
+    /// Errors since the last counter reset
+    pub aggregate: EccErrorCounts,
+}
+
+impl RawConversion for ecc::NV_GPU_ECC_ERROR_INFO {
+    type Target = EccErrors;
+    type Error = Infallible;
+
+    fn convert_raw(&self) -> Result<Self::Target, Self::Error> {
+        Ok(EccErrors {
+            current: self.current.convert_raw()?,
+            aggregate: self.aggregate.convert_raw()?,
+        })
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Copy, Clone, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub struct EccConfigurationInfo {
+    pub enabled: bool,
+    pub enabled_by_default: bool,
+}
+
+impl RawConversion for ecc::NV_GPU_ECC_CONFIGURATION_INFO {
+    type Target = EccConfigurationInfo;
+    type Error = Infallible;
+
+    fn convert_raw(&self) -> Result<Self::Target, Self::Error> {
+        Ok(EccConfigurationInfo {
+            enabled: self.isEnabled(),
+            enabled_by_default: self.isEnabledByDefault(),
+        })
+    }
+}
+
+/// The Windows driver model a GPU runs under, as reported by `PhysicalGpu::driver_model()`.
+///
+/// WDDM GPUs report their WDDM version in bits 8..16 of the raw value, e.g. `0x2000` for WDDM 2.0.
+/// Other GPUs report the model in the low bits using NVML's numbering, where `1` is TCC and `2` is
+/// MCDM. Display and Mosaic APIs only apply to GPUs running under WDDM.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Copy, Clone, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub enum DriverModel {
+    Wddm {
+        major: u8,
+        minor: u8,
+    },
+    /// Tesla Compute Cluster, a compute-only model without display support
+    Tcc,
+    /// Microsoft Compute Driver Model, a compute-only model without display support
+    Mcdm,
+    Unknown(u32),
 }
 
 impl DriverModel {
-    pub fn new(value: u32) -> Self {
-        DriverModel {
-            value: value,
+    pub fn from_raw(value: u32) -> Self {
+        // 2.0 or 1.(value >> 8)
+        match ((value >> 12) & 0xf) as u8 {
+            major @ 1..=3 => DriverModel::Wddm {
+                major,
+                minor: if major == 2 { 0 } else { (value >> 8) as u8 & 0xf },
+            },
+            _ => match value {
+                1 => DriverModel::Tcc,
+                2 => DriverModel::Mcdm,
+                _ => DriverModel::Unknown(value),
+            },
         }
     }
 
+    /// The raw value this model was decoded from.
+    pub fn raw(&self) -> u32 {
+        match *self {
+            DriverModel::Wddm { major, minor } => (major as u32) << 12 | (minor as u32) << 8,
+            DriverModel::Tcc => 1,
+            DriverModel::Mcdm => 2,
+            DriverModel::Unknown(value) => value,
+        }
+    }
+
+    pub fn is_wddm(&self) -> bool {
+        matches!(self, DriverModel::Wddm { .. })
+    }
+
+    /// The WDDM version as `(major, minor)`.
+    pub fn wddm_version(&self) -> Option<(u8, u8)> {
+        match *self {
+            DriverModel::Wddm { major, minor } => Some((major, minor)),
+            _ => None,
+        }
+    }
+
+    /// A warning to show for models that don't support display and Mosaic APIs, e.g.
+    /// "GPU is in TCC mode; display APIs unavailable".
+    pub fn display_warning(&self) -> Option<String> {
+        match *self {
+            DriverModel::Wddm { .. } => None,
+            DriverModel::Tcc | DriverModel::Mcdm => Some(format!("GPU is in {} mode; display APIs unavailable", self)),
+            DriverModel::Unknown(..) => Some(format!("GPU driver model is {}; display APIs may be unavailable", self)),
+        }
+    }
+
+    #[deprecated(note = "use `DriverModel::from_raw` instead")]
+    pub fn new(value: u32) -> Self {
+        DriverModel::from_raw(value)
+    }
+
+    #[deprecated(note = "use `DriverModel::raw` instead")]
+    pub fn value(&self) -> u32 {
+        self.raw()
+    }
+
+    #[deprecated(note = "use `DriverModel::wddm_version` instead")]
     pub fn wddm(&self) -> (u8, u8) {
-        // 2.0 or 1.(value >> 8)
-        let major = ((self.value >> 12) & 0xf) as u8;
+        let value = self.raw();
+        let major = ((value >> 12) & 0xf) as u8;
         (
             major,
-            if major == 2 { 0 } else { (self.value >> 8) as u8 & 0xf }
+            if major == 2 { 0 } else { (value >> 8) as u8 & 0xf }
         )
     }
 }
 
 impl fmt::Display for DriverModel {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let wddm = self.wddm();
-        write!(f, "WDDM {}.{:02}", wddm.0, wddm.1)
+        match *self {
+            DriverModel::Wddm { major, minor } => write!(f, "WDDM {}.{:02}", major, minor),
+            DriverModel::Tcc => write!(f, "TCC"),
+            DriverModel::Mcdm => write!(f, "MCDM"),
+            DriverModel::Unknown(value) => write!(f, "Unknown ({:08x})", value),
+        }
     }
 }
 
-impl fmt::Debug for DriverModel {
+/// A display reported by `PhysicalGpu::display_ids_all()` or `display_ids_connected()`.
+///
+/// This was named `DisplayId` before that name was taken by the display ID newtype. It converts
+/// into its `DisplayId`, so it can be passed directly to functions taking `impl Into<DisplayId>`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Copy, Clone, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub struct DisplayInfo {
+    pub connector: MonitorConnectorType,
+    pub display_id: DisplayId,
+    pub flags: DisplayIdsFlags,
+}
+
+impl From<DisplayInfo> for DisplayId {
+    fn from(info: DisplayInfo) -> Self {
+        info.display_id
+    }
+}
+
+impl DisplayInfo {
+    pub fn is_connected(&self) -> bool {
+        self.flags.contains(DisplayIdsFlags::CONNECTED)
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.flags.contains(DisplayIdsFlags::ACTIVE)
+    }
+
+    /// Part of an MST topology
+    pub fn is_dynamic(&self) -> bool {
+        self.flags.contains(DisplayIdsFlags::DYNAMIC)
+    }
+
+    pub fn connector_type(&self) -> ConnectorType {
+        self.connector.into()
+    }
+
+    /// Describes the display with a per-connector index, e.g. `DP-1 connected active`.
+    pub fn describe(&self, index: usize) -> String {
+        let mut s = format!("{}-{}", self.connector_type(), index);
+        self.fmt_state(&mut s).unwrap();
+        s
+    }
+
+    fn fmt_state<W: fmt::Write>(&self, f: &mut W) -> fmt::Result {
+        write!(f, " {}", if self.is_connected() { "connected" } else { "disconnected" })?;
+        if self.is_active() {
+            write!(f, " active")?;
+        }
+        if self.is_dynamic() {
+            write!(f, " dynamic")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for DisplayInfo {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{} ({:08x})", self, self.value)
+        write!(f, "{}", self.connector_type())?;
+        self.fmt_state(f)
     }
 }
 
+/// Simplified physical connector kind of a display output
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Copy, Clone, PartialOrd, Ord, PartialEq, Eq, Hash)]
-pub struct DisplayId {
-    pub connector: MonitorConnectorType,
-    pub display_id: u32,
-    pub flags: DisplayIdsFlags,
+pub enum ConnectorType {
+    Hdmi,
+    DisplayPort,
+    Dvi,
+    Vga,
+    /// Built-in panel (LVDS)
+    Internal,
+    Other,
+}
+
+impl From<MonitorConnectorType> for ConnectorType {
+    fn from(connector: MonitorConnectorType) -> Self {
+        match connector {
+            MonitorConnectorType::Hdmi => ConnectorType::Hdmi,
+            MonitorConnectorType::DisplayPort => ConnectorType::DisplayPort,
+            MonitorConnectorType::Dvi => ConnectorType::Dvi,
+            MonitorConnectorType::Vga => ConnectorType::Vga,
+            MonitorConnectorType::Lvds => ConnectorType::Internal,
+            _ => ConnectorType::Other,
+        }
+    }
+}
+
+impl fmt::Display for ConnectorType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            ConnectorType::Hdmi => "HDMI",
+            ConnectorType::DisplayPort => "DP",
+            ConnectorType::Dvi => "DVI",
+            ConnectorType::Vga => "VGA",
+            ConnectorType::Internal => "Internal",
+            ConnectorType::Other => "Other",
+        })
+    }
+}
+
+/// A physical display output of a GPU
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub struct Connector {
+    pub index: usize,
+    pub connector_type: ConnectorType,
+    /// Single-bit output mask, as accepted by `PhysicalGpu::edid`
+    pub output_id: u32,
+    /// Displays driven by this output; more than one for MST
+    pub display_ids: Vec<DisplayId>,
+}
+
+impl fmt::Display for Connector {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Connector {}: {}", self.index, self.connector_type)
+    }
+}
+
+/// DisplayPort link rate, as reported in `NV_DISPLAY_PORT_INFO`.
+///
+/// Rates not known to this crate, such as the UHBR rates of DisplayPort 2.x links, are kept as `Unknown`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Copy, Clone, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub enum DisplayPortLinkRate {
+    Rbr,
+    Hbr,
+    Hbr2,
+    Hbr3,
+    Unknown(display::NV_DP_LINK_RATE),
+}
+
+impl DisplayPortLinkRate {
+    pub fn from_raw(raw: display::NV_DP_LINK_RATE) -> Self {
+        match DpLinkRate::from_raw(raw) {
+            Ok(DpLinkRate::Rbr) => DisplayPortLinkRate::Rbr,
+            Ok(DpLinkRate::Hbr) => DisplayPortLinkRate::Hbr,
+            Ok(DpLinkRate::Hbr2) => DisplayPortLinkRate::Hbr2,
+            Ok(DpLinkRate::Hbr3) => DisplayPortLinkRate::Hbr3,
+            _ => DisplayPortLinkRate::Unknown(raw),
+        }
+    }
+}
+
+impl fmt::Display for DisplayPortLinkRate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DisplayPortLinkRate::Rbr => write!(f, "RBR"),
+            DisplayPortLinkRate::Hbr => write!(f, "HBR"),
+            DisplayPortLinkRate::Hbr2 => write!(f, "HBR2"),
+            DisplayPortLinkRate::Hbr3 => write!(f, "HBR3"),
+            DisplayPortLinkRate::Unknown(raw) => write!(f, "Unknown ({})", raw),
+        }
+    }
+}
+
+/// The DisplayPort link of a display, as displayed in e.g. "DP 1.4 HBR3 x4, 10 bpc".
+///
+/// Decoded from `NV_DISPLAY_PORT_INFO_V1`, which does not report Display Stream Compression, so
+/// the DSC state of the link is not exposed.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct DisplayPortInfo {
+    /// The DPCD revision of the monitor, e.g. `0x14` for DisplayPort 1.4
+    pub dpcd_version: u32,
+    pub link_rate: DisplayPortLinkRate,
+    pub lane_count: DpLaneCount,
+    pub max_link_rate: DisplayPortLinkRate,
+    pub max_lane_count: DpLaneCount,
+    pub color_format: DpColorFormat,
+    pub bpc: DpBpc,
+}
+
+impl fmt::Display for DisplayPortInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "DP {}.{} {} {}, {}",
+            self.dpcd_version >> 4, self.dpcd_version & 0xf,
+            self.link_rate, self.lane_count, self.bpc
+        )
+    }
+}
+
+impl RawConversion for display::NV_DISPLAY_PORT_INFO {
+    type Target = DisplayPortInfo;
+    type Error = sys::ArgumentRangeError;
+
+    fn convert_raw(&self) -> Result<Self::Target, Self::Error> {
+        trace!("convert_raw({:#?})", self);
+        Ok(DisplayPortInfo {
+            dpcd_version: self.dpcd_ver,
+            link_rate: DisplayPortLinkRate::from_raw(self.curLinkRate),
+            lane_count: DpLaneCount::from_raw(self.curLaneCount)?,
+            max_link_rate: DisplayPortLinkRate::from_raw(self.maxLinkRate),
+            max_lane_count: DpLaneCount::from_raw(self.maxLaneCount)?,
+            color_format: DpColorFormat::from_raw(self.colorFormat)?,
+            bpc: DpBpc::from_raw(self.bpc)?,
+        })
+    }
+}
+
+/// The HDMI capabilities of a display and the GPU driving it.
+///
+/// Decoded from `NV_HDMI_SUPPORT_INFO_V2`, which only covers HDMI 1.x era capabilities. HDMI 2.x
+/// features such as FRL (Fixed Rate Link) and deep color modes are not reported by it and so are
+/// not exposed.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct HdmiSupportInfo {
+    pub gpu_hdmi_capable: bool,
+    pub underscan: bool,
+    pub basic_audio: bool,
+    pub ycbcr444: bool,
+    pub ycbcr422: bool,
+    pub xvycc601: bool,
+    pub xvycc709: bool,
+    pub sycc601: bool,
+    pub adobe_ycc601: bool,
+    pub adobe_rgb: bool,
+    /// The revision of the EDID CEA-861 extension block
+    pub edid_861_revision: u32,
+}
+
+impl RawConversion for display::NV_HDMI_SUPPORT_INFO {
+    type Target = HdmiSupportInfo;
+    type Error = Infallible;
+
+    fn convert_raw(&self) -> Result<Self::Target, Self::Error> {
+        trace!("convert_raw({:#?})", self);
+        Ok(HdmiSupportInfo {
+            gpu_hdmi_capable: self.isGpuHDMICapable(),
+            underscan: self.isMonUnderscanCapable(),
+            basic_audio: self.isMonBasicAudioCapable(),
+            ycbcr444: self.isMonYCbCr444Capable(),
+            ycbcr422: self.isMonYCbCr422Capable(),
+            xvycc601: self.isMonxvYCC601Capable(),
+            xvycc709: self.isMonxvYCC709Capable(),
+            sycc601: self.isMonsYCC601Capable(),
+            adobe_ycc601: self.isMonAdobeYCC601Capable(),
+            adobe_rgb: self.isMonAdobeRGBCapable(),
+            edid_861_revision: self.EDID861ExtRev,
+        })
+    }
 }
 
 impl RawConversion for display::NV_GPU_DISPLAYIDS {
-    type Target = DisplayId;
+    type Target = DisplayInfo;
     type Error = sys::ArgumentRangeError;
 
     fn convert_raw(&self) -> Result<Self::Target, Self::Error> {
-        Ok(DisplayId {
+        Ok(DisplayInfo {
             connector: MonitorConnectorType::from_raw(self.connectorType)?,
-            display_id: self.displayId,
+            display_id: DisplayId(self.displayId),
             flags: DisplayIdsFlags::from_bits_truncate(self.flags),
         })
     }
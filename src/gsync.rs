@@ -1,8 +1,12 @@
-use log::trace;
-use nvapi_sys::{handles, status_result, NVAPI_MAX_GSYNC_DEVICES};
+use log::{trace, warn};
+use std::ptr;
+use nvapi_sys::{self as sys, handles, status_result, Status, NVAPI_MAX_GSYNC_DEVICES};
 use crate::sys::gsync::{self};
 
+pub use crate::sys::gsync::{TopologyConnector, DelayType, DisplaySyncState};
+
 use crate::PhysicalGpu;
+use crate::types::DisplayId;
 
 #[derive(Debug)]
 pub struct GSyncDevice {
@@ -27,13 +31,346 @@ impl GSyncDevice {
         }
     }
 
-    pub fn get_sync_status(&self, gpu: PhysicalGpu) -> crate::Result<gsync::NV_GSYNC_STATUS> {
+    pub fn get_sync_status(&self, gpu: PhysicalGpu) -> crate::Result<SyncStatus> {
         let mut status = gsync::NV_GSYNC_STATUS::default();
         status.version  = gsync::NV_GSYNC_STATUS_VER;
         match unsafe {
             gsync::NvAPI_GSync_GetSyncStatus(*self.handle(), *gpu.handle(), &mut status)
         } {
-            ret => status_result(ret).map(|_| status),
+            ret => status_result(ret).map(|_| SyncStatus(status)),
+        }
+    }
+
+    /// Returns the sync status of every GPU attached to this sync device.
+    ///
+    /// GPUs whose status cannot be queried are skipped, as is everything if the topology query fails.
+    pub fn sync_status_all(&self) -> Vec<(PhysicalGpu, SyncStatus)> {
+        trace!("gsync.sync_status_all()");
+        self.iter_physical_gpus()
+            .filter_map(|gpu| self.get_sync_status(PhysicalGpu::new(*gpu.handle())).ok().map(|status| (gpu, status)))
+            .collect()
+    }
+
+    /// Returns the refresh rate, RJ45 port states and house sync status of the sync device.
+    ///
+    /// Drivers that do not know the V2 layout fill in the V1 part only.
+    pub fn get_status_parameters(&self) -> crate::Result<gsync::NV_GSYNC_STATUS_PARAMS> {
+        trace!("gsync.get_status_parameters()");
+        sys::with_version_fallback(&[gsync::NV_GSYNC_STATUS_PARAMS_VER_2, gsync::NV_GSYNC_STATUS_PARAMS_VER_1], |version| {
+            let mut params = gsync::NV_GSYNC_STATUS_PARAMS::zeroed();
+            params.version = version;
+            status_result(unsafe { gsync::NvAPI_GSync_GetStatusParameters(self.handle, &mut params) })
+                .map(|_| params)
+        })
+    }
+
+    /// Whether the sync device detects an incoming house sync (genlock) signal.
+    pub fn house_sync_present(&self) -> crate::Result<bool> {
+        trace!("gsync.house_sync_present()");
+        self.get_status_parameters()
+            .map(|params| params.v1.bHouseSync != 0)
+    }
+
+    /// Whether a house sync signal is present and every GPU attached to the sync device is synced
+    /// to it.
+    ///
+    /// Returns `Ok(false)` when no GPUs are attached.
+    pub fn house_sync_locked(&self) -> crate::Result<bool> {
+        trace!("gsync.house_sync_locked()");
+        if !self.house_sync_present()? {
+            return Ok(false)
+        }
+
+        let gpus = self.get_physical_gpus()?;
+        if gpus.is_empty() {
+            return Ok(false)
+        }
+        for gpu in gpus {
+            let status = self.get_sync_status(gpu)?;
+            if !status.is_synced() || !status.signal_available() {
+                return Ok(false)
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// The board ID of the sync device, which stays the same across enumerations.
+    pub fn board_id(&self) -> crate::Result<u32> {
+        trace!("gsync.board_id()");
+        let mut caps = gsync::NV_GSYNC_CAPABILITIES::zeroed();
+        caps.version = gsync::NV_GSYNC_CAPABILITIES_VER;
+        status_result(unsafe { gsync::NvAPI_GSync_QueryCapabilities(self.handle, &mut caps) })
+            .map(|_| caps.boardId)
+    }
+
+    /// Returns the displays driven through this sync device, as reported by its topology.
+    pub fn get_displays(&self) -> crate::Result<Vec<gsync::NV_GSYNC_DISPLAY>> {
+        trace!("gsync.get_displays()");
+        let mut gpu_count = 0;
+        let mut count = 0;
+        status_result(unsafe {
+            gsync::NvAPI_GSync_GetTopology(self.handle, &mut gpu_count, ptr::null_mut(), &mut count, ptr::null_mut())
+        })?;
+
+        let mut displays = vec![gsync::NV_GSYNC_DISPLAY::zeroed(); count as usize];
+        for display in &mut displays {
+            display.version = gsync::NV_GSYNC_DISPLAY_VER;
+        }
+        status_result(unsafe {
+            gsync::NvAPI_GSync_GetTopology(self.handle, ptr::null_mut(), ptr::null_mut(), &mut count, displays.as_mut_ptr())
+        })?;
+        displays.truncate(count as usize);
+        Ok(displays)
+    }
+
+    /// Returns the GPUs attached to this sync device, as reported by its topology.
+    pub fn get_physical_gpus(&self) -> crate::Result<Vec<PhysicalGpu>> {
+        trace!("gsync.get_physical_gpus()");
+        self.get_gpu_sync_info()
+            .map(|gpus| gpus.iter().map(GpuSyncInfo::gpu).collect())
+    }
+
+    /// Returns the connector and sync state of every GPU attached to this sync device.
+    pub fn get_gpu_sync_info(&self) -> crate::Result<Vec<GpuSyncInfo>> {
+        trace!("gsync.get_gpu_sync_info()");
+        let mut count = 0;
+        let mut display_count = 0;
+        status_result(unsafe {
+            gsync::NvAPI_GSync_GetTopology(self.handle, &mut count, ptr::null_mut(), &mut display_count, ptr::null_mut())
+        })?;
+
+        let mut gpus = vec![gsync::NV_GSYNC_GPU::zeroed(); count as usize];
+        for gpu in &mut gpus {
+            gpu.version = gsync::NV_GSYNC_GPU_VER;
+        }
+        status_result(unsafe {
+            gsync::NvAPI_GSync_GetTopology(self.handle, &mut count, gpus.as_mut_ptr(), ptr::null_mut(), ptr::null_mut())
+        })?;
+        gpus.truncate(count as usize);
+        Ok(gpus.into_iter().map(GpuSyncInfo).collect())
+    }
+
+    /// Rounds a sync skew or startup delay to the closest value the hardware supports, without
+    /// applying it.
+    pub fn adjust_sync_delay(&self, kind: DelayType, delay: SyncDelay) -> crate::Result<SyncDelayAdjustment> {
+        trace!("gsync.adjust_sync_delay({:?}, {:?})", kind, delay);
+        let mut data = gsync::NV_GSYNC_DELAY::zeroed();
+        data.version = gsync::NV_GSYNC_DELAY_VER;
+        data.numLines = delay.lines;
+        data.numPixels = delay.pixels;
+        let mut sync_steps = 0;
+        status_result(unsafe { gsync::NvAPI_GSync_AdjustSyncDelay(self.handle, kind.raw(), &mut data, &mut sync_steps) })
+            .map(|_| SyncDelayAdjustment::new(delay, &data, sync_steps))
+    }
+
+    /// Sets the sync state of displays attached to this sync device, leaving the other displays
+    /// untouched.
+    ///
+    /// The driver unsyncs every display missing from `NvAPI_GSync_SetSyncStateSettings`, so the
+    /// settings are merged into the current state of every display of every sync device first.
+    /// Returns `InvalidArgument` without changing anything if more than one display of this device
+    /// would be `Master`, or if a display is not part of the topology or cannot be master.
+    pub fn set_sync_state_settings<D: Into<DisplayId> + Copy>(&self, settings: &[(D, DisplaySyncState)], flags: u32) -> crate::Result<()> {
+        let settings: Vec<(DisplayId, DisplaySyncState)> = settings.iter().map(|&(id, state)| (id.into(), state)).collect();
+        trace!("gsync.set_sync_state_settings({:?}, {:#x})", settings, flags);
+        let masters = settings.iter().filter(|&&(_, state)| state == DisplaySyncState::Master).count();
+        if masters > 1 {
+            warn!("set_sync_state_settings: {} displays set to Master, at most one is allowed", masters);
+            return Err(Status::InvalidArgument)
+        }
+
+        let mut displays = self.get_displays()?;
+        for &(id, state) in &settings {
+            let display = match displays.iter_mut().find(|d| d.displayId == id.0) {
+                Some(display) => display,
+                None => {
+                    warn!("set_sync_state_settings: display {} is not attached to the sync device", id);
+                    return Err(Status::InvalidArgument)
+                },
+            };
+            if state == DisplaySyncState::Master && !display.is_masterable() {
+                warn!("set_sync_state_settings: display {} cannot be master", id);
+                return Err(Status::InvalidArgument)
+            }
+            display.syncState = state.raw();
+        }
+
+        let masters = displays.iter().filter(|d| d.syncState == gsync::NVAPI_GSYNC_DISPLAY_SYNC_STATE_MASTER).count();
+        if masters > 1 {
+            warn!("set_sync_state_settings: the current master must be changed to another state as well");
+            return Err(Status::InvalidArgument)
+        }
+
+        let board_id = self.board_id()?;
+        for device in GSyncDevice::get_sync_devices()? {
+            if device.board_id()? != board_id {
+                displays.extend(device.get_displays()?);
+            }
+        }
+
+        status_result(unsafe { gsync::NvAPI_GSync_SetSyncStateSettings(displays.len() as u32, displays.as_mut_ptr(), flags) })
+    }
+
+    /// Lazily walks the GPUs attached to this sync device.
+    ///
+    /// The topology is queried on the first call to `next()`; if the query fails, nothing is yielded.
+    pub fn iter_physical_gpus(&self) -> impl Iterator<Item = PhysicalGpu> {
+        let handle = self.handle;
+        std::iter::once(()).flat_map(move |_| GSyncDevice::new(handle).get_physical_gpus().unwrap_or_default())
+    }
+}
+
+/// A sync skew or startup delay, see `GSyncDevice::adjust_sync_delay`.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct SyncDelay {
+    pub lines: u32,
+    pub pixels: u32,
+}
+
+impl SyncDelay {
+    pub fn new(lines: u32, pixels: u32) -> Self {
+        SyncDelay { lines, pixels }
+    }
+}
+
+/// The outcome of `GSyncDevice::adjust_sync_delay`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct SyncDelayAdjustment {
+    pub requested: SyncDelay,
+    /// The closest delay the hardware supports
+    pub adjusted: SyncDelay,
+    /// The largest delay in lines supported by the current display mode
+    pub max_lines: u32,
+    /// The smallest delay in pixels that can be induced
+    pub min_pixels: u32,
+    /// The delay in 7.8 ns steps
+    pub sync_steps: u32,
+}
+
+impl SyncDelayAdjustment {
+    pub fn new(requested: SyncDelay, data: &gsync::NV_GSYNC_DELAY, sync_steps: u32) -> Self {
+        SyncDelayAdjustment {
+            requested,
+            adjusted: SyncDelay::new(data.numLines, data.numPixels),
+            max_lines: data.maxLines(),
+            min_pixels: data.minPixels(),
+            sync_steps,
+        }
+    }
+
+    /// Whether the requested delay could not be achieved exactly.
+    pub fn is_clamped(&self) -> bool {
+        self.requested != self.adjusted
+    }
+}
+
+/// The sync state of a GPU attached to a sync device, as returned by `GSyncDevice::get_sync_status`.
+#[derive(Debug, Copy, Clone)]
+pub struct SyncStatus(pub gsync::NV_GSYNC_STATUS);
+
+impl SyncStatus {
+    pub fn is_synced(&self) -> bool {
+        self.0.bIsSynced != 0
+    }
+
+    pub fn is_stereo_synced(&self) -> bool {
+        self.0.bIsStereoSynced != 0
+    }
+
+    /// Whether the sync signal is available to the GPU.
+    pub fn signal_available(&self) -> bool {
+        self.0.bIsSyncSignalAvailable != 0
+    }
+}
+
+/// A GPU in the topology of a sync device.
+#[derive(Debug, Copy, Clone)]
+pub struct GpuSyncInfo(pub gsync::NV_GSYNC_GPU);
+
+impl GpuSyncInfo {
+    pub fn gpu(&self) -> PhysicalGpu {
+        PhysicalGpu::new(self.0.hPhysicalGpu)
+    }
+
+    /// The RJ45 connector of the sync device that the GPU is attached to.
+    pub fn connector(&self) -> crate::Result<TopologyConnector> {
+        TopologyConnector::from_raw(self.0.connector).map_err(From::from)
+    }
+
+    pub fn is_synced(&self) -> bool {
+        self.0.is_synced()
+    }
+
+    /// The GPU through which this one is connected, if it is not attached directly.
+    pub fn proxy_gpu(&self) -> Option<PhysicalGpu> {
+        if self.0.hProxyPhysicalGpu.is_null() {
+            None
+        } else {
+            Some(PhysicalGpu::new(self.0.hProxyPhysicalGpu))
+        }
+    }
+}
+
+/// Checks whether every GPU attached to every sync device reports `bIsSynced`.
+///
+/// Returns `Ok(true)` when no sync devices are present.
+pub fn gsync_group_synced() -> crate::Result<bool> {
+    trace!("gsync_group_synced()");
+    let devices = match GSyncDevice::get_sync_devices() {
+        Ok(devices) => devices,
+        Err(Status::NvidiaDeviceNotFound) => return Ok(true),
+        Err(e) => return Err(e),
+    };
+
+    for device in devices {
+        for gpu in device.get_physical_gpus()? {
+            if !device.get_sync_status(gpu)?.is_synced() {
+                return Ok(false)
+            }
         }
     }
-}
\ No newline at end of file
+
+    Ok(true)
+}
+
+/// Makes one display the sync master of each listed sync device, in a single call to
+/// `NvAPI_GSync_SetSyncStateSettings`.
+///
+/// `mapping` pairs a device `board_id()` with the display ID that should drive it. Every other
+/// display of a listed device becomes a slave. The driver unsyncs every display missing from the
+/// call, so the displays of unlisted devices are passed along with their current sync state.
+/// Returns `InvalidArgument` if a device is not present or the display cannot be master.
+pub fn gsync_set_all_masters<D: Into<DisplayId> + Copy>(mapping: &[(u64, D)]) -> crate::Result<()> {
+    let mapping: Vec<(u64, DisplayId)> = mapping.iter().map(|&(board_id, id)| (board_id, id.into())).collect();
+    trace!("gsync_set_all_masters({:?})", mapping);
+    let devices = GSyncDevice::get_sync_devices()?;
+    let mut ids = Vec::with_capacity(devices.len());
+    for device in &devices {
+        ids.push(device.board_id()? as u64);
+    }
+    if mapping.iter().any(|&(board_id, _)| !ids.contains(&board_id)) {
+        return Err(Status::InvalidArgument)
+    }
+
+    let mut displays = Vec::new();
+    for (device, board_id) in devices.iter().zip(&ids) {
+        let mut device_displays = device.get_displays()?;
+        if let Some(&(_, DisplayId(master))) = mapping.iter().find(|&&(id, _)| id == *board_id) {
+            if !device_displays.iter().any(|d| d.displayId == master && d.is_masterable()) {
+                return Err(Status::InvalidArgument)
+            }
+
+            for display in &mut device_displays {
+                display.syncState = if display.displayId == master {
+                    gsync::NVAPI_GSYNC_DISPLAY_SYNC_STATE_MASTER
+                } else {
+                    gsync::NVAPI_GSYNC_DISPLAY_SYNC_STATE_SLAVE
+                };
+            }
+        }
+        displays.extend(device_displays);
+    }
+
+    status_result(unsafe { gsync::NvAPI_GSync_SetSyncStateSettings(displays.len() as u32, displays.as_mut_ptr(), 0) })
+}
@@ -1,7 +1,82 @@
+use std::fmt;
+use std::sync::OnceLock;
 use crate::sys;
 use log::trace;
+use serde::{Serialize, Deserialize};
 use crate::types::RawConversion;
 
+pub use sys::system::ChipsetInfoFlags;
+
+/// The system chipset, as reported by `NvAPI_SYS_GetChipSetInfo`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ChipsetInfo {
+    pub vendor_id: u32,
+    pub device_id: u32,
+    pub vendor_name: String,
+    pub chipset_name: String,
+    pub flags: ChipsetInfoFlags,
+}
+
+impl fmt::Display for ChipsetInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.vendor_name, self.chipset_name)
+    }
+}
+
+impl RawConversion for sys::system::NV_CHIPSET_INFO {
+    type Target = ChipsetInfo;
+    type Error = std::convert::Infallible;
+
+    fn convert_raw(&self) -> Result<Self::Target, Self::Error> {
+        trace!("convert_raw({:#?})", self);
+        Ok(ChipsetInfo {
+            vendor_id: self.vendorId,
+            device_id: self.deviceId,
+            vendor_name: sys::types::short_string_to_string(&self.szVendorName),
+            chipset_name: sys::types::short_string_to_string(&self.szChipsetName),
+            flags: ChipsetInfoFlags::from_bits_truncate(self.flags),
+        })
+    }
+}
+
+/// A driver version such as 560.29, as packed into the integer returned by `driver_version()`.
+///
+/// Versions order numerically, so they can be compared against a minimum supported driver.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Copy, Clone, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub struct DriverVersion {
+    pub major: u32,
+    pub minor: u32,
+}
+
+impl DriverVersion {
+    pub fn new(major: u32, minor: u32) -> Self {
+        DriverVersion { major, minor }
+    }
+
+    /// Splits a packed version, e.g. 56029 into 560.29.
+    pub fn from_raw(raw: u32) -> Self {
+        DriverVersion::new(raw / 100, raw % 100)
+    }
+
+    pub fn raw(&self) -> u32 {
+        self.major * 100 + self.minor
+    }
+}
+
+impl fmt::Display for DriverVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{:02}", self.major, self.minor)
+    }
+}
+
+impl From<u32> for DriverVersion {
+    fn from(raw: u32) -> Self {
+        DriverVersion::from_raw(raw)
+    }
+}
+
 pub fn driver_version() -> sys::Result<(u32, String)> {
     trace!("driver_version()");
     let mut str = sys::types::short_string();
@@ -12,6 +87,31 @@ pub fn driver_version() -> sys::Result<(u32, String)> {
     }
 }
 
+/// The installed driver version, without the branch string of `driver_version()`.
+pub fn driver_version_info() -> sys::Result<DriverVersion> {
+    driver_version().map(|(version, _)| DriverVersion::from_raw(version))
+}
+
+/// Returns the system chipset.
+///
+/// Older drivers that reject the current structure version are retried with the V3 through V1
+/// layouts, leaving the fields they do not know about zeroed.
+pub fn chipset_info() -> sys::Result<ChipsetInfo> {
+    trace!("chipset_info()");
+    let versions = [
+        sys::system::NV_CHIPSET_INFO_VER_4,
+        sys::system::NV_CHIPSET_INFO_VER_3,
+        sys::system::NV_CHIPSET_INFO_VER_2,
+        sys::system::NV_CHIPSET_INFO_VER_1,
+    ];
+    sys::with_version_fallback(&versions, |version| {
+        let mut data = sys::system::NV_CHIPSET_INFO::zeroed();
+        data.version = version;
+        sys::status_result(unsafe { sys::system::NvAPI_SYS_GetChipSetInfo(&mut data) })
+            .map(|_| data)
+    }).and_then(|data| data.convert_raw().map_err(From::from))
+}
+
 pub fn interface_version() -> sys::Result<String> {
     trace!("interface_version()");
     let mut str = sys::types::short_string();
@@ -21,6 +121,43 @@ pub fn interface_version() -> sys::Result<String> {
     }
 }
 
+/// The driver and NVAPI versions, as returned by `system_info()`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SystemInfo {
+    pub driver: DriverVersion,
+    /// The driver branch, e.g. `r560_94`
+    pub branch: String,
+    pub interface: String,
+}
+
+impl fmt::Display for SystemInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Driver {} ({}), {}", self.driver, self.branch, self.interface)
+    }
+}
+
+/// Returns `driver_version()` and `interface_version()` together.
+///
+/// The versions cannot change while the driver is loaded, so the first successful result is
+/// cached for the lifetime of the process.
+pub fn system_info() -> sys::Result<SystemInfo> {
+    static SYSTEM_INFO: OnceLock<SystemInfo> = OnceLock::new();
+
+    trace!("system_info()");
+    if let Some(info) = SYSTEM_INFO.get() {
+        return Ok(info.clone())
+    }
+
+    let (driver, branch) = driver_version()?;
+    let info = SystemInfo {
+        driver: DriverVersion::from_raw(driver),
+        branch,
+        interface: interface_version()?,
+    };
+    Ok(SYSTEM_INFO.get_or_init(|| info).clone())
+}
+
 pub fn error_message(status: sys::Status) -> sys::Result<String> {
     trace!("error_message({:?})", status);
     let mut str = sys::types::short_string();
@@ -30,6 +167,13 @@ pub fn error_message(status: sys::Status) -> sys::Result<String> {
     }
 }
 
+/// Whether the NVAPI library of an NVIDIA driver can be loaded, without initializing it.
+pub fn is_available() -> bool {
+    trace!("is_available()");
+    sys::nvapi_QueryInterface(sys::Api::NvAPI_Initialize.id()).is_ok()
+}
+
+/// Fails with `Status::LibraryNotFound` when no NVIDIA driver is installed.
 pub fn initialize() -> sys::Result<()> {
     trace!("initialize()");
     unsafe {
@@ -9,9 +9,16 @@ mod clock;
 mod thermal;
 mod gpu;
 mod gsync;
+mod mosaic;
+mod display;
 mod info;
+mod scene;
 #[cfg(feature = "i2c")]
 mod i2c_impl;
+#[cfg(feature = "tokio")]
+mod tokio_impl;
+#[cfg(all(windows, feature = "d3d"))]
+pub mod d3d;
 
 pub use types::*;
 pub use pstate::*;
@@ -20,6 +27,9 @@ pub use thermal::*;
 pub use gpu::*;
 pub use info::*;
 pub use gsync::*;
+pub use mosaic::*;
+pub use display::*;
+pub use scene::*;
 #[cfg(feature = "i2c")]
 pub use i2c_impl::*;
 
@@ -0,0 +1,350 @@
+use std::ptr;
+use log::{trace, warn};
+use crate::sys::{self, status_result, NV_RECT};
+use crate::sys::mosaic::{self, NV_MOSAIC_TOPO_BRIEF, NV_MOSAIC_DISPLAY_SETTING, NV_MOSAIC_GRID_TOPO};
+use crate::types::{DisplayId, Range};
+
+pub use sys::mosaic::{MosaicGridFlags, MosaicTopo, MosaicTopoType, MosaicTopoValidity};
+
+/// Overlap axis of a Mosaic topology.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Axis {
+    X,
+    Y,
+}
+
+/// Access to the system-wide Mosaic configuration.
+#[derive(Debug)]
+pub struct Mosaic;
+
+impl Mosaic {
+    pub fn get_supported_topo_info(kind: MosaicTopoType) -> crate::Result<mosaic::NV_MOSAIC_SUPPORTED_TOPO_INFO> {
+        trace!("mosaic.get_supported_topo_info({:?})", kind);
+        let mut info = mosaic::NV_MOSAIC_SUPPORTED_TOPO_INFO::zeroed();
+        info.version = mosaic::NVAPI_MOSAIC_SUPPORTED_TOPO_INFO_VER;
+        status_result(unsafe { mosaic::NvAPI_Mosaic_GetSupportedTopoInfo(&mut info, kind.raw()) })
+            .map(|_| info)
+    }
+
+    /// Picks the display setting of `info` with the given resolution and the lowest refresh
+    /// rate of at least `min_refresh_hz`, preferring a higher color depth among equal rates.
+    ///
+    /// Rates are compared in mHz using `rrx1k`, so that fractional rates such as 119.88 Hz can
+    /// be told apart from 120 Hz.
+    pub fn find_display_setting(info: &mosaic::NV_MOSAIC_SUPPORTED_TOPO_INFO, width: u32, height: u32, min_refresh_hz: f64) -> Option<NV_MOSAIC_DISPLAY_SETTING> {
+        trace!("mosaic.find_display_setting({}x{}, {})", width, height, min_refresh_hz);
+        let min_rrx1k = (min_refresh_hz * 1000.0).round() as u32;
+        let rrx1k = |setting: &NV_MOSAIC_DISPLAY_SETTING| match setting.rrx1k {
+            0 => setting.freq * 1000,
+            rrx1k => rrx1k,
+        };
+
+        info.display_settings().iter()
+            .filter(|setting| setting.width == width && setting.height == height && rrx1k(setting) >= min_rrx1k)
+            .min_by_key(|setting| (rrx1k(setting), std::cmp::Reverse(setting.bpp)))
+            .cloned()
+    }
+
+    pub fn get_topology_details(brief: &NV_MOSAIC_TOPO_BRIEF) -> crate::Result<mosaic::NV_MOSAIC_TOPO_GROUP> {
+        trace!("mosaic.get_topology_details({:?})", brief.topo);
+        let mut brief = *brief;
+        let mut group = mosaic::NV_MOSAIC_TOPO_GROUP::zeroed();
+        group.version = mosaic::NVAPI_MOSAIC_TOPO_GROUP_VER;
+        status_result(unsafe { mosaic::NvAPI_Mosaic_GetTopoGroup(&mut brief, &mut group) })
+            .map(|_| group)
+    }
+
+    /// Returns the allowed X and Y overlap for the given topology and display settings.
+    pub fn get_overlap_limits(brief: &NV_MOSAIC_TOPO_BRIEF, settings: &NV_MOSAIC_DISPLAY_SETTING) -> crate::Result<(Range<i32>, Range<i32>)> {
+        trace!("mosaic.get_overlap_limits({:?})", brief.topo);
+        let mut brief = *brief;
+        let mut settings = *settings;
+        let (mut x, mut y) = (Range::default(), Range::default());
+        status_result(unsafe { mosaic::NvAPI_Mosaic_GetOverlapLimits(&mut brief, &mut settings, &mut x.min, &mut x.max, &mut y.min, &mut y.max) })
+            .map(|_| (x, y))
+    }
+
+    /// Clamps the X and Y overlap to `get_overlap_limits`, returning the clamped values and whether
+    /// either one was out of range.
+    pub fn clamp_overlap(brief: &NV_MOSAIC_TOPO_BRIEF, settings: &NV_MOSAIC_DISPLAY_SETTING, x: i32, y: i32) -> crate::Result<(i32, i32, bool)> {
+        trace!("mosaic.clamp_overlap({:?}, {}, {})", brief.topo, x, y);
+        let (limits_x, limits_y) = Self::get_overlap_limits(brief, settings)?;
+        let (clamped_x, clamped_y) = (limits_x.clamp_value(x), limits_y.clamp_value(y));
+        Ok((clamped_x, clamped_y, (clamped_x, clamped_y) != (x, y)))
+    }
+
+    /// Returns the current topology, its display settings and the X and Y overlap.
+    pub fn get_current_topology() -> crate::Result<(NV_MOSAIC_TOPO_BRIEF, NV_MOSAIC_DISPLAY_SETTING, i32, i32)> {
+        trace!("mosaic.get_current_topology()");
+        let mut brief = NV_MOSAIC_TOPO_BRIEF::zeroed();
+        brief.version = mosaic::NVAPI_MOSAIC_TOPO_BRIEF_VER;
+        let mut settings = NV_MOSAIC_DISPLAY_SETTING::zeroed();
+        settings.version = mosaic::NVAPI_MOSAIC_DISPLAY_SETTING_VER;
+        let (mut overlap_x, mut overlap_y) = (0, 0);
+        status_result(unsafe { mosaic::NvAPI_Mosaic_GetCurrentTopo(&mut brief, &mut settings, &mut overlap_x, &mut overlap_y) })
+            .map(|_| (brief, settings, overlap_x, overlap_y))
+    }
+
+    pub fn set_current_topology(brief: &NV_MOSAIC_TOPO_BRIEF, settings: &NV_MOSAIC_DISPLAY_SETTING, overlap_x: i32, overlap_y: i32, enable: bool) -> crate::Result<()> {
+        trace!("mosaic.set_current_topology({:?}, {}, {}, {})", brief.topo, overlap_x, overlap_y, enable);
+        let mut brief = *brief;
+        let mut settings = *settings;
+        status_result(unsafe { mosaic::NvAPI_Mosaic_SetCurrentTopo(&mut brief, &mut settings, overlap_x, overlap_y, enable as u32) })
+    }
+
+    /// Enables a passive stereo topology, with one grid for each eye.
+    ///
+    /// Returns `InvalidArgument` if `brief` is not a passive stereo topology or its group does not
+    /// provide both a left and a right eye topology.
+    pub fn set_passive_stereo(brief: &NV_MOSAIC_TOPO_BRIEF, settings: &NV_MOSAIC_DISPLAY_SETTING, overlap_x: i32, overlap_y: i32) -> crate::Result<()> {
+        trace!("mosaic.set_passive_stereo({:?}, {}, {})", brief.topo, overlap_x, overlap_y);
+        if !brief.is_passive_stereo() {
+            return Err(sys::Status::InvalidArgument)
+        }
+
+        let group = Self::get_topology_details(brief)?;
+        let topos = group.topos();
+        if topos.len() != mosaic::NV_MOSAIC_TOPO_NUM_EYES
+            || topos[mosaic::NV_MOSAIC_TOPO_IDX_LEFT_EYE].rowCount == 0
+            || topos[mosaic::NV_MOSAIC_TOPO_IDX_RIGHT_EYE].rowCount == 0 {
+            return Err(sys::Status::InvalidArgument)
+        }
+
+        Self::set_current_topology(brief, settings, overlap_x, overlap_y, true)
+    }
+
+    pub fn enable_current_topology(enable: bool) -> crate::Result<()> {
+        trace!("mosaic.enable_current_topology({})", enable);
+        status_result(unsafe { mosaic::NvAPI_Mosaic_EnableCurrentTopo(enable as u32) })
+    }
+
+    /// Disables the current topology until the returned guard is restored or dropped.
+    ///
+    /// If no topology is enabled, nothing is changed and the guard does nothing either.
+    pub fn disable_topology() -> crate::Result<MosaicRestoreGuard> {
+        trace!("mosaic.disable_topology()");
+        let (brief, settings, overlap_x, overlap_y) = Self::get_current_topology()?;
+        let enabled = brief.enabled != 0;
+        if enabled {
+            Self::enable_current_topology(false)?;
+        }
+
+        Ok(MosaicRestoreGuard {
+            brief,
+            settings,
+            overlap_x,
+            overlap_y,
+            pending: enabled,
+        })
+    }
+
+    /// Runs `f` with the current topology disabled, re-enabling it afterwards even if `f` panics.
+    pub fn with_disabled_topology<T, F: FnOnce() -> T>(f: F) -> crate::Result<T> {
+        let guard = Self::disable_topology()?;
+        let res = f();
+        guard.restore().map(|_| res)
+    }
+
+    /// Enumerates the active grid topologies, including single displays.
+    pub fn enum_display_grids() -> crate::Result<Vec<NV_MOSAIC_GRID_TOPO>> {
+        trace!("mosaic.enum_display_grids()");
+        let mut count = 0;
+        status_result(unsafe { mosaic::NvAPI_Mosaic_EnumDisplayGrids(ptr::null_mut(), &mut count) })?;
+
+        // older drivers only fill in V1 grids, which have a different layout
+        sys::with_version_fallback(&[mosaic::NV_MOSAIC_GRID_TOPO_VER2, mosaic::NV_MOSAIC_GRID_TOPO_VER1], |version| {
+            let mut count = count;
+            if version == mosaic::NV_MOSAIC_GRID_TOPO_VER1 {
+                let mut grids = vec![mosaic::NV_MOSAIC_GRID_TOPO_V1::zeroed(); count as usize];
+                for grid in &mut grids {
+                    grid.version = version;
+                }
+                status_result(unsafe { mosaic::NvAPI_Mosaic_EnumDisplayGrids(grids.as_mut_ptr() as *mut _, &mut count) })?;
+                Ok(grids.into_iter().take(count as usize).map(From::from).collect())
+            } else {
+                let mut grids = vec![NV_MOSAIC_GRID_TOPO::zeroed(); count as usize];
+                for grid in &mut grids {
+                    grid.version = version;
+                }
+                status_result(unsafe { mosaic::NvAPI_Mosaic_EnumDisplayGrids(grids.as_mut_ptr(), &mut count) })?;
+                grids.truncate(count as usize);
+                Ok(grids)
+            }
+        })
+    }
+
+    /// Builds a grid of `rows` by `columns` displays, filled in row-major order from `display_ids`.
+    ///
+    /// Returns `InvalidArgument` unless there is exactly one display per cell, up to
+    /// `NV_MOSAIC_MAX_DISPLAYS` displays.
+    pub fn build_grid<D: Into<DisplayId> + Copy>(rows: u32, columns: u32, display_ids: &[D], settings: &NV_MOSAIC_DISPLAY_SETTING, flags: u32) -> crate::Result<NV_MOSAIC_GRID_TOPO> {
+        let count = rows as usize * columns as usize;
+        if count == 0 || count != display_ids.len() || count > mosaic::NV_MOSAIC_MAX_DISPLAYS {
+            return Err(sys::Status::InvalidArgument)
+        }
+
+        let mut grid = NV_MOSAIC_GRID_TOPO::zeroed();
+        grid.version = mosaic::NV_MOSAIC_GRID_TOPO_VER;
+        grid.rows = rows;
+        grid.columns = columns;
+        grid.displayCount = count as u32;
+        grid.flags = flags;
+        for (display, &id) in grid.displays.iter_mut().zip(display_ids) {
+            display.version = mosaic::NV_MOSAIC_GRID_TOPO_DISPLAY_VER;
+            display.displayId = id.into().0;
+        }
+        grid.displaySettings = settings.v1;
+        grid.displaySettings.version = mosaic::NVAPI_MOSAIC_DISPLAY_SETTING_VER1;
+        Ok(grid)
+    }
+
+    pub fn set_display_grids(grids: &mut [NV_MOSAIC_GRID_TOPO], flags: u32) -> crate::Result<()> {
+        trace!("mosaic.set_display_grids({}, {:#x})", grids.len(), flags);
+        status_result(unsafe { mosaic::NvAPI_Mosaic_SetDisplayGrids(grids.as_mut_ptr(), grids.len() as u32, flags) })
+    }
+
+    /// Validates the grids without applying them, returning one status per grid.
+    pub fn validate_display_grids(grids: &mut [NV_MOSAIC_GRID_TOPO], flags: u32) -> crate::Result<Vec<mosaic::NV_MOSAIC_DISPLAY_TOPO_STATUS>> {
+        trace!("mosaic.validate_display_grids({}, {:#x})", grids.len(), flags);
+        let mut status = vec![mosaic::NV_MOSAIC_DISPLAY_TOPO_STATUS::zeroed(); grids.len()];
+        for s in &mut status {
+            s.version = mosaic::NV_MOSAIC_DISPLAY_TOPO_STATUS_VER;
+        }
+        status_result(unsafe { mosaic::NvAPI_Mosaic_ValidateDisplayGrids(flags, grids.as_mut_ptr(), status.as_mut_ptr(), grids.len() as u32) })
+            .map(|_| status)
+    }
+
+    /// Validates the grids with `validate_display_grids` and only applies them if no grid reports
+    /// `errorFlags`.
+    ///
+    /// When validation fails, nothing is applied and the status of every grid is returned as the
+    /// inner error.
+    pub fn apply_grids_validated(grids: &mut [NV_MOSAIC_GRID_TOPO], flags: u32) -> crate::Result<Result<(), Vec<mosaic::NV_MOSAIC_DISPLAY_TOPO_STATUS>>> {
+        trace!("mosaic.apply_grids_validated({}, {:#x})", grids.len(), flags);
+        let status = Self::validate_display_grids(grids, flags)?;
+        if status.iter().any(|s| s.errorFlags != 0) {
+            return Ok(Err(status))
+        }
+
+        Self::set_display_grids(grids, flags).map(Ok)
+    }
+
+    /// Returns the ID and refresh rate in Hz of every display that is part of a Mosaic grid.
+    ///
+    /// Grids only carry V1 display settings, so rates are whole Hz rather than `rrx1k`.
+    pub fn display_refresh_rates() -> crate::Result<Vec<(DisplayId, f64)>> {
+        trace!("mosaic.display_refresh_rates()");
+        Ok(Self::enum_display_grids()?.iter()
+            .filter(|grid| grid.displayCount > 1)
+            .flat_map(|grid| grid.displays().iter().map(move |display| (DisplayId(display.displayId), grid.displaySettings.freq as f64)))
+            .collect())
+    }
+
+    /// Applies each overlap value within `get_overlap_limits` along `axis`, in increments of `step`,
+    /// and records the viewports of the resulting Mosaic display.
+    ///
+    /// NVAPI has no way to preview a topology, so every value performs a mode set. The topology that
+    /// was current before the sweep is restored afterwards, even if the sweep fails.
+    pub fn sweep_overlap(brief: &NV_MOSAIC_TOPO_BRIEF, settings: &NV_MOSAIC_DISPLAY_SETTING, axis: Axis, step: u32) -> crate::Result<Vec<(i32, Vec<NV_RECT>)>> {
+        trace!("mosaic.sweep_overlap({:?}, {:?}, {})", brief.topo, axis, step);
+        if step == 0 {
+            return Err(sys::Status::InvalidArgument)
+        }
+
+        let (limits_x, limits_y) = Self::get_overlap_limits(brief, settings)?;
+        let limits = match axis {
+            Axis::X => limits_x,
+            Axis::Y => limits_y,
+        };
+
+        let (current, current_settings, current_x, current_y) = Self::get_current_topology()?;
+        let result: crate::Result<Vec<_>> = (limits.min..=limits.max).step_by(step as usize).map(|overlap| {
+            let (x, y) = match axis {
+                Axis::X => (overlap, 0),
+                Axis::Y => (0, overlap),
+            };
+            Self::set_current_topology(brief, settings, x, y, true)?;
+            Self::mosaic_viewports().map(|viewports| (overlap, viewports))
+        }).collect();
+
+        let restored = if current.topo == mosaic::NV_MOSAIC_TOPO_NONE {
+            Self::enable_current_topology(false)
+        } else {
+            Self::set_current_topology(&current, &current_settings, current_x, current_y, current.enabled != 0)
+        };
+
+        result.and_then(|r| restored.map(|_| r))
+    }
+
+    /// The viewport of each display in the Mosaic grid containing `display_id`, at the given source
+    /// resolution, along with whether the viewports are bezel corrected.
+    ///
+    /// A resolution of `0x0` uses the current resolution. Unused entries are left out.
+    pub fn get_display_viewports_by_resolution(display_id: impl Into<DisplayId>, width: u32, height: u32) -> crate::Result<(Vec<NV_RECT>, bool)> {
+        let display_id = display_id.into();
+        trace!("mosaic.get_display_viewports_by_resolution({}, {}, {})", display_id, width, height);
+        let mut viewports = [NV_RECT::zeroed(); mosaic::NV_MOSAIC_MAX_DISPLAYS];
+        let mut bezel_corrected = 0;
+        status_result(unsafe { mosaic::NvAPI_Mosaic_GetDisplayViewportsByResolution(display_id.0, width, height, &mut viewports, &mut bezel_corrected) })
+            .map(|_| (viewports.iter().cloned().filter(|v| !v.is_empty()).collect(), bezel_corrected != 0))
+    }
+
+    /// The first viewport returned by `get_display_viewports_by_resolution`.
+    pub fn get_display_viewports_by_resolution_single(display_id: impl Into<DisplayId>, width: u32, height: u32) -> crate::Result<NV_RECT> {
+        Self::get_display_viewports_by_resolution(display_id, width, height)
+            .and_then(|(viewports, _)| viewports.first().cloned().ok_or(sys::Status::MosaicNotActive))
+    }
+
+    /// The viewports of the Mosaic grid containing `display_id` at its current resolution.
+    pub fn get_current_display_viewports(display_id: impl Into<DisplayId>) -> crate::Result<(Vec<NV_RECT>, bool)> {
+        Self::get_display_viewports_by_resolution(display_id, 0, 0)
+    }
+
+    /// Viewports at the current resolution of the first multi-display grid.
+    fn mosaic_viewports() -> crate::Result<Vec<NV_RECT>> {
+        let display_id = Self::enum_display_grids()?.iter()
+            .find(|grid| grid.displayCount > 1)
+            .map(|grid| grid.displays[0].displayId)
+            .ok_or(sys::Status::MosaicNotActive)?;
+
+        Self::get_current_display_viewports(display_id)
+            .map(|(viewports, _)| viewports)
+    }
+}
+
+/// Re-enables a topology disabled by `Mosaic::disable_topology`.
+///
+/// Dropping the guard restores the topology as well, but can only log a failure.
+#[derive(Debug)]
+#[must_use]
+pub struct MosaicRestoreGuard {
+    brief: NV_MOSAIC_TOPO_BRIEF,
+    settings: NV_MOSAIC_DISPLAY_SETTING,
+    overlap_x: i32,
+    overlap_y: i32,
+    pending: bool,
+}
+
+impl MosaicRestoreGuard {
+    /// Re-applies the captured topology, display settings and overlap.
+    pub fn restore(mut self) -> crate::Result<()> {
+        self.restore_inner()
+    }
+
+    fn restore_inner(&mut self) -> crate::Result<()> {
+        if !self.pending {
+            return Ok(())
+        }
+
+        self.pending = false;
+        Mosaic::set_current_topology(&self.brief, &self.settings, self.overlap_x, self.overlap_y, true)
+    }
+}
+
+impl Drop for MosaicRestoreGuard {
+    fn drop(&mut self) {
+        if let Err(e) = self.restore_inner() {
+            warn!("failed to restore Mosaic topology {:?}: {:?}", self.brief.topo, e);
+        }
+    }
+}
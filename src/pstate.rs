@@ -1,4 +1,5 @@
 use std::collections::BTreeMap;
+use std::fmt;
 use std::convert::Infallible;
 use log::trace;
 use crate::sys::gpu::pstate;
@@ -168,6 +169,50 @@ impl RawConversion for pstate::NV_GPU_PERF_PSTATES20_PARAM_DELTA {
     }
 }
 
+/// Per-domain utilization, `None` for domains that are not present on the GPU.
+#[derive(Debug, Copy, Clone, Default, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct UtilizationDomains {
+    pub gpu: Option<u8>,
+    pub frame_buffer: Option<u8>,
+    pub video_engine: Option<u8>,
+    pub bus: Option<u8>,
+}
+
+impl UtilizationDomains {
+    pub fn from_raw(info: &pstate::NV_GPU_DYNAMIC_PSTATES_INFO_EX) -> Self {
+        let get = |domain: UtilizationDomain| {
+            let util = &info.utilization[domain.raw() as usize];
+            if util.bIsPresent.get() {
+                Some(util.percentage.min(100) as u8)
+            } else {
+                None
+            }
+        };
+
+        UtilizationDomains {
+            gpu: get(UtilizationDomain::Graphics),
+            frame_buffer: get(UtilizationDomain::FrameBuffer),
+            video_engine: get(UtilizationDomain::VideoEngine),
+            bus: get(UtilizationDomain::BusInterface),
+        }
+    }
+}
+
+impl fmt::Display for UtilizationDomains {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let domains = [("GPU", self.gpu), ("FB", self.frame_buffer), ("VID", self.video_engine), ("BUS", self.bus)];
+        let mut first = true;
+        for (name, value) in domains.iter().filter_map(|&(name, value)| value.map(|v| (name, v))) {
+            if !first {
+                write!(f, ", ")?;
+            }
+            first = false;
+            write!(f, "{} {}%", name, value)?;
+        }
+        Ok(())
+    }
+}
+
 impl RawConversion for pstate::NV_GPU_DYNAMIC_PSTATES_INFO_EX {
     type Target = BTreeMap<pstate::UtilizationDomain, Percentage>;
     type Error = sys::ArgumentRangeError;
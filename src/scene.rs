@@ -0,0 +1,114 @@
+use log::{trace, warn};
+use serde::{Serialize, Deserialize};
+use crate::sys::gsync::NVAPI_GSYNC_DISPLAY_SYNC_STATE_MASTER;
+use crate::sys::mosaic::NV_MOSAIC_GRID_TOPO;
+use crate::display::{Display, DisplayConfigFlags, DisplayConfigPath, HdrMode};
+use crate::gsync::{self, GSyncDevice};
+use crate::mosaic::Mosaic;
+use crate::types::DisplayId;
+use crate::sys::Status;
+
+/// Settings applied to a single display as part of a `Scene`.
+///
+/// `None` leaves the setting untouched.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct DisplayConfig {
+    pub display_id: DisplayId,
+    pub hdr_mode: Option<HdrMode>,
+    pub vibrance: Option<i32>,
+}
+
+/// A complete display setup: the modes and positions of all displays, per-display settings, the
+/// Mosaic grids, and the GSync master display of each sync device.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Default)]
+pub struct Scene {
+    /// Applied with `Display::set_config`, left untouched when empty.
+    pub display_config: Vec<DisplayConfigPath>,
+    pub displays: Vec<DisplayConfig>,
+    /// Applied with `Mosaic::set_display_grids`, left untouched when empty.
+    pub mosaic: Vec<NV_MOSAIC_GRID_TOPO>,
+    /// `(board_id, display_id)` pairs as taken by `gsync_set_all_masters`, left untouched when empty.
+    pub gsync_masters: Vec<(u64, DisplayId)>,
+}
+
+/// Captures the current scene.
+///
+/// Display settings the driver does not report are captured as `None`, and a system without
+/// sync devices has no GSync masters.
+pub fn capture_scene() -> crate::Result<Scene> {
+    trace!("capture_scene()");
+    let display_config = match Display::get_config() {
+        Err(Status::NotSupported) | Err(Status::NoImplementation) => Vec::new(),
+        res => res?,
+    };
+    let mosaic = Mosaic::enum_display_grids()?;
+
+    let displays = mosaic.iter()
+        .flat_map(|grid| grid.displays[..grid.displayCount as usize].iter())
+        .map(|display| DisplayConfig {
+            display_id: DisplayId(display.displayId),
+            hdr_mode: Display::hdr_mode(display.displayId).ok(),
+            vibrance: Display::vibrance(display.displayId).ok().map(|v| v.current),
+        }).collect();
+
+    let mut gsync_masters = Vec::new();
+    for device in GSyncDevice::get_sync_devices().unwrap_or_default() {
+        let board_id = device.board_id()? as u64;
+        if let Some(master) = device.get_displays()?.iter().find(|d| d.syncState == NVAPI_GSYNC_DISPLAY_SYNC_STATE_MASTER) {
+            gsync_masters.push((board_id, DisplayId(master.displayId)));
+        }
+    }
+
+    Ok(Scene {
+        display_config,
+        displays,
+        mosaic,
+        gsync_masters,
+    })
+}
+
+/// Applies a scene in dependency order: display modes and positions, display settings, then
+/// Mosaic grids, then GSync masters.
+///
+/// The current scene is captured first; if any step fails it is re-applied on a best-effort
+/// basis and the original error is returned.
+pub fn apply_scene(scene: &Scene) -> crate::Result<()> {
+    trace!("apply_scene({:?})", scene);
+    let previous = capture_scene()?;
+
+    let res = apply(scene);
+    if res.is_err() {
+        if let Err(rollback) = apply(&previous) {
+            warn!("apply_scene rollback failed: {:?}", rollback);
+        }
+    }
+    res
+}
+
+fn apply(scene: &Scene) -> crate::Result<()> {
+    if !scene.display_config.is_empty() {
+        Display::set_config(&scene.display_config, DisplayConfigFlags::empty())?;
+    }
+
+    for display in &scene.displays {
+        if let Some(mode) = display.hdr_mode {
+            Display::set_hdr_mode(display.display_id, mode)?;
+        }
+        if let Some(level) = display.vibrance {
+            Display::set_vibrance(display.display_id, level)?;
+        }
+    }
+
+    if !scene.mosaic.is_empty() {
+        let mut grids = scene.mosaic.clone();
+        Mosaic::set_display_grids(&mut grids, 0)?;
+    }
+
+    if !scene.gsync_masters.is_empty() {
+        gsync::gsync_set_all_masters(&scene.gsync_masters)?;
+    }
+
+    Ok(())
+}
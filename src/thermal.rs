@@ -1,5 +1,6 @@
+use std::fmt;
 use log::trace;
-use crate::sys::gpu::{thermal, cooler};
+use crate::sys::gpu::{thermal, cooler, fan};
 use crate::sys;
 use crate::types::{Percentage, Celsius, CelsiusShifted, Range, RawConversion};
 
@@ -41,6 +42,50 @@ impl RawConversion for thermal::NV_GPU_THERMAL_SETTINGS {
     }
 }
 
+/// The current temperature of a sensor, as displayed in e.g. "Core: 45 °C".
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ThermalSensor {
+    pub target: ThermalTarget,
+    pub current: Celsius,
+}
+
+impl From<Sensor> for ThermalSensor {
+    fn from(sensor: Sensor) -> Self {
+        ThermalSensor {
+            target: sensor.target,
+            current: sensor.current_temperature,
+        }
+    }
+}
+
+impl fmt::Display for ThermalSensor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.target, self.current)
+    }
+}
+
+/// How far the GPU core is from its thermal limit, as displayed in e.g.
+/// "12 °C headroom, not throttling".
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ThermalHeadroom {
+    pub current: Celsius,
+    pub limit: Celsius,
+    pub throttling: bool,
+}
+
+impl ThermalHeadroom {
+    /// Degrees left before the limit is reached, negative once it is exceeded.
+    pub fn margin(&self) -> Celsius {
+        Celsius(self.limit.0 - self.current.0)
+    }
+}
+
+impl fmt::Display for ThermalHeadroom {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} headroom, {}", self.margin(), if self.throttling { "throttling" } else { "not throttling" })
+    }
+}
+
 #[derive(Debug, Copy, Clone)]
 pub struct ThermalInfo {
     pub controller: ThermalController,
@@ -236,3 +281,72 @@ impl RawConversion for cooler::private::NV_GPU_COOLER_POLICY_TABLE {
         })
     }
 }
+
+pub use sys::gpu::fan::private::FanCoolerControlMode;
+
+#[derive(Debug, Copy, Clone)]
+pub struct FanCoolerStatus {
+    pub cooler_id: u32,
+    pub rpm: u32,
+    pub level_range: Range<Percentage>,
+    pub level: Percentage,
+}
+
+impl RawConversion for fan::private::NV_GPU_CLIENT_FAN_COOLERS_STATUS_ENTRY {
+    type Target = FanCoolerStatus;
+    type Error = sys::ArgumentRangeError;
+
+    fn convert_raw(&self) -> Result<Self::Target, Self::Error> {
+        trace!("convert_raw({:#?})", self);
+        Ok(FanCoolerStatus {
+            cooler_id: self.coolerId,
+            rpm: self.currentRpm,
+            level_range: Range {
+                min: Percentage::from_raw(self.currentMinLevel)?,
+                max: Percentage::from_raw(self.currentMaxLevel)?,
+            },
+            level: Percentage::from_raw(self.currentLevel)?,
+        })
+    }
+}
+
+impl RawConversion for fan::private::NV_GPU_CLIENT_FAN_COOLERS_STATUS {
+    type Target = Vec<FanCoolerStatus>;
+    type Error = sys::ArgumentRangeError;
+
+    fn convert_raw(&self) -> Result<Self::Target, Self::Error> {
+        trace!("convert_raw({:#?})", self);
+        self.entries[..self.count as usize].iter().map(RawConversion::convert_raw).collect()
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct FanCoolerControl {
+    pub cooler_id: u32,
+    pub level: Percentage,
+    pub mode: FanCoolerControlMode,
+}
+
+impl RawConversion for fan::private::NV_GPU_CLIENT_FAN_COOLERS_CONTROL_ENTRY {
+    type Target = FanCoolerControl;
+    type Error = sys::ArgumentRangeError;
+
+    fn convert_raw(&self) -> Result<Self::Target, Self::Error> {
+        trace!("convert_raw({:#?})", self);
+        Ok(FanCoolerControl {
+            cooler_id: self.coolerId,
+            level: Percentage::from_raw(self.level)?,
+            mode: FanCoolerControlMode::from_raw(self.controlMode)?,
+        })
+    }
+}
+
+impl RawConversion for fan::private::NV_GPU_CLIENT_FAN_COOLERS_CONTROL {
+    type Target = Vec<FanCoolerControl>;
+    type Error = sys::ArgumentRangeError;
+
+    fn convert_raw(&self) -> Result<Self::Target, Self::Error> {
+        trace!("convert_raw({:#?})", self);
+        self.entries[..self.count as usize].iter().map(RawConversion::convert_raw).collect()
+    }
+}
@@ -0,0 +1,38 @@
+use std::sync::Arc;
+use std::time::Duration;
+use log::trace;
+use tokio::sync::mpsc;
+use tokio_stream::Stream;
+use tokio_stream::wrappers::ReceiverStream;
+use crate::{GpuSensors, PhysicalGpu};
+
+impl PhysicalGpu {
+    /// Polls `sensors()` every `interval` on a blocking thread, so that the async caller is not
+    /// stalled by the driver.
+    ///
+    /// Must be called from within a tokio runtime. Polling stops once the stream is dropped, and
+    /// ticks are delayed rather than queued when a reading takes longer than the interval.
+    pub fn sensors_stream(&self, interval: Duration) -> impl Stream<Item = GpuSensors> {
+        trace!("gpu.sensors_stream({:?})", interval);
+        let gpu = Arc::new(PhysicalGpu::new(*self.handle()));
+        let (tx, rx) = mpsc::channel(1);
+
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                ticker.tick().await;
+                let gpu = gpu.clone();
+                let sensors = match tokio::task::spawn_blocking(move || gpu.sensors()).await {
+                    Ok(sensors) => sensors,
+                    Err(_) => break,
+                };
+                if tx.send(sensors).await.is_err() {
+                    break
+                }
+            }
+        });
+
+        ReceiverStream::new(rx)
+    }
+}
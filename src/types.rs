@@ -1,4 +1,3 @@
-use std::ffi::CStr;
 use std::{fmt, ops};
 use std::convert::Infallible;
 use serde::{Serialize, Deserialize};
@@ -16,9 +15,38 @@ impl RawConversion for sys::types::NvAPI_ShortString {
     type Error = Infallible;
 
     fn convert_raw(&self) -> Result<Self::Target, Self::Error> {
-        unsafe {
-            Ok(CStr::from_ptr(self.as_ptr()).to_string_lossy().into_owned())
-        }
+        Ok(sys::short_string_to_string(self))
+    }
+}
+
+/// A display ID as used by the display, Mosaic and GSync APIs.
+///
+/// Display IDs are opaque and stable across enumerations, unlike display handles or indices.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq, Default)]
+pub struct DisplayId(pub u32);
+
+impl fmt::Display for DisplayId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:#010x}", self.0)
+    }
+}
+
+impl fmt::Debug for DisplayId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl From<u32> for DisplayId {
+    fn from(id: u32) -> Self {
+        DisplayId(id)
+    }
+}
+
+impl From<DisplayId> for u32 {
+    fn from(id: DisplayId) -> Self {
+        id.0
     }
 }
 
@@ -28,7 +56,7 @@ pub struct Celsius(pub i32);
 
 impl fmt::Display for Celsius {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}C", self.0)
+        write!(f, "{} \u{b0}C", self.0)
     }
 }
 
@@ -38,6 +66,28 @@ impl fmt::Debug for Celsius {
     }
 }
 
+impl Celsius {
+    pub fn get(&self) -> i32 {
+        self.0
+    }
+
+    pub fn fahrenheit(&self) -> f32 {
+        self.0 as f32 * 9.0 / 5.0 + 32.0
+    }
+}
+
+impl From<i32> for Celsius {
+    fn from(c: i32) -> Self {
+        Celsius(c)
+    }
+}
+
+impl From<Celsius> for i32 {
+    fn from(c: Celsius) -> Self {
+        c.0
+    }
+}
+
 /// Nvidia encodes temperature as `<< 8` for some reason sometimes.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq, Default)]
@@ -45,7 +95,7 @@ pub struct CelsiusShifted(pub i32);
 
 impl fmt::Display for CelsiusShifted {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}C", self.get())
+        write!(f, "{} \u{b0}C", self.get())
     }
 }
 
@@ -94,6 +144,34 @@ impl fmt::Debug for Microvolts {
     }
 }
 
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq, Default)]
+pub struct Millivolts(pub u32);
+
+impl fmt::Display for Millivolts {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} mV", self.0)
+    }
+}
+
+impl fmt::Debug for Millivolts {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl From<Microvolts> for Millivolts {
+    fn from(v: Microvolts) -> Self {
+        Millivolts((v.0 + 500) / 1000)
+    }
+}
+
+impl From<Millivolts> for Microvolts {
+    fn from(v: Millivolts) -> Self {
+        Microvolts(v.0 * 1000)
+    }
+}
+
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq, Default)]
 pub struct MicrovoltsDelta(pub i32);
@@ -437,6 +515,23 @@ impl From<Percentage> for Percentage1000 {
     }
 }
 
+/// Approximate power, as derived from the relative readings NVAPI reports
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, PartialOrd, PartialEq, Default)]
+pub struct Watts(pub f32);
+
+impl fmt::Display for Watts {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:.*} W", f.precision().unwrap_or(0), self.0)
+    }
+}
+
+impl fmt::Debug for Watts {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq, Default)]
 pub struct Range<T> {
@@ -470,6 +565,11 @@ impl<T> Range<T> {
             max: v,
         }
     }
+
+    /// Limits `v` to `min..=max`.
+    pub fn clamp_value(&self, v: T) -> T where T: Ord + Clone {
+        v.max(self.min.clone()).min(self.max.clone())
+    }
 }
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
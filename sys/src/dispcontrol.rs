@@ -1,4 +1,5 @@
-use std::os::raw::c_char;
+use std::os::raw::{c_char, c_void};
+use crate::mosaic::NV_ROTATE;
 use crate::status::NvAPI_Status;
 use crate::handles;
 
@@ -42,3 +43,682 @@ nvapi_fn! {
     pub unsafe fn NvAPI_DISP_GetAssociatedUnAttachedNvidiaDisplayHandle;
 }
 
+nvapi_fn! {
+    pub type DISP_GetGDIPrimaryDisplayIdFn = extern "C" fn(displayId: *mut u32) -> NvAPI_Status;
+
+    /// This API returns the Display ID of the GDI Primary.
+    ///
+    /// Returns `NVAPI_NVIDIA_DEVICE_NOT_FOUND` if the GDI Primary is not on an NVIDIA GPU.
+    pub unsafe fn NvAPI_DISP_GetGDIPrimaryDisplayId;
+}
+
+
+nvenum! {
+    pub enum NV_HDR_CMD / HdrCmd {
+        /// Get current HDR output configuration
+        NV_HDR_CMD_GET / Get = 0,
+        /// Set HDR output configuration
+        NV_HDR_CMD_SET / Set = 1,
+    }
+}
+
+nvenum! {
+    pub enum NV_HDR_MODE / HdrMode {
+        /// Turn off HDR
+        NV_HDR_MODE_OFF / Off = 0,
+        /// Source: CCCS [a.k.a FP16 scRGB, linear, sRGB primaries, [-65504,0, 65504] range, RGB(1,1,1) = 80nits]
+        /// Output: UHDA HDR [a.k.a HDR10, RGB/YCC 10/12bpc ST2084(PQ) EOTF RGB(1,1,1) = 10000 nits, Rec2020 color primaries, ST2086 static HDR metadata].
+        /// This is the only supported production HDR mode.
+        NV_HDR_MODE_UHDA / Uhda = 2,
+        /// Source: CCCS (a.k.a FP16 scRGB) Output: EDR (Extended Dynamic Range) - HDR content is tonemapped and gamut mapped to output on regular SDR display set to max luminance ( ~300 nits ).
+        NV_HDR_MODE_EDR / Edr = 3,
+        /// Source: any Output: SDR (Standard Dynamic Range), we continuously send SDR EOTF InfoFrame signaling, HDMI compliance testing.
+        NV_HDR_MODE_SDR / Sdr = 4,
+        /// Source: HDR10 RGB 10bpc Output: HDR10 RGB 10 colorDepth - signal UHDA HDR mode (PQ + Rec2020) to the sink but send source pixel values unmodified (no PQ or Rec2020 conversions) - assumes source is already in HDR10 format.
+        NV_HDR_MODE_UHDA_PASSTHROUGH / UhdaPassthrough = 5,
+        /// Source: CCCS (a.k.a FP16 scRGB) Output: notebook HDR
+        NV_HDR_MODE_UHDA_NB / UhdaNb = 6,
+        /// Source: RGB8 Dolby Vision encoded (12 bpc YCbCr422 packed into RGB8) Output: Dolby Vision encoded : Application is to choose the Dolby Vision mode.
+        NV_HDR_MODE_DOLBY_VISION / DolbyVision = 7,
+    }
+}
+
+nvenum_display! {
+    HdrMode => {
+        Uhda = "HDR10",
+        UhdaPassthrough = "HDR10 Passthrough",
+        UhdaNb = "Notebook HDR",
+        DolbyVision = "Dolby Vision",
+        _ = _,
+    }
+}
+
+nvenum! {
+    pub enum NV_STATIC_METADATA_DESCRIPTOR_ID / StaticMetadataDescriptorId {
+        /// Tell the driver to use ST2086 static metadata (the only supported type).
+        NV_STATIC_METADATA_TYPE_1 / Type1 = 0,
+    }
+}
+
+nvenum! {
+    pub enum NV_COLOR_FORMAT / ColorFormat {
+        NV_COLOR_FORMAT_RGB / Rgb = 0,
+        NV_COLOR_FORMAT_YUV422 / Yuv422 = 1,
+        NV_COLOR_FORMAT_YUV444 / Yuv444 = 2,
+        NV_COLOR_FORMAT_YUV420 / Yuv420 = 3,
+        NV_COLOR_FORMAT_DEFAULT / Default = 0xfe,
+        NV_COLOR_FORMAT_AUTO / Auto = 0xff,
+    }
+}
+
+nvenum! {
+    pub enum NV_DYNAMIC_RANGE / DynamicRange {
+        NV_DYNAMIC_RANGE_VESA / Vesa = 0,
+        NV_DYNAMIC_RANGE_CEA / Cea = 1,
+        NV_DYNAMIC_RANGE_AUTO / Auto = 0xff,
+    }
+}
+
+nvenum! {
+    pub enum NV_BPC / Bpc {
+        NV_BPC_DEFAULT / Default = 0,
+        NV_BPC_6 / Bpc6 = 1,
+        NV_BPC_8 / Bpc8 = 2,
+        NV_BPC_10 / Bpc10 = 3,
+        NV_BPC_12 / Bpc12 = 4,
+        NV_BPC_16 / Bpc16 = 5,
+    }
+}
+
+nvstruct! {
+    /// ST2086 static metadata, an anonymous struct in the nvapi headers.
+    ///
+    /// Chromaticity coordinates are in units of 0.00002, max luminance values in units of
+    /// 1 cd/m^2 and the min luminance in units of 0.0001 cd/m^2.
+    pub struct NV_HDR_MASTERING_DISPLAY_DATA {
+        /// x coordinate of color primary 0 (e.g. Red) of mastering display
+        pub displayPrimary_x0: u16,
+        /// y coordinate of color primary 0 (e.g. Red) of mastering display
+        pub displayPrimary_y0: u16,
+        /// x coordinate of color primary 1 (e.g. Green) of mastering display
+        pub displayPrimary_x1: u16,
+        /// y coordinate of color primary 1 (e.g. Green) of mastering display
+        pub displayPrimary_y1: u16,
+        /// x coordinate of color primary 2 (e.g. Blue) of mastering display
+        pub displayPrimary_x2: u16,
+        /// y coordinate of color primary 2 (e.g. Blue) of mastering display
+        pub displayPrimary_y2: u16,
+        /// x coordinate of white point of mastering display
+        pub displayWhitePoint_x: u16,
+        /// y coordinate of white point of mastering display
+        pub displayWhitePoint_y: u16,
+        /// Maximum display mastering luminance
+        pub max_display_mastering_luminance: u16,
+        /// Minimum display mastering luminance
+        pub min_display_mastering_luminance: u16,
+        /// Maximum Content Light level (MaxCLL)
+        pub max_content_light_level: u16,
+        /// Maximum Frame-Average Light Level (MaxFALL)
+        pub max_frame_average_light_level: u16,
+    }
+}
+
+impl NV_HDR_MASTERING_DISPLAY_DATA {
+    /// BT.2020 primaries with a D65 white point, mastered at 0.0001 to 1000 cd/m^2.
+    pub fn bt2020() -> Self {
+        NV_HDR_MASTERING_DISPLAY_DATA {
+            displayPrimary_x0: 35400,
+            displayPrimary_y0: 14600,
+            displayPrimary_x1: 8500,
+            displayPrimary_y1: 39850,
+            displayPrimary_x2: 6550,
+            displayPrimary_y2: 2300,
+            displayWhitePoint_x: 15635,
+            displayWhitePoint_y: 16450,
+            max_display_mastering_luminance: 1000,
+            min_display_mastering_luminance: 1,
+            max_content_light_level: 1000,
+            max_frame_average_light_level: 400,
+        }
+    }
+}
+
+nvstruct! {
+    pub struct NV_HDR_COLOR_DATA_V1 {
+        /// Version of this structure
+        pub version: u32,
+        /// Command get/set
+        pub cmd: NV_HDR_CMD,
+        /// HDR mode
+        pub hdrMode: NV_HDR_MODE,
+        /// Static Metadata Descriptor Id (0 for static metadata type 1)
+        pub static_metadata_descriptor_id: NV_STATIC_METADATA_DESCRIPTOR_ID,
+        /// Static Metadata Descriptor Type 1, CEA-861.3, SMPTE ST2086
+        pub mastering_display_data: NV_HDR_MASTERING_DISPLAY_DATA,
+    }
+}
+
+const NV_HDR_COLOR_DATA_V1_SIZE: usize = 4 * 4 + 2 * 12;
+
+nvstruct! {
+    pub struct NV_HDR_COLOR_DATA_V2 {
+        pub v1: NV_HDR_COLOR_DATA_V1,
+        /// Optional, One of NV_COLOR_FORMAT enum values, if set it will apply requested color format for HDR session
+        pub hdrColorFormat: NV_COLOR_FORMAT,
+        /// Optional, One of NV_DYNAMIC_RANGE enum values, if set it will apply requested dynamic range for HDR session
+        pub hdrDynamicRange: NV_DYNAMIC_RANGE,
+        /// Optional, One of NV_BPC enum values, if set it will apply requested color depth
+        pub hdrBpc: NV_BPC,
+    }
+}
+
+nvinherit! { NV_HDR_COLOR_DATA_V2(v1: NV_HDR_COLOR_DATA_V1) }
+
+const NV_HDR_COLOR_DATA_V2_SIZE: usize = NV_HDR_COLOR_DATA_V1_SIZE + 4 * 3;
+
+pub type NV_HDR_COLOR_DATA = NV_HDR_COLOR_DATA_V2;
+
+nvversion! { NV_HDR_COLOR_DATA_VER1(NV_HDR_COLOR_DATA_V1 = NV_HDR_COLOR_DATA_V1_SIZE, 1) }
+nvversion! { NV_HDR_COLOR_DATA_VER2(NV_HDR_COLOR_DATA_V2 = NV_HDR_COLOR_DATA_V2_SIZE, 2) }
+nvversion! { NV_HDR_COLOR_DATA_VER = NV_HDR_COLOR_DATA_VER2 }
+
+nvapi_fn! {
+    pub type Disp_HdrColorControlFn = extern "C" fn(displayId: u32, pHdrColorData: *mut NV_HDR_COLOR_DATA) -> NvAPI_Status;
+
+    /// This API configures HDR and extended color output of the display.
+    ///
+    /// `NV_HDR_CMD_GET` returns the current configuration, `NV_HDR_CMD_SET` applies the given mode
+    /// and static metadata.
+    pub unsafe fn NvAPI_Disp_HdrColorControl;
+}
+
+nvbits! {
+    /// Bit fields of `NV_HDR_CAPABILITIES_V1`.
+    pub enum NV_HDR_CAPABILITIES_FLAGS / HdrCapabilitiesFlags {
+        /// HDMI2.0a UHDA HDR with ST2084 EOTF (CEA861.3). Boolean: 0 = not supported, 1 = supported;
+        NV_HDR_CAPABILITIES_ST2084_EOTF_SUPPORTED / ST2084_EOTF = 0x01,
+        /// HDMI2.0a traditional HDR gamma (CEA861.3). Boolean: 0 = not supported, 1 = supported;
+        NV_HDR_CAPABILITIES_TRADITIONAL_HDR_GAMMA_SUPPORTED / TRADITIONAL_HDR_GAMMA = 0x02,
+        /// Extended Dynamic Range on SDR displays. Boolean: 0 = not supported, 1 = supported;
+        NV_HDR_CAPABILITIES_EDR_SUPPORTED / EDR = 0x04,
+        /// If set, driver will expand default (=zero) HDR capabilities parameters contained in display's EDID.
+        NV_HDR_CAPABILITIES_DRIVER_EXPAND_DEFAULT_HDR_PARAMETERS / DRIVER_EXPAND_DEFAULT_HDR_PARAMETERS = 0x08,
+        /// HDMI2.0a traditional SDR gamma (CEA861.3). Boolean: 0 = not supported, 1 = supported;
+        NV_HDR_CAPABILITIES_TRADITIONAL_SDR_GAMMA_SUPPORTED / TRADITIONAL_SDR_GAMMA = 0x10,
+    }
+}
+
+nvstruct! {
+    /// Display data of `NV_HDR_CAPABILITIES_V1`, an anonymous struct in the nvapi headers.
+    ///
+    /// Units are the same as in `NV_HDR_MASTERING_DISPLAY_DATA`.
+    pub struct NV_HDR_DISPLAY_DATA {
+        /// x coordinate of color primary 0 (e.g. Red) of the display
+        pub displayPrimary_x0: u16,
+        /// y coordinate of color primary 0 (e.g. Red) of the display
+        pub displayPrimary_y0: u16,
+        /// x coordinate of color primary 1 (e.g. Green) of the display
+        pub displayPrimary_x1: u16,
+        /// y coordinate of color primary 1 (e.g. Green) of the display
+        pub displayPrimary_y1: u16,
+        /// x coordinate of color primary 2 (e.g. Blue) of the display
+        pub displayPrimary_x2: u16,
+        /// y coordinate of color primary 2 (e.g. Blue) of the display
+        pub displayPrimary_y2: u16,
+        /// x coordinate of white point of the display
+        pub displayWhitePoint_x: u16,
+        /// y coordinate of white point of the display
+        pub displayWhitePoint_y: u16,
+        /// Maximum display luminance = desired max luminance of HDR content
+        pub desired_content_max_luminance: u16,
+        /// Minimum display luminance = desired min luminance of HDR content
+        pub desired_content_min_luminance: u16,
+        /// Desired maximum Frame-Average Light Level (MaxFALL) of HDR content
+        pub desired_content_max_frame_average_luminance: u16,
+    }
+}
+
+nvstruct! {
+    pub struct NV_HDR_CAPABILITIES_V1 {
+        /// Version of this structure
+        pub version: u32,
+        /// `NV_HDR_CAPABILITIES_FLAGS`
+        pub flags: NV_HDR_CAPABILITIES_FLAGS,
+        /// Static Metadata Descriptor Id (0 for static metadata type 1)
+        pub static_metadata_descriptor_id: NV_STATIC_METADATA_DESCRIPTOR_ID,
+        /// Static Metadata Descriptor Type 1, CEA-861.3, SMPTE ST2086
+        pub display_data: NV_HDR_DISPLAY_DATA,
+    }
+}
+
+pub type NV_HDR_CAPABILITIES = NV_HDR_CAPABILITIES_V1;
+
+// 11 u16 of display data, padded to 4 bytes
+nvversion! { NV_HDR_CAPABILITIES_VER1(NV_HDR_CAPABILITIES_V1 = 4 * 3 + 2 * 12, 1) }
+nvversion! { NV_HDR_CAPABILITIES_VER = NV_HDR_CAPABILITIES_VER1 }
+
+nvapi_fn! {
+    pub type Disp_GetHdrCapabilitiesFn = extern "C" fn(displayId: u32, pHdrCapabilities: *mut NV_HDR_CAPABILITIES) -> NvAPI_Status;
+
+    /// This API gets High Dynamic Range (HDR) capabilities of the display.
+    pub unsafe fn NvAPI_Disp_GetHdrCapabilities;
+}
+
+nvenum! {
+    /// Used in `NV_MONITOR_CAPABILITIES`.
+    pub enum NV_MONITOR_CAPS_TYPE / MonitorCapsType {
+        /// HDMI vendor specific data block
+        NV_MONITOR_CAPS_TYPE_HDMI_VSDB / HdmiVsdb = 0x1000,
+        /// HDMI video capability data block
+        NV_MONITOR_CAPS_TYPE_HDMI_VCDB / HdmiVcdb = 0x1001,
+        /// Variable refresh rate and G-SYNC capabilities
+        #[default]
+        NV_MONITOR_CAPS_TYPE_GENERIC / Generic = 0x1002,
+    }
+}
+
+nvenum_display! {
+    MonitorCapsType => _
+}
+
+nvbits! {
+    /// Bit fields of `NV_MONITOR_CAPS_GENERIC`, the first data byte of `NV_MONITOR_CAPABILITIES`.
+    pub enum NV_MONITOR_CAPS_GENERIC / MonitorCapsGeneric {
+        /// The monitor supports variable refresh rate, e.g. G-SYNC Compatible or FreeSync
+        NV_MONITOR_CAPS_GENERIC_SUPPORT_VRR / SUPPORT_VRR = 0x01,
+        /// The monitor supports ultra low motion blur
+        NV_MONITOR_CAPS_GENERIC_SUPPORT_ULMB / SUPPORT_ULMB = 0x02,
+        /// The monitor has a G-SYNC module
+        NV_MONITOR_CAPS_GENERIC_IS_TRUE_GSYNC / IS_TRUE_GSYNC = 0x04,
+        NV_MONITOR_CAPS_GENERIC_IS_RLA_CAPABLE / IS_RLA_CAPABLE = 0x08,
+        /// VRR can be enabled in the current mode
+        NV_MONITOR_CAPS_GENERIC_CURRENTLY_CAPABLE_OF_VRR / CURRENTLY_CAPABLE_OF_VRR = 0x10,
+    }
+}
+
+/// Size of the largest member of the `NV_MONITOR_CAPABILITIES` data union, `NV_MONITOR_CAPS_VSDB`.
+pub const NV_MONITOR_CAPS_DATA_SIZE: usize = 11 + 7 + 31;
+
+nvstruct! {
+    pub struct NV_MONITOR_CAPABILITIES_V1 {
+        /// Version of this structure
+        pub version: u32,
+        /// Size of the data
+        pub size: u16,
+        /// `NV_MONITOR_CAPS_TYPE`, the kind of data requested
+        pub infoType: NV_MONITOR_CAPS_TYPE,
+        /// `NV_MONITOR_CONN_TYPE`
+        pub connectorType: u32,
+        /// Boolean: whether `data` is filled in
+        pub bIsValidInfo: u8,
+        /// A union of `NV_MONITOR_CAPS_VSDB`, `NV_MONITOR_CAPS_VCDB` and `NV_MONITOR_CAPS_GENERIC`,
+        /// selected by `infoType`. All members are bit fields over bytes.
+        pub data: [u8; NV_MONITOR_CAPS_DATA_SIZE],
+    }
+}
+
+pub type NV_MONITOR_CAPABILITIES = NV_MONITOR_CAPABILITIES_V1;
+
+// the u16 and the byte-aligned union are padded to 4 bytes
+nvversion! { NV_MONITOR_CAPABILITIES_VER1(NV_MONITOR_CAPABILITIES_V1 = 4 * 4 + 1 + NV_MONITOR_CAPS_DATA_SIZE + 2, 1) }
+nvversion! { NV_MONITOR_CAPABILITIES_VER = NV_MONITOR_CAPABILITIES_VER1 }
+
+nvapi_fn! {
+    pub type DISP_GetMonitorCapabilitiesFn = extern "C" fn(displayId: u32, pMonitorCapabilities: *mut NV_MONITOR_CAPABILITIES) -> NvAPI_Status;
+
+    /// This API returns the Monitor capabilities of the requested `infoType`.
+    pub unsafe fn NvAPI_DISP_GetMonitorCapabilities;
+}
+
+nvenum! {
+    pub enum NV_FORMAT / Format {
+        NV_FORMAT_UNKNOWN / Unknown = 0,
+        NV_FORMAT_P8 / P8 = 41,
+        NV_FORMAT_R5G6B5 / R5G6B5 = 23,
+        NV_FORMAT_A8R8G8B8 / A8R8G8B8 = 21,
+        NV_FORMAT_A16B16G16R16F / A16B16G16R16F = 113,
+    }
+}
+
+nvenum! {
+    pub enum NV_SCALING / Scaling {
+        /// No change
+        NV_SCALING_DEFAULT / Default = 0,
+        /// Balanced - Full Screen
+        NV_SCALING_GPU_SCALING_TO_CLOSEST / ToClosest = 1,
+        /// Force GPU - Full Screen
+        NV_SCALING_GPU_SCALING_TO_NATIVE / ToNative = 2,
+        /// Force GPU - Centered\No Scaling
+        NV_SCALING_GPU_SCANOUT_TO_NATIVE / ScanoutToNative = 3,
+        /// Force GPU - Aspect Ratio
+        NV_SCALING_GPU_SCALING_TO_ASPECT_SCANOUT_TO_NATIVE / ToAspectScanoutToNative = 5,
+        /// Balanced - Aspect Ratio
+        NV_SCALING_GPU_SCALING_TO_ASPECT_SCANOUT_TO_CLOSEST / ToAspectScanoutToClosest = 6,
+        /// Balanced - Centered\No Scaling
+        NV_SCALING_GPU_SCANOUT_TO_CLOSEST / ScanoutToClosest = 7,
+        /// Force GPU - Integer Scaling
+        NV_SCALING_GPU_INTEGER_ASPECT_SCALING / IntegerAspect = 8,
+        NV_SCALING_CUSTOMIZED / Customized = 255,
+    }
+}
+
+nvenum! {
+    pub enum NV_TIMING_OVERRIDE / TimingOverride {
+        /// get the current timing
+        NV_TIMING_OVERRIDE_CURRENT / Current = 0,
+        /// the timing the driver will use based the current policy
+        NV_TIMING_OVERRIDE_AUTO / Auto = 1,
+        /// EIA 861x pre-defined timing
+        NV_TIMING_OVERRIDE_EDID / Edid = 2,
+        /// VESA DMT timing
+        NV_TIMING_OVERRIDE_DMT / Dmt = 3,
+        /// VESA DMT timing with reduced blanking
+        NV_TIMING_OVERRIDE_DMT_RB / DmtReducedBlanking = 4,
+        /// VESA CVT timing
+        NV_TIMING_OVERRIDE_CVT / Cvt = 5,
+        /// VESA CVT timing with reduced blanking
+        NV_TIMING_OVERRIDE_CVT_RB / CvtReducedBlanking = 6,
+        /// VESA GTF timing
+        NV_TIMING_OVERRIDE_GTF / Gtf = 7,
+        /// EIA 861x pre-defined timing
+        NV_TIMING_OVERRIDE_EIA861 / Eia861 = 8,
+        /// predefined analog tv timing
+        NV_TIMING_OVERRIDE_ANALOG_TV / AnalogTv = 9,
+        /// NV custom timings
+        NV_TIMING_OVERRIDE_CUST / Custom = 10,
+        /// NV pre-defined timing (basically the PsF timings)
+        NV_TIMING_OVERRIDE_NV_PREDEFINED / Predefined = 11,
+        /// NV pre-defined timing with aspect ratio
+        NV_TIMING_OVERRIDE_NV_ASPR / AspectRatio = 12,
+        /// Override for SDI timing
+        NV_TIMING_OVERRIDE_SDI / Sdi = 13,
+    }
+}
+
+nvstruct! {
+    pub struct NV_TIMINGEXT {
+        /// Reserved for NVIDIA hardware-based enhancement, such as double-scan.
+        pub flag: u32,
+        /// Logical refresh rate to present
+        pub rr: u16,
+        /// Physical vertical refresh rate in 0.001Hz
+        pub rrx1k: u32,
+        /// Display aspect ratio Hi(aspect):horizontal-aspect, Low(aspect):vertical-aspect
+        pub aspect: u32,
+        /// Bit-wise pixel repetition factor: 0x1:no pixel repetition; 0x2:each pixel repeats twice horizontally,..
+        pub rep: u16,
+        /// Timing standard
+        pub status: u32,
+        /// Timing name
+        pub name: [u8; 40],
+    }
+}
+
+nvstruct! {
+    /// The very basic timing structure based on the VESA standard.
+    pub struct NV_TIMING {
+        /// Horizontal visible
+        pub HVisible: u16,
+        /// Horizontal border
+        pub HBorder: u16,
+        /// Horizontal front porch
+        pub HFrontPorch: u16,
+        /// Horizontal sync width
+        pub HSyncWidth: u16,
+        /// Horizontal total
+        pub HTotal: u16,
+        /// Horizontal sync polarity: 1-negative, 0-positive
+        pub HSyncPol: u8,
+        /// Vertical visible
+        pub VVisible: u16,
+        /// Vertical border
+        pub VBorder: u16,
+        /// Vertical front porch
+        pub VFrontPorch: u16,
+        /// Vertical sync width
+        pub VSyncWidth: u16,
+        /// Vertical total
+        pub VTotal: u16,
+        /// Vertical sync polarity: 1-negative, 0-positive
+        pub VSyncPol: u8,
+        /// 1-interlaced, 0-progressive
+        pub interlaced: u16,
+        /// Pixel clock in 10 kHz
+        pub pclk: u32,
+        /// Extra timing information
+        pub etc: NV_TIMINGEXT,
+    }
+}
+
+nvenum! {
+    pub enum NV_DISPLAYCONFIG_SPANNING_ORIENTATION / SpanningOrientation {
+        NV_DISPLAYCONFIG_SPAN_NONE / None = 0,
+        NV_DISPLAYCONFIG_SPAN_HORIZONTAL / Horizontal = 1,
+        NV_DISPLAYCONFIG_SPAN_VERTICAL / Vertical = 2,
+    }
+}
+
+nvstruct! {
+    pub struct NV_RESOLUTION {
+        pub width: u32,
+        pub height: u32,
+        pub colorDepth: u32,
+    }
+}
+
+nvstruct! {
+    pub struct NV_POSITION {
+        pub x: i32,
+        pub y: i32,
+    }
+}
+
+nvstruct! {
+    pub struct NV_DISPLAYCONFIG_PATH_ADVANCED_TARGET_INFO {
+        /// Must be `NV_DISPLAYCONFIG_PATH_ADVANCED_TARGET_INFO_VER`
+        pub version: u32,
+        /// Rotation setting
+        pub rotation: NV_ROTATE,
+        /// Scaling setting
+        pub scaling: NV_SCALING,
+        /// Non-interlaced Refresh Rate of the mode, multiplied by 1000, 0 = ignored.
+        /// This is the value which driver reports to the OS.
+        pub refreshRate1K: u32,
+        /// Bit fields `interlaced`, `primary`, `isPanAndScanTarget`, `disableVirtualModeSupport`
+        /// and `isPreferredUnscaledTarget`, from bit 0 up.
+        pub flags: u32,
+        /// `NV_GPU_CONNECTOR_TYPE`, only valid on get.
+        pub connector: u32,
+        /// `NV_DISPLAY_TV_FORMAT`, only valid on get.
+        pub tvFormat: u32,
+        /// Ignored if `timingOverride == NV_TIMING_OVERRIDE_CURRENT`
+        pub timingOverride: NV_TIMING_OVERRIDE,
+        /// Scan out timing, valid only if `timingOverride == NV_TIMING_OVERRIDE_CUST`.
+        /// The value `timing.etc.rrx1k` is obtained from the EDID. The driver may choose to
+        /// modify this value within the frequency range supported by the display.
+        pub timing: NV_TIMING,
+    }
+}
+
+nvversion! { NV_DISPLAYCONFIG_PATH_ADVANCED_TARGET_INFO_VER1(NV_DISPLAYCONFIG_PATH_ADVANCED_TARGET_INFO = 4 * 8 + 96, 1) }
+nvversion! { NV_DISPLAYCONFIG_PATH_ADVANCED_TARGET_INFO_VER = NV_DISPLAYCONFIG_PATH_ADVANCED_TARGET_INFO_VER1 }
+
+impl NV_DISPLAYCONFIG_PATH_ADVANCED_TARGET_INFO {
+    pub fn interlaced(&self) -> bool {
+        self.flags & 1 != 0
+    }
+
+    /// Declares primary display in clone configuration. This is *NOT* GDI Primary.
+    pub fn primary(&self) -> bool {
+        self.flags & 2 != 0
+    }
+
+    pub fn isPanAndScanTarget(&self) -> bool {
+        self.flags & 4 != 0
+    }
+}
+
+nvstruct! {
+    pub struct NV_DISPLAYCONFIG_PATH_TARGET_INFO_V2 {
+        pub displayId: u32,
+        /// May be NULL if no advanced settings are required
+        pub details: *mut NV_DISPLAYCONFIG_PATH_ADVANCED_TARGET_INFO,
+        /// Windows CCD target ID. Must be present only for non-NVIDIA adapter, for NVIDIA adapter
+        /// this parameter is ignored.
+        pub targetId: u32,
+    }
+}
+
+pub type NV_DISPLAYCONFIG_PATH_TARGET_INFO = NV_DISPLAYCONFIG_PATH_TARGET_INFO_V2;
+
+nvstruct! {
+    pub struct NV_DISPLAYCONFIG_SOURCE_MODE_INFO_V1 {
+        pub resolution: NV_RESOLUTION,
+        /// Ignored at present, must be `NV_FORMAT_UNKNOWN` (0)
+        pub colorFormat: NV_FORMAT,
+        /// Is all positions are 0 or invalid, displays will be automatically positioned from
+        /// left to right with GDI Primary at 0,0, and all other displays in the order of the path array.
+        pub position: NV_POSITION,
+        /// Spanning is only supported on XP
+        pub spanningOrientation: NV_DISPLAYCONFIG_SPANNING_ORIENTATION,
+        /// Bit fields `bGDIPrimary` and `bSLIFocus`, from bit 0 up.
+        pub flags: u32,
+    }
+}
+
+pub type NV_DISPLAYCONFIG_SOURCE_MODE_INFO = NV_DISPLAYCONFIG_SOURCE_MODE_INFO_V1;
+
+impl NV_DISPLAYCONFIG_SOURCE_MODE_INFO_V1 {
+    pub fn bGDIPrimary(&self) -> bool {
+        self.flags & 1 != 0
+    }
+
+    pub fn bSLIFocus(&self) -> bool {
+        self.flags & 2 != 0
+    }
+}
+
+nvstruct! {
+    pub struct NV_DISPLAYCONFIG_PATH_INFO_V2 {
+        /// Must be `NV_DISPLAYCONFIG_PATH_INFO_VER`
+        pub version: u32,
+        /// Valid for all versions of Windows, unions with `reserved`
+        pub sourceId: u32,
+        /// Number of elements in the `targetInfo` array
+        pub targetInfoCount: u32,
+        pub targetInfo: *mut NV_DISPLAYCONFIG_PATH_TARGET_INFO_V2,
+        /// May be NULL if mode info is not important
+        pub sourceModeInfo: *mut NV_DISPLAYCONFIG_SOURCE_MODE_INFO_V1,
+        /// Bit field `IsNonNVIDIAAdapter` in bit 0. True for non-NVIDIA adapter.
+        pub flags: u32,
+        /// Used by Non-NVIDIA adapter for pointer to OS Adapter of LUID
+        pub pOSAdapterID: *mut c_void,
+    }
+}
+
+pub type NV_DISPLAYCONFIG_PATH_INFO = NV_DISPLAYCONFIG_PATH_INFO_V2;
+
+impl NV_DISPLAYCONFIG_PATH_INFO_V2 {
+    pub fn IsNonNVIDIAAdapter(&self) -> bool {
+        self.flags & 1 != 0
+    }
+}
+
+#[cfg(target_pointer_width = "64")]
+const NV_DISPLAYCONFIG_PATH_INFO_V2_SIZE: usize = 4 * 3 + 4 + 8 * 2 + 4 + 4 + 8;
+#[cfg(target_pointer_width = "32")]
+const NV_DISPLAYCONFIG_PATH_INFO_V2_SIZE: usize = 4 * 3 + 4 * 2 + 4 + 4;
+
+nvversion! { NV_DISPLAYCONFIG_PATH_INFO_VER2(NV_DISPLAYCONFIG_PATH_INFO_V2 = NV_DISPLAYCONFIG_PATH_INFO_V2_SIZE, 2) }
+nvversion! { NV_DISPLAYCONFIG_PATH_INFO_VER = NV_DISPLAYCONFIG_PATH_INFO_VER2 }
+
+nvbits! {
+    /// Flags for `NvAPI_DISP_SetDisplayConfig`
+    pub enum NV_DISPLAYCONFIG_FLAGS / DisplayConfigFlags {
+        /// Only validate the configuration without applying it
+        NV_DISPLAYCONFIG_VALIDATE_ONLY / VALIDATE_ONLY = 0x00000001,
+        /// Save the configuration so that it is restored on the next boot
+        NV_DISPLAYCONFIG_SAVE_TO_PERSISTENCE / SAVE_TO_PERSISTENCE = 0x00000002,
+        /// Driver reload is permitted if necessary
+        NV_DISPLAYCONFIG_DRIVER_RELOAD_ALLOWED / DRIVER_RELOAD_ALLOWED = 0x00000004,
+        /// Refresh OS mode list
+        NV_DISPLAYCONFIG_FORCE_MODE_ENUMERATION / FORCE_MODE_ENUMERATION = 0x00000008,
+        /// Tell OS to avoid optimizing CommitVidPn call during a modeset
+        NV_FORCE_COMMIT_VIDPN / FORCE_COMMIT_VIDPN = 0x00000010,
+    }
+}
+
+nvapi_fn! {
+    pub type DISP_GetDisplayConfigFn = extern "C" fn(pathInfoCount: *mut u32, pathInfo: *mut NV_DISPLAYCONFIG_PATH_INFO) -> NvAPI_Status;
+
+    /// This API lets caller retrieve the current global display configuration.
+    ///
+    /// # Usage
+    ///
+    /// The caller might have to call this three times to fetch all the required configuration
+    /// details as follows:
+    ///
+    /// 1. First pass: Caller should call `NvAPI_DISP_GetDisplayConfig()` with `pathInfo` set to
+    ///    NULL to fetch `pathInfoCount`.
+    /// 2. Second pass: Allocate memory for `pathInfo` with respect to the number of
+    ///    `pathInfoCount` (from first pass) to fetch `targetInfoCount`. If `sourceModeInfo` is
+    ///    needed allocate memory or it can be initialized to NULL.
+    /// 3. Third pass (optional, only required if target information is required): Allocate
+    ///    memory for `targetInfo` with respect to number of `targetInfoCount` (from second pass).
+    pub unsafe fn NvAPI_DISP_GetDisplayConfig;
+}
+
+nvapi_fn! {
+    pub type DISP_SetDisplayConfigFn = extern "C" fn(pathInfoCount: u32, pathInfo: *mut NV_DISPLAYCONFIG_PATH_INFO, flags: NV_DISPLAYCONFIG_FLAGS) -> NvAPI_Status;
+
+    /// This API lets caller apply a global display configuration across multiple GPUs.
+    ///
+    /// If all sourceIds are zero, then NvAPI will pick up sourceId's based on the following
+    /// criteria:
+    ///
+    /// - If user provides `sourceModeInfo` then we are trying to assign 0th sourceId always to
+    ///   GDIPrimary. This is needed since active windows always moves along with 0th sourceId.
+    /// - For rest of the paths, we are incrementally assigning the sourceId per adapter basis.
+    /// - If user doesn't provide `sourceModeInfo` then NVAPI just picks up some default sourceId's
+    ///   in incremental order.
+    ///
+    /// Note: NVAPI will not intelligently choose the sourceIDs for any configs that does not
+    /// need a modeset.
+    pub unsafe fn NvAPI_DISP_SetDisplayConfig;
+}
+
+/// Undocumented API
+pub mod private {
+    use crate::status::NvAPI_Status;
+    use crate::handles::NvDisplayHandle;
+
+    nvstruct! {
+        /// Digital vibrance levels of a display.
+        pub struct NV_DISPLAY_DVC_INFO_EX {
+            pub version: u32,
+            pub currentLevel: i32,
+            pub minLevel: i32,
+            pub maxLevel: i32,
+            pub defaultLevel: i32,
+        }
+    }
+
+    nvversion! { NV_DISPLAY_DVC_INFO_EX_VER1(NV_DISPLAY_DVC_INFO_EX = 4 * 5, 1) }
+    nvversion! { NV_DISPLAY_DVC_INFO_EX_VER = NV_DISPLAY_DVC_INFO_EX_VER1 }
+
+    nvapi_fn! {
+        pub type GetDVCInfoExFn = extern "C" fn(hNvDisplay: NvDisplayHandle, outputId: u32, pDVCInfo: *mut NV_DISPLAY_DVC_INFO_EX) -> NvAPI_Status;
+
+        /// Retrieves the digital vibrance levels of a display.
+        ///
+        /// The display may be addressed by a display ID passed as `outputId` with a null handle.
+        pub unsafe fn NvAPI_GetDVCInfoEx;
+    }
+
+    nvapi_fn! {
+        pub type SetDVCLevelExFn = extern "C" fn(hNvDisplay: NvDisplayHandle, outputId: u32, pDVCInfo: *mut NV_DISPLAY_DVC_INFO_EX) -> NvAPI_Status;
+
+        /// Sets `currentLevel` as the digital vibrance level of a display.
+        pub unsafe fn NvAPI_SetDVCLevelEx;
+    }
+}
@@ -12,3 +12,16 @@ nvapi_fn! {
     pub unsafe fn NvAPI_D3D_GetObjectHandleForResource;
 }
 
+
+nvapi_fn! {
+    pub type D3D_RegisterDeviceFn = extern "C" fn(pDev: *mut IUnknown) -> NvAPI_Status;
+
+    /// Tells NvAPI about a D3D device. This must be called prior to using any DX1x deferred-context
+    /// calls.
+    ///
+    /// # Returns
+    ///
+    /// - `NVAPI_OK`: the device was registered
+    /// - `NVAPI_INVALID_ARGUMENT`: `pDev` is NULL or not a D3D device
+    pub unsafe fn NvAPI_D3D_RegisterDevice;
+}
@@ -1,5 +1,7 @@
+#![allow(non_upper_case_globals)]
+
 use crate::status::NvAPI_Status;
-use crate::handles::NvPhysicalGpuHandle;
+use crate::handles::{NvPhysicalGpuHandle, NvDisplayHandle};
 
 nvenum! {
     pub enum NV_MONITOR_CONN_TYPE / MonitorConnectorType {
@@ -120,3 +122,330 @@ nvapi_fn! {
     /// - `NVAPI_INSUFFICIENT_BUFFER`: When the input buffer(pDisplayIds) is less than the actual number of display IDs
     pub unsafe fn NvAPI_GPU_GetAllDisplayIds;
 }
+
+/// The maximum EDID size that can be returned by a single `NvAPI_GPU_GetEDID` call
+pub const NV_EDID_DATA_SIZE: usize = 256;
+
+nvstruct! {
+    pub struct NV_EDID_V3 {
+        /// Structure version
+        pub version: u32,
+        pub EDID_Data: [u8; NV_EDID_DATA_SIZE],
+        /// The total size of the EDID, which may exceed `NV_EDID_DATA_SIZE`
+        pub sizeofEDID: u32,
+        /// ID which always returned in a monotonically increasing counter.
+        /// Across a split-EDID read we need to verify that all calls returned the same edidId.
+        /// This counter is incremented if we get the updated EDID.
+        pub edidId: u32,
+        /// Which 256-byte page of the EDID we want to read. Start at 0.
+        /// If the read succeeds with `sizeofEDID > NV_EDID_DATA_SIZE`,
+        /// call back again with offset+256 until we have read the entire buffer
+        pub offset: u32,
+    }
+}
+
+pub type NV_EDID = NV_EDID_V3;
+
+nvversion! { NV_EDID_VER3(NV_EDID_V3 = 4 + NV_EDID_DATA_SIZE + 4 * 3, 3) }
+nvversion! { NV_EDID_VER = NV_EDID_VER3 }
+
+nvapi_fn! {
+    pub type GPU_GetEDID = extern "C" fn(hPhysicalGpu: NvPhysicalGpuHandle, displayOutputId: u32, pEDID: *mut NV_EDID) -> NvAPI_Status;
+
+    /// This function returns the EDID data for the specified GPU handle and connection bit mask.
+    /// `displayOutputId` should have exactly 1 bit set to indicate a single display, or it may be
+    /// a display ID.
+    ///
+    /// EDIDs larger than `NV_EDID_DATA_SIZE` are read in pages by setting `offset`, keeping
+    /// `edidId` from the first call.
+    ///
+    /// # Returns
+    ///
+    /// - `NVAPI_INVALID_ARGUMENT`: pEDID is NULL; displayOutputId has 0 or > 1 bits set
+    /// - `NVAPI_DATA_NOT_FOUND`: The requested display does not contain an EDID.
+    pub unsafe fn NvAPI_GPU_GetEDID;
+}
+
+nvenum! {
+    pub enum NV_DP_LINK_RATE / DpLinkRate {
+        /// 1.62 Gbps per lane
+        NV_DP_1_62GBPS / Rbr = 6,
+        /// 2.70 Gbps per lane
+        NV_DP_2_70GBPS / Hbr = 0xa,
+        /// 5.40 Gbps per lane
+        NV_DP_5_40GBPS / Hbr2 = 0x14,
+        /// 8.10 Gbps per lane
+        NV_DP_8_10GBPS / Hbr3 = 0x1e,
+    }
+}
+
+nvenum_display! {
+    DpLinkRate => {
+        Rbr = "RBR",
+        Hbr = "HBR",
+        Hbr2 = "HBR2",
+        Hbr3 = "HBR3",
+    }
+}
+
+nvenum! {
+    pub enum NV_DP_LANE_COUNT / DpLaneCount {
+        NV_DP_1_LANE / One = 1,
+        NV_DP_2_LANE / Two = 2,
+        NV_DP_4_LANE / Four = 4,
+    }
+}
+
+nvenum_display! {
+    DpLaneCount => {
+        One = "x1",
+        Two = "x2",
+        Four = "x4",
+    }
+}
+
+nvenum! {
+    pub enum NV_DP_COLOR_FORMAT / DpColorFormat {
+        NV_DP_COLOR_FORMAT_RGB / Rgb = 0,
+        NV_DP_COLOR_FORMAT_YCbCr422 / YCbCr422 = 1,
+        NV_DP_COLOR_FORMAT_YCbCr444 / YCbCr444 = 2,
+    }
+}
+
+nvenum_display! {
+    DpColorFormat => {
+        Rgb = "RGB",
+        _ = _,
+    }
+}
+
+nvenum! {
+    pub enum NV_DP_COLORIMETRY / DpColorimetry {
+        NV_DP_COLORIMETRY_RGB / Rgb = 0,
+        NV_DP_COLORIMETRY_YCbCr_ITU601 / YCbCrItu601 = 1,
+        NV_DP_COLORIMETRY_YCbCr_ITU709 / YCbCrItu709 = 2,
+    }
+}
+
+nvenum_display! {
+    DpColorimetry => _
+}
+
+nvenum! {
+    pub enum NV_DP_DYNAMIC_RANGE / DpDynamicRange {
+        NV_DP_DYNAMIC_RANGE_VESA / Vesa = 0,
+        NV_DP_DYNAMIC_RANGE_CEA / Cea = 1,
+    }
+}
+
+nvenum_display! {
+    DpDynamicRange => {
+        Vesa = "VESA",
+        Cea = "CEA",
+    }
+}
+
+nvenum! {
+    pub enum NV_DP_BPC / DpBpc {
+        NV_DP_BPC_DEFAULT / Default = 0,
+        NV_DP_BPC_6 / Bpc6 = 1,
+        NV_DP_BPC_8 / Bpc8 = 2,
+        NV_DP_BPC_10 / Bpc10 = 3,
+        NV_DP_BPC_12 / Bpc12 = 4,
+        NV_DP_BPC_16 / Bpc16 = 5,
+    }
+}
+
+nvenum_display! {
+    DpBpc => {
+        Bpc6 = "6 bpc",
+        Bpc8 = "8 bpc",
+        Bpc10 = "10 bpc",
+        Bpc12 = "12 bpc",
+        Bpc16 = "16 bpc",
+        _ = _,
+    }
+}
+
+nvstruct! {
+    pub struct NV_DISPLAY_PORT_INFO_V1 {
+        /// Structure version
+        pub version: u32,
+        /// DPCD version of the monitor
+        pub dpcd_ver: u32,
+        /// Maximum supported link rate
+        pub maxLinkRate: NV_DP_LINK_RATE,
+        /// Maximum supported lane count
+        pub maxLaneCount: NV_DP_LANE_COUNT,
+        /// Current link rate
+        pub curLinkRate: NV_DP_LINK_RATE,
+        /// Current lane count
+        pub curLaneCount: NV_DP_LANE_COUNT,
+        /// Current color format
+        pub colorFormat: NV_DP_COLOR_FORMAT,
+        /// Dynamic range
+        pub dynamicRange: NV_DP_DYNAMIC_RANGE,
+        /// Ignored in RGB space
+        pub colorimetry: NV_DP_COLORIMETRY,
+        /// Current bit-per-component
+        pub bpc: NV_DP_BPC,
+        /// Bitfield, see the accessor methods
+        pub flags: u32,
+    }
+}
+
+pub type NV_DISPLAY_PORT_INFO = NV_DISPLAY_PORT_INFO_V1;
+
+nvversion! { NV_DISPLAY_PORT_INFO_VER1(NV_DISPLAY_PORT_INFO_V1 = 4 * 11, 1) }
+nvversion! { NV_DISPLAY_PORT_INFO_VER = NV_DISPLAY_PORT_INFO_VER1 }
+
+impl NV_DISPLAY_PORT_INFO_V1 {
+    /// If the monitor is driven by a DisplayPort
+    pub fn isDp(&self) -> bool {
+        self.flags & 0x1 != 0
+    }
+
+    /// If the monitor is driven by an NV Dp transmitter
+    pub fn isInternalDp(&self) -> bool {
+        self.flags & 0x2 != 0
+    }
+
+    /// If the color format change is supported
+    pub fn isColorCtrlSupported(&self) -> bool {
+        self.flags & 0x4 != 0
+    }
+
+    pub fn is6BPCSupported(&self) -> bool {
+        self.flags & 0x8 != 0
+    }
+
+    pub fn is8BPCSupported(&self) -> bool {
+        self.flags & 0x10 != 0
+    }
+
+    pub fn is10BPCSupported(&self) -> bool {
+        self.flags & 0x20 != 0
+    }
+
+    pub fn is12BPCSupported(&self) -> bool {
+        self.flags & 0x40 != 0
+    }
+
+    pub fn is16BPCSupported(&self) -> bool {
+        self.flags & 0x80 != 0
+    }
+
+    pub fn isYCrCb420Supported(&self) -> bool {
+        self.flags & 0x100 != 0
+    }
+
+    pub fn isYCrCb422Supported(&self) -> bool {
+        self.flags & 0x200 != 0
+    }
+
+    pub fn isYCrCb444Supported(&self) -> bool {
+        self.flags & 0x400 != 0
+    }
+}
+
+nvapi_fn! {
+    pub type GetDisplayPortInfoFn = extern "C" fn(hNvDisplay: NvDisplayHandle, outputId: u32, pInfo: *mut NV_DISPLAY_PORT_INFO) -> NvAPI_Status;
+
+    /// This function returns the current DisplayPort-related information on the specified device
+    /// (monitor).
+    ///
+    /// `hNvDisplay` may be the default (null) handle when `outputId` identifies the display.
+    ///
+    /// # Returns
+    ///
+    /// - `NVAPI_INVALID_ARGUMENT`: `pInfo` is NULL
+    /// - `NVAPI_INCOMPATIBLE_STRUCT_VERSION`: the version of `pInfo` is not supported
+    pub unsafe fn NvAPI_GetDisplayPortInfo;
+}
+
+nvstruct! {
+    pub struct NV_HDMI_SUPPORT_INFO_V2 {
+        /// Structure version
+        pub version: u32,
+        /// Bitfield, see the accessor methods
+        pub flags: u32,
+        /// EDID 861 extension revision
+        pub EDID861ExtRev: u32,
+    }
+}
+
+pub type NV_HDMI_SUPPORT_INFO = NV_HDMI_SUPPORT_INFO_V2;
+
+nvversion! { NV_HDMI_SUPPORT_INFO_VER1(NV_HDMI_SUPPORT_INFO_V2 = 4 * 3, 1) }
+nvversion! { NV_HDMI_SUPPORT_INFO_VER2(NV_HDMI_SUPPORT_INFO_V2 = 4 * 3, 2) }
+nvversion! { NV_HDMI_SUPPORT_INFO_VER = NV_HDMI_SUPPORT_INFO_VER2 }
+
+impl NV_HDMI_SUPPORT_INFO_V2 {
+    /// If the GPU can handle HDMI
+    pub fn isGpuHDMICapable(&self) -> bool {
+        self.flags & 0x1 != 0
+    }
+
+    /// If the monitor supports underscan
+    pub fn isMonUnderscanCapable(&self) -> bool {
+        self.flags & 0x2 != 0
+    }
+
+    /// If the monitor supports basic audio
+    pub fn isMonBasicAudioCapable(&self) -> bool {
+        self.flags & 0x4 != 0
+    }
+
+    /// If YCbCr 4:4:4 is supported
+    pub fn isMonYCbCr444Capable(&self) -> bool {
+        self.flags & 0x8 != 0
+    }
+
+    /// If YCbCr 4:2:2 is supported
+    pub fn isMonYCbCr422Capable(&self) -> bool {
+        self.flags & 0x10 != 0
+    }
+
+    /// If xvYCC BT.601 is supported
+    pub fn isMonxvYCC601Capable(&self) -> bool {
+        self.flags & 0x20 != 0
+    }
+
+    /// If xvYCC BT.709 is supported
+    pub fn isMonxvYCC709Capable(&self) -> bool {
+        self.flags & 0x40 != 0
+    }
+
+    /// If the monitor is HDMI (with IEEE's HDMI registry ID)
+    pub fn isMonHDMI(&self) -> bool {
+        self.flags & 0x80 != 0
+    }
+
+    /// If sYCC601 extended colorimetry is supported (V2 only)
+    pub fn isMonsYCC601Capable(&self) -> bool {
+        self.flags & 0x100 != 0
+    }
+
+    /// If AdobeYCC601 extended colorimetry is supported (V2 only)
+    pub fn isMonAdobeYCC601Capable(&self) -> bool {
+        self.flags & 0x200 != 0
+    }
+
+    /// If AdobeRGB extended colorimetry is supported (V2 only)
+    pub fn isMonAdobeRGBCapable(&self) -> bool {
+        self.flags & 0x400 != 0
+    }
+}
+
+nvapi_fn! {
+    pub type GetHDMISupportInfoFn = extern "C" fn(hNvDisplay: NvDisplayHandle, outputId: u32, pInfo: *mut NV_HDMI_SUPPORT_INFO) -> NvAPI_Status;
+
+    /// This API returns the current infoframe data on the specified device (monitor).
+    ///
+    /// `hNvDisplay` may be the default (null) handle when `outputId` identifies the display.
+    ///
+    /// # Returns
+    ///
+    /// - `NVAPI_INCOMPATIBLE_STRUCT_VERSION`: the version of `pInfo` is not supported
+    pub unsafe fn NvAPI_GetHDMISupportInfo;
+}
@@ -0,0 +1,115 @@
+use crate::status::NvAPI_Status;
+use crate::handles::NvPhysicalGpuHandle;
+use crate::types::BoolU32;
+
+nvenum! {
+    /// Used in NV_GPU_ECC_STATUS_INFO.
+    pub enum NV_ECC_CONFIGURATION / EccConfiguration {
+        NV_ECC_CONFIGURATION_NOT_SUPPORTED / NotSupported = 0,
+        /// Changes require a POST to take effect
+        NV_ECC_CONFIGURATION_DEFERRED / Deferred = 1,
+        /// Changes can optionally be made to take effect immediately
+        NV_ECC_CONFIGURATION_IMMEDIATE / Immediate = 2,
+    }
+}
+
+nvenum_display! {
+    EccConfiguration => _
+}
+
+nvstruct! {
+    /// Used in NvAPI_GPU_GetECCStatusInfo().
+    pub struct NV_GPU_ECC_STATUS_INFO {
+        /// Structure version
+        pub version: u32,
+        /// ECC memory feature support
+        pub isSupported: BoolU32,
+        /// Supported ECC memory feature configuration options
+        pub configurationOptions: NV_ECC_CONFIGURATION,
+        /// Active ECC memory setting
+        pub isEnabled: BoolU32,
+    }
+}
+
+nvversion! { NV_GPU_ECC_STATUS_INFO_VER(NV_GPU_ECC_STATUS_INFO = 4 * 4, 1) }
+
+nvapi_fn! {
+    pub type GPU_GetECCStatusInfoFn = extern "C" fn(hPhysicalGpu: NvPhysicalGpuHandle, pECCStatusInfo: *mut NV_GPU_ECC_STATUS_INFO) -> NvAPI_Status;
+
+    /// This function returns ECC memory status information.
+    pub unsafe fn NvAPI_GPU_GetECCStatusInfo;
+}
+
+nvstruct! {
+    pub struct NV_GPU_ECC_ERROR_INFO_COUNTS {
+        /// Number of single-bit ECC errors detected
+        pub singleBitErrors: u64,
+        /// Number of double-bit ECC errors detected
+        pub doubleBitErrors: u64,
+    }
+}
+
+nvstruct! {
+    /// Used in NvAPI_GPU_GetECCErrorInfo().
+    pub struct NV_GPU_ECC_ERROR_INFO {
+        /// Structure version
+        pub version: u32,
+        /// Number of ECC errors detected since last boot
+        pub current: NV_GPU_ECC_ERROR_INFO_COUNTS,
+        /// Number of ECC errors detected since last counter reset
+        pub aggregate: NV_GPU_ECC_ERROR_INFO_COUNTS,
+    }
+}
+
+nvversion! { NV_GPU_ECC_ERROR_INFO_VER(NV_GPU_ECC_ERROR_INFO = 8 + 8 * 4, 1) }
+
+nvapi_fn! {
+    pub type GPU_GetECCErrorInfoFn = extern "C" fn(hPhysicalGpu: NvPhysicalGpuHandle, pECCErrorInfo: *mut NV_GPU_ECC_ERROR_INFO) -> NvAPI_Status;
+
+    /// This function returns ECC memory error information.
+    pub unsafe fn NvAPI_GPU_GetECCErrorInfo;
+}
+
+nvapi_fn! {
+    pub type GPU_ResetECCErrorInfoFn = extern "C" fn(hPhysicalGpu: NvPhysicalGpuHandle, bResetCurrent: u8, bResetAggregate: u8) -> NvAPI_Status;
+
+    /// This function resets ECC memory error counters.
+    pub unsafe fn NvAPI_GPU_ResetECCErrorInfo;
+}
+
+nvstruct! {
+    /// Used in NvAPI_GPU_GetECCConfigurationInfo().
+    pub struct NV_GPU_ECC_CONFIGURATION_INFO {
+        /// Structure version
+        pub version: u32,
+        /// Current ECC configuration stored in non-volatile memory (bit 0),
+        /// factory default ECC configuration (bit 1)
+        pub flags: u32,
+    }
+}
+
+nvversion! { NV_GPU_ECC_CONFIGURATION_INFO_VER(NV_GPU_ECC_CONFIGURATION_INFO = 4 * 2, 1) }
+
+impl NV_GPU_ECC_CONFIGURATION_INFO {
+    pub fn isEnabled(&self) -> bool {
+        self.flags & 1 != 0
+    }
+
+    pub fn isEnabledByDefault(&self) -> bool {
+        self.flags & 2 != 0
+    }
+}
+
+nvapi_fn! {
+    pub type GPU_GetECCConfigurationInfoFn = extern "C" fn(hPhysicalGpu: NvPhysicalGpuHandle, pECCConfigurationInfo: *mut NV_GPU_ECC_CONFIGURATION_INFO) -> NvAPI_Status;
+
+    /// This function returns ECC memory configuration information.
+    pub unsafe fn NvAPI_GPU_GetECCConfigurationInfo;
+}
+
+nvapi_fn! {
+    pub type GPU_SetECCConfigurationFn = extern "C" fn(hPhysicalGpu: NvPhysicalGpuHandle, bEnable: u8, bEnableImmediately: u8) -> NvAPI_Status;
+
+    /// This function updates the ECC memory configuration setting.
+    pub unsafe fn NvAPI_GPU_SetECCConfiguration;
+}
@@ -0,0 +1,111 @@
+/// Undocumented API
+pub mod private {
+    use crate::status::NvAPI_Status;
+    use crate::handles::NvPhysicalGpuHandle;
+
+    pub const NVAPI_MAX_FAN_COOLERS: usize = 32;
+
+    nvstruct! {
+        pub struct NV_GPU_CLIENT_FAN_COOLERS_INFO_ENTRY {
+            pub coolerId: u32,
+            pub unknown: [u32; 2],
+            pub maxRpm: u32,
+            pub reserved: [u32; 8],
+        }
+    }
+
+    nvstruct! {
+        pub struct NV_GPU_CLIENT_FAN_COOLERS_INFO_V1 {
+            pub version: u32,
+            pub unknown: u32,
+            pub count: u32,
+            pub reserved: [u32; 8],
+            pub entries: [NV_GPU_CLIENT_FAN_COOLERS_INFO_ENTRY; NVAPI_MAX_FAN_COOLERS],
+        }
+    }
+
+    nvversion! { NV_GPU_CLIENT_FAN_COOLERS_INFO_VER_1(NV_GPU_CLIENT_FAN_COOLERS_INFO_V1 = 4 * (3 + 8) + 4 * (4 + 8) * NVAPI_MAX_FAN_COOLERS, 1) }
+    nvversion! { NV_GPU_CLIENT_FAN_COOLERS_INFO_VER = NV_GPU_CLIENT_FAN_COOLERS_INFO_VER_1 }
+
+    pub type NV_GPU_CLIENT_FAN_COOLERS_INFO = NV_GPU_CLIENT_FAN_COOLERS_INFO_V1;
+
+    nvapi_fn! {
+        /// Turing and later
+        pub unsafe fn NvAPI_GPU_ClientFanCoolersGetInfo(hPhysicalGPU: NvPhysicalGpuHandle, pFanCoolersInfo: *mut NV_GPU_CLIENT_FAN_COOLERS_INFO) -> NvAPI_Status;
+    }
+
+    nvstruct! {
+        pub struct NV_GPU_CLIENT_FAN_COOLERS_STATUS_ENTRY {
+            pub coolerId: u32,
+            pub currentRpm: u32,
+            pub currentMinLevel: u32,
+            pub currentMaxLevel: u32,
+            pub currentLevel: u32,
+            pub reserved: [u32; 8],
+        }
+    }
+
+    nvstruct! {
+        pub struct NV_GPU_CLIENT_FAN_COOLERS_STATUS_V1 {
+            pub version: u32,
+            pub count: u32,
+            pub reserved: [u32; 8],
+            pub entries: [NV_GPU_CLIENT_FAN_COOLERS_STATUS_ENTRY; NVAPI_MAX_FAN_COOLERS],
+        }
+    }
+
+    nvversion! { NV_GPU_CLIENT_FAN_COOLERS_STATUS_VER_1(NV_GPU_CLIENT_FAN_COOLERS_STATUS_V1 = 4 * (2 + 8) + 4 * (5 + 8) * NVAPI_MAX_FAN_COOLERS, 1) }
+    nvversion! { NV_GPU_CLIENT_FAN_COOLERS_STATUS_VER = NV_GPU_CLIENT_FAN_COOLERS_STATUS_VER_1 }
+
+    pub type NV_GPU_CLIENT_FAN_COOLERS_STATUS = NV_GPU_CLIENT_FAN_COOLERS_STATUS_V1;
+
+    nvapi_fn! {
+        /// Turing and later
+        pub unsafe fn NvAPI_GPU_ClientFanCoolersGetStatus(hPhysicalGPU: NvPhysicalGpuHandle, pFanCoolersStatus: *mut NV_GPU_CLIENT_FAN_COOLERS_STATUS) -> NvAPI_Status;
+    }
+
+    nvenum! {
+        pub enum NV_FAN_COOLER_CONTROL_MODE / FanCoolerControlMode {
+            NVAPI_FAN_COOLER_CONTROL_MODE_AUTO / Auto = 0,
+            NVAPI_FAN_COOLER_CONTROL_MODE_MANUAL / Manual = 1,
+        }
+    }
+
+    nvenum_display! {
+        FanCoolerControlMode => _
+    }
+
+    nvstruct! {
+        pub struct NV_GPU_CLIENT_FAN_COOLERS_CONTROL_ENTRY {
+            pub coolerId: u32,
+            pub level: u32,
+            pub controlMode: NV_FAN_COOLER_CONTROL_MODE,
+            pub reserved: [u32; 8],
+        }
+    }
+
+    nvstruct! {
+        pub struct NV_GPU_CLIENT_FAN_COOLERS_CONTROL_V1 {
+            pub version: u32,
+            pub unknown: u32,
+            pub count: u32,
+            pub reserved: [u32; 8],
+            pub entries: [NV_GPU_CLIENT_FAN_COOLERS_CONTROL_ENTRY; NVAPI_MAX_FAN_COOLERS],
+        }
+    }
+
+    nvversion! { NV_GPU_CLIENT_FAN_COOLERS_CONTROL_VER_1(NV_GPU_CLIENT_FAN_COOLERS_CONTROL_V1 = 4 * (3 + 8) + 4 * (3 + 8) * NVAPI_MAX_FAN_COOLERS, 1) }
+    nvversion! { NV_GPU_CLIENT_FAN_COOLERS_CONTROL_VER = NV_GPU_CLIENT_FAN_COOLERS_CONTROL_VER_1 }
+
+    pub type NV_GPU_CLIENT_FAN_COOLERS_CONTROL = NV_GPU_CLIENT_FAN_COOLERS_CONTROL_V1;
+
+    nvapi_fn! {
+        /// Turing and later
+        pub unsafe fn NvAPI_GPU_ClientFanCoolersGetControl(hPhysicalGPU: NvPhysicalGpuHandle, pFanCoolersControl: *mut NV_GPU_CLIENT_FAN_COOLERS_CONTROL) -> NvAPI_Status;
+    }
+
+    nvapi_fn! {
+        /// Turing and later
+        pub unsafe fn NvAPI_GPU_ClientFanCoolersSetControl(hPhysicalGPU: NvPhysicalGpuHandle, pFanCoolersControl: *const NV_GPU_CLIENT_FAN_COOLERS_CONTROL) -> NvAPI_Status;
+    }
+}
@@ -0,0 +1,84 @@
+use crate::status::NvAPI_Status;
+use crate::handles::NvPhysicalGpuHandle;
+
+nvenum! {
+    /// Used in NvAPI_GPU_QueryIlluminationSupport(), NvAPI_GPU_GetIllumination() and NvAPI_GPU_SetIllumination().
+    pub enum NV_GPU_ILLUMINATION_ATTRIB / IlluminationAttribute {
+        /// Brightness of the GeForce logo LED
+        NV_GPU_IA_LOGO_BRIGHTNESS / LogoBrightness = 0,
+        /// Brightness of the SLI bridge LED
+        NV_GPU_IA_SLI_BRIGHTNESS / SliBrightness = 1,
+    }
+}
+
+nvenum_display! {
+    IlluminationAttribute => {
+        LogoBrightness = "Logo",
+        SliBrightness = "SLI",
+    }
+}
+
+nvstruct! {
+    /// Used in NvAPI_GPU_QueryIlluminationSupport().
+    pub struct NV_GPU_QUERY_ILLUMINATION_SUPPORT_PARM_V1 {
+        /// Structure version
+        pub version: u32,
+        /// The handle of the GPU to query
+        pub hPhysicalGpu: NvPhysicalGpuHandle,
+        /// An enumeration value specifying the attribute to query
+        pub Attribute: NV_GPU_ILLUMINATION_ATTRIB,
+        /// Whether the attribute is supported on the GPU
+        pub bSupported: u32,
+    }
+}
+
+pub type NV_GPU_QUERY_ILLUMINATION_SUPPORT_PARM = NV_GPU_QUERY_ILLUMINATION_SUPPORT_PARM_V1;
+
+const NV_GPU_ILLUMINATION_PARM_SIZE: usize = std::mem::size_of::<NV_GPU_QUERY_ILLUMINATION_SUPPORT_PARM_V1>();
+
+nvversion! { NV_GPU_QUERY_ILLUMINATION_SUPPORT_PARM_VER_1(NV_GPU_QUERY_ILLUMINATION_SUPPORT_PARM_V1 = NV_GPU_ILLUMINATION_PARM_SIZE, 1) }
+nvversion! { NV_GPU_QUERY_ILLUMINATION_SUPPORT_PARM_VER = NV_GPU_QUERY_ILLUMINATION_SUPPORT_PARM_VER_1 }
+
+nvapi_fn! {
+    pub type GPU_QueryIlluminationSupportFn = extern "C" fn(pIlluminationSupportInfo: *mut NV_GPU_QUERY_ILLUMINATION_SUPPORT_PARM) -> NvAPI_Status;
+
+    /// This function reports whether the specified illumination attribute is supported by the GPU.
+    pub unsafe fn NvAPI_GPU_QueryIlluminationSupport;
+}
+
+nvstruct! {
+    /// Used in NvAPI_GPU_GetIllumination() and NvAPI_GPU_SetIllumination().
+    pub struct NV_GPU_GET_ILLUMINATION_PARM_V1 {
+        /// Structure version
+        pub version: u32,
+        /// The handle of the GPU to query
+        pub hPhysicalGpu: NvPhysicalGpuHandle,
+        /// An enumeration value specifying the attribute to query
+        pub Attribute: NV_GPU_ILLUMINATION_ATTRIB,
+        /// The brightness in percent
+        pub Value: u32,
+    }
+}
+
+pub type NV_GPU_GET_ILLUMINATION_PARM = NV_GPU_GET_ILLUMINATION_PARM_V1;
+pub type NV_GPU_SET_ILLUMINATION_PARM_V1 = NV_GPU_GET_ILLUMINATION_PARM_V1;
+pub type NV_GPU_SET_ILLUMINATION_PARM = NV_GPU_SET_ILLUMINATION_PARM_V1;
+
+nvversion! { NV_GPU_GET_ILLUMINATION_PARM_VER_1(NV_GPU_GET_ILLUMINATION_PARM_V1 = NV_GPU_ILLUMINATION_PARM_SIZE, 1) }
+nvversion! { NV_GPU_GET_ILLUMINATION_PARM_VER = NV_GPU_GET_ILLUMINATION_PARM_VER_1 }
+nvversion! { NV_GPU_SET_ILLUMINATION_PARM_VER_1 = NV_GPU_GET_ILLUMINATION_PARM_VER_1 }
+nvversion! { NV_GPU_SET_ILLUMINATION_PARM_VER = NV_GPU_SET_ILLUMINATION_PARM_VER_1 }
+
+nvapi_fn! {
+    pub type GPU_GetIlluminationFn = extern "C" fn(pIlluminationInfo: *mut NV_GPU_GET_ILLUMINATION_PARM) -> NvAPI_Status;
+
+    /// This function reports the current illumination value of the specified attribute.
+    pub unsafe fn NvAPI_GPU_GetIllumination;
+}
+
+nvapi_fn! {
+    pub type GPU_SetIlluminationFn = extern "C" fn(pIlluminationInfo: *mut NV_GPU_SET_ILLUMINATION_PARM) -> NvAPI_Status;
+
+    /// This function sets the illumination value of the specified attribute.
+    pub unsafe fn NvAPI_GPU_SetIllumination;
+}
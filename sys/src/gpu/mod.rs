@@ -1,11 +1,15 @@
+use std::os::raw::c_void;
 use crate::status::NvAPI_Status;
-use crate::handles::NvPhysicalGpuHandle;
+use crate::handles::{NvLogicalGpuHandle, NvPhysicalGpuHandle};
 use crate::types;
 
 /// The GPU cooler APIs are used to get and set the fan level or equivalent
 /// cooler levels for various target devices associated with the GPU.
 pub mod cooler;
 
+/// The client fan cooler APIs replace the cooler APIs on Turing and later GPUs.
+pub mod fan;
+
 /// The GPU performance state APIs are used to get and set various performance
 /// levels on a per-GPU basis. P-States are GPU active/executing performance
 /// capability and power consumption states.
@@ -34,6 +38,12 @@ pub mod power;
 
 pub mod display;
 
+/// The ECC APIs query and reset the ECC memory state and error counters of a GPU.
+pub mod ecc;
+
+/// The illumination APIs control the brightness of the LEDs on a GPU board.
+pub mod illumination;
+
 nvapi_fn! {
     pub type EnumPhysicalGPUsFn = extern "C" fn(nvGPUHandle: *mut [NvPhysicalGpuHandle; types::NVAPI_MAX_PHYSICAL_GPUS], pGpuCount: *mut u32) -> NvAPI_Status;
 
@@ -56,6 +66,31 @@ nvapi_fn! {
     pub unsafe fn NvAPI_EnumPhysicalGPUs;
 }
 
+nvapi_fn! {
+    pub type EnumLogicalGPUsFn = extern "C" fn(nvGPUHandle: *mut [NvLogicalGpuHandle; types::NVAPI_MAX_LOGICAL_GPUS], pGpuCount: *mut u32) -> NvAPI_Status;
+
+    /// This function returns an array of logical GPU handles.
+    ///
+    /// Each handle represents one or more GPUs acting in concert as a single graphics device.
+    ///
+    /// At least one GPU must be present in the system and running an NVIDIA display driver.
+    pub unsafe fn NvAPI_EnumLogicalGPUs;
+}
+
+nvapi_fn! {
+    pub type GetPhysicalGPUsFromLogicalGPUFn = extern "C" fn(hLogicalGPU: NvLogicalGpuHandle, hPhysicalGPU: *mut [NvPhysicalGpuHandle; types::NVAPI_MAX_PHYSICAL_GPUS], pGpuCount: *mut u32) -> NvAPI_Status;
+
+    /// This function returns the physical GPUs associated with the specified logical GPU.
+    pub unsafe fn NvAPI_GetPhysicalGPUsFromLogicalGPU;
+}
+
+nvapi_fn! {
+    pub type GetLogicalGPUFromPhysicalGPUFn = extern "C" fn(hPhysicalGPU: NvPhysicalGpuHandle, pLogicalGPU: *mut NvLogicalGpuHandle) -> NvAPI_Status;
+
+    /// This function returns the logical GPU handle associated with specified physical GPU handle.
+    pub unsafe fn NvAPI_GetLogicalGPUFromPhysicalGPU;
+}
+
 nvapi_fn! {
     pub type GPU_GetFullNameFn = extern "C" fn(hPhysicalGPU: NvPhysicalGpuHandle, szName: *mut types::NvAPI_ShortString) -> NvAPI_Status;
 
@@ -71,6 +106,28 @@ nvapi_fn! {
     pub unsafe fn NvAPI_GPU_GetPhysicalFrameBufferSize;
 }
 
+nvapi_fn! {
+    pub type GPU_GetVirtualFrameBufferSizeFn = extern "C" fn(hPhysicalGPU: NvPhysicalGpuHandle, pSize: *mut u32) -> NvAPI_Status;
+
+    /// This function returns the virtual size of framebuffer in KB.  This includes the physical RAM plus any
+    /// system RAM that has been dedicated for use by the GPU.
+    pub unsafe fn NvAPI_GPU_GetVirtualFrameBufferSize;
+}
+
+nvapi_fn! {
+    pub type GPU_GetVbiosRevisionFn = extern "C" fn(hPhysicalGPU: NvPhysicalGpuHandle, pBiosRevision: *mut u32) -> NvAPI_Status;
+
+    /// This function returns the revision of the video BIOS associated with this GPU.
+    pub unsafe fn NvAPI_GPU_GetVbiosRevision;
+}
+
+nvapi_fn! {
+    pub type GPU_GetVbiosOEMRevisionFn = extern "C" fn(hPhysicalGPU: NvPhysicalGpuHandle, pBiosRevision: *mut u32) -> NvAPI_Status;
+
+    /// This function returns the OEM revision of the video BIOS associated with this GPU.
+    pub unsafe fn NvAPI_GPU_GetVbiosOEMRevision;
+}
+
 nvapi_fn! {
     pub type GPU_GetVbiosVersionStringFn = extern "C" fn(hPhysicalGPU: NvPhysicalGpuHandle, szBiosRevision: *mut types::NvAPI_ShortString) -> NvAPI_Status;
 
@@ -87,6 +144,32 @@ nvapi_fn! {
     pub unsafe fn NvAPI_GPU_GetPCIIdentifiers;
 }
 
+nvenum! {
+    /// Used in NvAPI_GPU_GetBusType()
+    pub enum NV_GPU_BUS_TYPE / BusType {
+        NVAPI_GPU_BUS_TYPE_UNDEFINED / Undefined = 0,
+        NVAPI_GPU_BUS_TYPE_PCI / Pci = 1,
+        NVAPI_GPU_BUS_TYPE_AGP / Agp = 2,
+        NVAPI_GPU_BUS_TYPE_PCI_EXPRESS / PciExpress = 3,
+        NVAPI_GPU_BUS_TYPE_FPCI / Fpci = 4,
+        NVAPI_GPU_BUS_TYPE_AXI / Axi = 5,
+    }
+}
+
+nvapi_fn! {
+    pub type GPU_GetBusTypeFn = extern "C" fn(hPhysicalGPU: NvPhysicalGpuHandle, pBusType: *mut NV_GPU_BUS_TYPE) -> NvAPI_Status;
+
+    /// This function returns the type of bus associated with this GPU.
+    pub unsafe fn NvAPI_GPU_GetBusType;
+}
+
+nvapi_fn! {
+    pub type GPU_GetCurrentPCIEDownstreamWidthFn = extern "C" fn(hPhysicalGpu: NvPhysicalGpuHandle, pWidth: *mut u32) -> NvAPI_Status;
+
+    /// This function returns the number of PCIE lanes being used for the PCIE interface downstream from the GPU.
+    pub unsafe fn NvAPI_GPU_GetCurrentPCIEDownstreamWidth;
+}
+
 nvenum! {
     /// Used in NvAPI_GPU_GetSystemType()
     pub enum NV_SYSTEM_TYPE / SystemType {
@@ -100,6 +183,14 @@ nvenum_display! {
     SystemType => _
 }
 
+impl SystemType {
+    /// Whether this is a notebook GPU, which may run with a narrower PCIe link and tighter
+    /// power limits than its desktop counterpart.
+    pub fn is_mobile(&self) -> bool {
+        *self == SystemType::Laptop
+    }
+}
+
 nvapi_fn! {
     pub type GPU_GetSystemTypeFn = extern "C" fn(hPhysicalGPU: NvPhysicalGpuHandle, pSystemType: *mut NV_SYSTEM_TYPE) -> NvAPI_Status;
 
@@ -123,6 +214,101 @@ nvapi_fn! {
     pub unsafe fn NvAPI_GPU_GetGpuCoreCount;
 }
 
+nvapi_fn! {
+    pub type GPU_GetQuadroStatusFn = extern "C" fn(hPhysicalGPU: NvPhysicalGpuHandle, pStatus: *mut u32) -> NvAPI_Status;
+
+    /// This function retrieves the Quadro status for the GPU (1 if Quadro, 0 if GeForce)
+    pub unsafe fn NvAPI_GPU_GetQuadroStatus;
+}
+
+nvbits! {
+    /// Used in NvAPI_GPU_WorkstationFeatureQuery()
+    pub enum NVAPI_GPU_WORKSTATION_FEATURE_MASK / WorkstationFeatures {
+        NVAPI_GPU_WORKSTATION_FEATURE_MASK_SWAPGROUP / SWAPGROUP = 0x00000001,
+        NVAPI_GPU_WORKSTATION_FEATURE_MASK_STEREO / STEREO = 0x00000010,
+        NVAPI_GPU_WORKSTATION_FEATURE_MASK_WARPING / WARPING = 0x00000100,
+        NVAPI_GPU_WORKSTATION_FEATURE_MASK_PIXINTENSITY / PIXINTENSITY = 0x00000200,
+        NVAPI_GPU_WORKSTATION_FEATURE_MASK_GRAYSCALE / GRAYSCALE = 0x00000400,
+        NVAPI_GPU_WORKSTATION_FEATURE_MASK_BPC10 / BPC10 = 0x00001000,
+    }
+}
+
+nvapi_fn! {
+    pub type GPU_WorkstationFeatureQueryFn = extern "C" fn(hPhysicalGpu: NvPhysicalGpuHandle, pConfiguredFeatureMask: *mut NVAPI_GPU_WORKSTATION_FEATURE_MASK, pConsistentFeatureMask: *mut NVAPI_GPU_WORKSTATION_FEATURE_MASK) -> NvAPI_Status;
+
+    /// This API queries the current set of workstation features.
+    ///
+    /// `pConsistentFeatureMask` receives the features whose configuration is consistent across
+    /// the system. Either pointer may be null.
+    pub unsafe fn NvAPI_GPU_WorkstationFeatureQuery;
+}
+
+nvenum! {
+    pub enum NV_GPU_HDCP_FUSE_STATE / HdcpFuseState {
+        NV_GPU_HDCP_FUSE_STATE_UNKNOWN / Unknown = 0,
+        NV_GPU_HDCP_FUSE_STATE_DISABLED / Disabled = 1,
+        NV_GPU_HDCP_FUSE_STATE_ENABLED / Enabled = 2,
+    }
+}
+
+nvenum_display! {
+    HdcpFuseState => _
+}
+
+nvenum! {
+    pub enum NV_GPU_HDCP_KEY_SOURCE / HdcpKeySource {
+        NV_GPU_HDCP_KEY_SOURCE_UNKNOWN / Unknown = 0,
+        NV_GPU_HDCP_KEY_SOURCE_NONE / None = 1,
+        NV_GPU_HDCP_KEY_SOURCE_CRYPTO_ROM / CryptoRom = 2,
+        NV_GPU_HDCP_KEY_SOURCE_SBIOS / Sbios = 3,
+        NV_GPU_HDCP_KEY_SOURCE_I2C_ROM / I2cRom = 4,
+        NV_GPU_HDCP_KEY_SOURCE_FUSES / Fuses = 5,
+    }
+}
+
+nvenum_display! {
+    HdcpKeySource => {
+        CryptoRom = "Crypto ROM",
+        Sbios = "SBIOS",
+        I2cRom = "I2C ROM",
+        _ = _,
+    }
+}
+
+nvenum! {
+    pub enum NV_GPU_HDCP_KEY_SOURCE_STATE / HdcpKeySourceState {
+        NV_GPU_HDCP_KEY_SOURCE_STATE_UNKNOWN / Unknown = 0,
+        NV_GPU_HDCP_KEY_SOURCE_STATE_ABSENT / Absent = 1,
+        NV_GPU_HDCP_KEY_SOURCE_STATE_PRESENT / Present = 2,
+    }
+}
+
+nvenum_display! {
+    HdcpKeySourceState => _
+}
+
+nvstruct! {
+    pub struct NV_GPU_GET_HDCP_SUPPORT_STATUS {
+        /// Structure version
+        pub version: u32,
+        /// GPU's HDCP fuse state
+        pub hdcpFuseState: NV_GPU_HDCP_FUSE_STATE,
+        /// GPU's HDCP key source
+        pub hdcpKeySource: NV_GPU_HDCP_KEY_SOURCE,
+        /// GPU's HDCP key source state
+        pub hdcpKeySourceState: NV_GPU_HDCP_KEY_SOURCE_STATE,
+    }
+}
+
+nvversion! { NV_GPU_GET_HDCP_SUPPORT_STATUS_VER(NV_GPU_GET_HDCP_SUPPORT_STATUS = 4 * 4, 1) }
+
+nvapi_fn! {
+    pub type GPU_GetHDCPSupportStatusFn = extern "C" fn(hPhysicalGpu: NvPhysicalGpuHandle, pGetHDCPSupportStatus: *mut NV_GPU_GET_HDCP_SUPPORT_STATUS) -> NvAPI_Status;
+
+    /// This function returns a GPU's HDCP support status.
+    pub unsafe fn NvAPI_GPU_GetHDCPSupportStatus;
+}
+
 nvstruct! {
     pub struct NV_BOARD_INFO_V1 {
         /// structure version
@@ -142,6 +328,74 @@ nvapi_fn! {
     pub unsafe fn NvAPI_GPU_GetBoardInfo(hPhysicalGpu: NvPhysicalGpuHandle, pBoardInfo: *mut NV_BOARD_INFO) -> NvAPI_Status;
 }
 
+nvapi_fn! {
+    /// This API returns the OS-AdapterID from physicalGpu Handle. OS-AdapterID is the Adapter ID
+    /// that is used by Win7 CCD APIs.
+    ///
+    /// `pOSAdapterId` points to a Windows `LUID`, i.e. 8 bytes.
+    pub unsafe fn NvAPI_GPU_GetAdapterIdFromPhysicalGpu(hPhysicalGpu: NvPhysicalGpuHandle, pOSAdapterId: *mut c_void) -> NvAPI_Status;
+}
+
+nvenum! {
+    /// Used in NvAPI_GPU_GetArchInfo()
+    pub enum NV_GPU_ARCHITECTURE_ID / ArchitectureId {
+        NV_GPU_ARCHITECTURE_NV40 / NV40 = 0x00000040,
+        NV_GPU_ARCHITECTURE_NV50 / NV50 = 0x00000050,
+        NV_GPU_ARCHITECTURE_G78 / G78 = 0x00000060,
+        NV_GPU_ARCHITECTURE_G80 / G80 = 0x00000080,
+        NV_GPU_ARCHITECTURE_G90 / G90 = 0x00000090,
+        NV_GPU_ARCHITECTURE_GT200 / GT200 = 0x000000a0,
+        NV_GPU_ARCHITECTURE_GF100 / GF100 = 0x000000c0,
+        NV_GPU_ARCHITECTURE_GF110 / GF110 = 0x000000d0,
+        NV_GPU_ARCHITECTURE_GK100 / GK100 = 0x000000e0,
+        NV_GPU_ARCHITECTURE_GK110 / GK110 = 0x000000f0,
+        NV_GPU_ARCHITECTURE_GK200 / GK200 = 0x00000100,
+        NV_GPU_ARCHITECTURE_GM000 / GM000 = 0x00000110,
+        NV_GPU_ARCHITECTURE_GM200 / GM200 = 0x00000120,
+        NV_GPU_ARCHITECTURE_GP100 / GP100 = 0x00000130,
+        NV_GPU_ARCHITECTURE_GV100 / GV100 = 0x00000140,
+        NV_GPU_ARCHITECTURE_GV110 / GV110 = 0x00000150,
+        NV_GPU_ARCHITECTURE_TU100 / TU100 = 0x00000160,
+        NV_GPU_ARCHITECTURE_GA100 / GA100 = 0x00000170,
+        NV_GPU_ARCHITECTURE_GH100 / GH100 = 0x00000180,
+        NV_GPU_ARCHITECTURE_AD100 / AD100 = 0x00000190,
+        NV_GPU_ARCHITECTURE_GB200 / GB200 = 0x000001b0,
+    }
+}
+
+nvenum_display! {
+    ArchitectureId => _
+}
+
+nvstruct! {
+    /// Used in NvAPI_GPU_GetArchInfo()
+    pub struct NV_GPU_ARCH_INFO_V1 {
+        /// structure version
+        pub version: u32,
+        /// `NV_GPU_ARCHITECTURE_ID`
+        pub architecture: u32,
+        /// `NV_GPU_ARCH_IMPLEMENTATION_ID`
+        pub implementation: u32,
+        /// `NV_GPU_CHIP_REVISION`
+        pub revision: u32,
+    }
+}
+
+pub type NV_GPU_ARCH_INFO_V2 = NV_GPU_ARCH_INFO_V1;
+
+pub type NV_GPU_ARCH_INFO = NV_GPU_ARCH_INFO_V2;
+
+nvversion! { NV_GPU_ARCH_INFO_VER_1(NV_GPU_ARCH_INFO_V1 = 4 * 4, 1) }
+nvversion! { NV_GPU_ARCH_INFO_VER_2(NV_GPU_ARCH_INFO_V2 = 4 * 4, 2) }
+nvversion! { NV_GPU_ARCH_INFO_VER = NV_GPU_ARCH_INFO_VER_2 }
+
+nvapi_fn! {
+    pub type GPU_GetArchInfoFn = extern "C" fn(hPhysicalGpu: NvPhysicalGpuHandle, pGpuArchInfo: *mut NV_GPU_ARCH_INFO) -> NvAPI_Status;
+
+    /// This function retrieves the architecture, implementation and chip revision of the GPU.
+    pub unsafe fn NvAPI_GPU_GetArchInfo;
+}
+
 nvbits! {
     /// Bit masks for knowing the exact reason for performance decrease
     ///
@@ -157,6 +411,30 @@ nvbits! {
     }
 }
 
+impl ::std::fmt::Display for PerformanceDecreaseReason {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        if self.is_empty() {
+            return write!(f, "None")
+        }
+
+        for (i, reason) in self.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            let name = match reason {
+                PerformanceDecreaseReason::THERMAL_PROTECTION => "Thermal",
+                PerformanceDecreaseReason::POWER_CONTROL => "Power Limit",
+                PerformanceDecreaseReason::AC_BATTERY => "AC Battery",
+                PerformanceDecreaseReason::API_TRIGGERED => "API Triggered",
+                PerformanceDecreaseReason::INSUFFICIENT_POWER => "Insufficient Power",
+                _ => "Unknown",
+            };
+            write!(f, "{}", name)?;
+        }
+        Ok(())
+    }
+}
+
 nvapi_fn! {
     /// This function retrieves reasons for the current performance decrease.
     pub unsafe fn NvAPI_GPU_GetPerfDecreaseInfo(hPhysicalGpu: NvPhysicalGpuHandle, pPerfDecrInfo: *mut NVAPI_GPU_PERF_DECREASE) -> NvAPI_Status;
@@ -190,6 +468,9 @@ pub mod private {
             NV_GPU_RAM_GDDR5 / GDDR5 = 8,
             NV_GPU_RAM_LPDDR2 / LPDDR2 = 9,
             NV_GPU_RAM_GDDR5X / GDDR5X = 10,
+            NV_GPU_RAM_HBM2 / HBM2 = 12,
+            NV_GPU_RAM_GDDR6 / GDDR6 = 14,
+            NV_GPU_RAM_GDDR6X / GDDR6X = 15,
         }
     }
 
@@ -160,6 +160,9 @@ pub mod private {
     }
     const NV_GPU_CLIENT_THERMAL_POLICIES_STATUS_ENTRY_SIZE: usize = 4 * 3;
 
+    /// Entry flag that gives the temperature limit precedence over the power limit.
+    pub const NV_GPU_CLIENT_THERMAL_POLICIES_FLAGS_PRIORITY: u32 = 1;
+
     nvstruct! {
         pub struct NV_GPU_CLIENT_THERMAL_POLICIES_STATUS_V2 {
             pub version: u32,
@@ -4,10 +4,10 @@ use crate::handles::NvPhysicalGpuHandle;
 
 nvstruct! {
     pub struct NV_GSYNC_CAPABILITIES_V1 {
-        version: u32,
-        boardId: u32,
-        revision: u32,
-        capFlags: u32,
+        pub version: u32,
+        pub boardId: u32,
+        pub revision: u32,
+        pub capFlags: u32,
     }
 }
 
@@ -15,8 +15,8 @@ const NV_GSYNC_CAPABILITIES_V1_SIZE: usize = 4 * 4;
 
 nvstruct! {
     pub struct NV_GSYNC_CAPABILITIES_V2 {
-        v1: NV_GSYNC_CAPABILITIES_V1,
-        extendedRevision: u32,
+        pub v1: NV_GSYNC_CAPABILITIES_V1,
+        pub extendedRevision: u32,
     }
 }
 
@@ -40,11 +40,20 @@ nvenum! {
 
 nvstruct! {
     pub struct NV_GSYNC_DISPLAY {
-        version: u32,
-        displayId: u32,
-        isMasterable: u32,
-        reserved: u32,
- 	    syncState: NVAPI_GSYNC_DISPLAY_SYNC_STATE,
+        pub version: u32,
+        pub displayId: u32,
+        /// Bitfield of `isMasterable:1` and `reserved:31`
+        pub flags: u32,
+        pub syncState: NVAPI_GSYNC_DISPLAY_SYNC_STATE,
+    }
+}
+
+nvversion! { NV_GSYNC_DISPLAY_VER(NV_GSYNC_DISPLAY = 4 * 4, 1) }
+
+impl NV_GSYNC_DISPLAY {
+    /// Whether the display can be the sync master
+    pub fn is_masterable(&self) -> bool {
+        self.flags & 0x1 != 0
     }
 }
 
@@ -58,17 +67,32 @@ nvenum! {
     }
 }
 
+nvenum_display! {
+    TopologyConnector => _
+}
+
 nvstruct! {
     pub struct NV_GSYNC_GPU {
-        version: u32,
-        hPhysicalGpu: NvPhysicalGpuHandle,
-        connector: NVAPI_GSYNC_GPU_TOPOLOGY_CONNECTOR,
-        hProxyPhysicalGpu: NvPhysicalGpuHandle,
-        isSynced: u32,
-        reserved: u32,
+        pub version: u32,
+        pub hPhysicalGpu: NvPhysicalGpuHandle,
+        pub connector: NVAPI_GSYNC_GPU_TOPOLOGY_CONNECTOR,
+        pub hProxyPhysicalGpu: NvPhysicalGpuHandle,
+        /// Bitfield of `isSynced:1` and `reserved:31`
+        pub flags: u32,
     }
 }
 
+impl NV_GSYNC_GPU {
+    /// Whether the GPU is synced to the sync device
+    pub fn is_synced(&self) -> bool {
+        self.flags & 0x1 != 0
+    }
+}
+
+const NV_GSYNC_GPU_SIZE: usize = std::mem::size_of::<NV_GSYNC_GPU>();
+
+nvversion! { NV_GSYNC_GPU_VER(NV_GSYNC_GPU = NV_GSYNC_GPU_SIZE, 1) }
+
 nvenum! {
     pub enum NVAPI_GSYNC_POLARITY / Polarity {
         NVAPI_GSYNC_POLARITY_RISING_EDGE / RisingEdge = 0, 
@@ -96,14 +120,32 @@ nvenum! {
 
 nvstruct! {
     pub struct NV_GSYNC_DELAY {
-        version: u32,
-        numLines: u32,
-        numPixels: u32,
+        pub version: u32,
+        /// delay to be induced in number of horizontal lines.
+        pub numLines: u32,
+        /// delay to be induced in number of pixels.
+        pub numPixels: u32,
         maxLines: u32,
         minPixels: u32,
     }
 }
 
+nvversion! { NV_GSYNC_DELAY_VER(NV_GSYNC_DELAY = 4 * 5, 1) }
+
+impl NV_GSYNC_DELAY {
+    /// Maximum number of lines supported at current display mode to induce delay.
+    /// Updated by `NvAPI_GSync_GetControlParameters()`. Read only.
+    pub fn maxLines(&self) -> u32 {
+        self.maxLines
+    }
+
+    /// Minimum number of pixels required to induce delay.
+    /// Updated by `NvAPI_GSync_GetControlParameters()`. Read only.
+    pub fn minPixels(&self) -> u32 {
+        self.minPixels
+    }
+}
+
 nvstruct! {
     pub struct NV_GSYNC_CONTROL_PARAMS {
         version: u32,
@@ -137,8 +179,7 @@ nvstruct! {
     }
 }
 
-// TODO: this most likely wont work and needs to be updated.
-nvversion! { NV_GSYNC_STATUS_VER(NV_GSYNC_STATUS = 0, 1) /* temp */}
+nvversion! { NV_GSYNC_STATUS_VER(NV_GSYNC_STATUS = 4 * 4, 1) }
 
 nvenum! {
     pub enum NVAPI_GSYNC_RJ45_IO / RJ45_IO {
@@ -152,12 +193,14 @@ pub const NVAPI_MAX_RJ45_PER_GSYNC: usize = 2;
 
 nvstruct! {
     pub struct NV_GSYNC_STATUS_PARAMS_V1 {
-        version: u32,
-     	refreshRate: u32,
-     	RJ45_IO: [NVAPI_GSYNC_RJ45_IO; NVAPI_MAX_RJ45_PER_GSYNC],
-     	RJ45_Ethernet: [u32; NVAPI_MAX_RJ45_PER_GSYNC],
-     	houseSyncIncoming: u32,
-     	bHouseSync: u32,
+        pub version: u32,
+        pub refreshRate: u32,
+        pub RJ45_IO: [NVAPI_GSYNC_RJ45_IO; NVAPI_MAX_RJ45_PER_GSYNC],
+        pub RJ45_Ethernet: [u32; NVAPI_MAX_RJ45_PER_GSYNC],
+        /// Frequency of the incoming house sync signal in Hz
+        pub houseSyncIncoming: u32,
+        /// Whether a house sync signal is detected on the BNC connector
+        pub bHouseSync: u32,
     }
 }
 
@@ -165,9 +208,9 @@ const NV_GSYNC_STATUS_PARAMS_V1_SIZE: usize = std::mem::size_of::<NV_GSYNC_STATU
 
 nvstruct! {
     pub struct NV_GSYNC_STATUS_PARAMS_V2 {
-        v1: NV_GSYNC_STATUS_PARAMS_V1,
-        bInternalSlave: u32,
-        reserved: u32,
+        pub v1: NV_GSYNC_STATUS_PARAMS_V1,
+        pub bInternalSlave: u32,
+        pub reserved: u32,
     }
 }
 
@@ -181,48 +224,53 @@ nvversion! { NV_GSYNC_STATUS_PARAMS_VER_1(NV_GSYNC_STATUS_PARAMS_V1 = NV_GSYNC_S
 nvversion! { NV_GSYNC_STATUS_PARAMS_VER_2(NV_GSYNC_STATUS_PARAMS_V2 = NV_GSYNC_STATUS_PARAMS_V2_SIZE, 2) }
 nvversion! { NV_GSYNC_STATUS_PARAMS_VER = NV_GSYNC_STATUS_PARAMS_VER_2 }
 
-nvapi! {
+nvapi_fn! {
     pub type GSync_EnumSyncDevicesFn = extern "C" fn(nvGSyncHandles: *mut [NvGSyncDeviceHandle; super::types::NVAPI_MAX_GSYNC_DEVICES], gsyncCount: *mut u32) -> NvAPI_Status;
     pub unsafe fn NvAPI_GSync_EnumSyncDevices;
 }
 
-nvapi! {
+nvapi_fn! {
     pub type GSync_QueryCapabilitiesFn = extern "C" fn(hNvGSyncDevice: NvGSyncDeviceHandle, pNvGSyncCapabilities: *mut NV_GSYNC_CAPABILITIES) -> NvAPI_Status;
     pub unsafe fn NvAPI_GSync_QueryCapabilities;
 }
 
-nvapi! {
-    pub type GSync_GetTopologyFn = extern "C" fn(hNvGSyncDevice: NvGSyncDeviceHandle, gsyncGpuCount: *mut u32, gsyncGPUs: *mut NV_GSYNC_GPU, gsyncDisplayCount: u32, gsyncDisplays: *mut NV_GSYNC_DISPLAY) -> NvAPI_Status;
+nvapi_fn! {
+    pub type GSync_GetTopologyFn = extern "C" fn(hNvGSyncDevice: NvGSyncDeviceHandle, gsyncGpuCount: *mut u32, gsyncGPUs: *mut NV_GSYNC_GPU, gsyncDisplayCount: *mut u32, gsyncDisplays: *mut NV_GSYNC_DISPLAY) -> NvAPI_Status;
     pub unsafe fn NvAPI_GSync_GetTopology;
 }
 
-nvapi! {
-    pub type GSync_SetSyncStateSettingsFn = extern "C" fn(gsyncDisplayCount: u32, pGsyncDisplays: NV_GSYNC_DISPLAY, flags: u32) -> NvAPI_Status;
+nvapi_fn! {
+    pub type GSync_SetSyncStateSettingsFn = extern "C" fn(gsyncDisplayCount: u32, pGsyncDisplays: *mut NV_GSYNC_DISPLAY, flags: u32) -> NvAPI_Status;
     pub unsafe fn NvAPI_GSync_SetSyncStateSettings;
 }
 
-nvapi! {
+nvapi_fn! {
     pub type GSync_GetControlParametersFn = extern "C" fn(hNvGSyncDevice: NvGSyncDeviceHandle, pGsyncControls: *mut NV_GSYNC_CONTROL_PARAMS) -> NvAPI_Status;
     pub unsafe fn NvAPI_GSync_GetControlParameters;
 }
 
-nvapi! {
+nvapi_fn! {
     pub type GSync_SetControlParametersFn = extern "C" fn(hNvGSyncDevice: NvGSyncDeviceHandle, pGsyncControls: *mut NV_GSYNC_CONTROL_PARAMS) -> NvAPI_Status;
     pub unsafe fn NvAPI_GSync_SetControlParameters;
 }
 
-nvapi! {
-    // Parameter should be pointer?
+nvapi_fn! {
     pub type GSync_AdjustSyncDelayFn = extern "C" fn(hNvGSyncDevice: NvGSyncDeviceHandle, delayType: NVAPI_GSYNC_DELAY_TYPE, pGsyncDelay: *mut NV_GSYNC_DELAY, syncSteps: *mut u32) -> NvAPI_Status;
+
+    /// This API adjusts the skew and startup delay to the closest possible values. Use this API
+    /// to convert user-specified skew and startup delay in lines and pixels to the closest
+    /// possible delay supported by hardware.
+    ///
+    /// `syncSteps` optionally receives the number of 7.8 ns steps the sync is delayed by.
     pub unsafe fn NvAPI_GSync_AdjustSyncDelay;
 }
 
-nvapi! {
+nvapi_fn! {
     pub type GSync_GetSyncStatusFn = extern "C" fn(hNvGSyncDevice: NvGSyncDeviceHandle, hPhysicalGpu: NvPhysicalGpuHandle, status: *mut NV_GSYNC_STATUS) -> NvAPI_Status;
     pub unsafe fn NvAPI_GSync_GetSyncStatus;
 }
 
-nvapi! {
+nvapi_fn! {
     pub type GSync_GetStatusParametersFn = extern "C" fn(hNvGSyncDevice: NvGSyncDeviceHandle, pStatusParams: *mut NV_GSYNC_STATUS_PARAMS) -> NvAPI_Status;
     pub unsafe fn NvAPI_GSync_GetStatusParameters;
 }
\ No newline at end of file
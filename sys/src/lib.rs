@@ -1,9 +1,14 @@
 #![allow(non_camel_case_types, non_snake_case)]
 #![doc(html_root_url = "http://docs.rs/nvapi-sys/0.2.0")]
+// `nvenum!` recurses once per variant, and `Status` has well over 128 of them
+#![recursion_limit = "256"]
 
 #[macro_use]
 mod macros;
 
+#[cfg(feature = "serde")]
+mod serde_impl;
+
 pub mod nvid;
 pub mod nvapi;
 pub mod status;
@@ -35,6 +40,15 @@ pub mod dx;
 
 pub mod dispcontrol;
 
+/// System APIs - Information about the platform the GPUs are installed in.
+pub mod system;
+
+/// Mosaic APIs - Combine multiple displays into a single logical desktop.
+pub mod mosaic;
+
+#[cfg(feature = "mock")]
+pub mod mock;
+
 pub use nvid::Api;
 pub use nvapi::nvapi_QueryInterface;
 pub use types::*;
@@ -47,13 +61,30 @@ use std::convert::Infallible;
 pub type Result<T> = result::Result<T, Status>;
 
 /// Treat `NVAPI_OK` as `Ok(())` and all else as an `Err(..)`.
+///
+/// Codes unknown to `Status` become `Status::Unknown`, which asks the driver to describe them.
 pub fn status_result(status: NvAPI_Status) -> Result<()> {
     match status {
         status::NVAPI_OK => Ok(()),
-        status => Err(Status::from_raw(status).unwrap_or(Status::Error)),
+        status => Err(Status::from_code(status)),
     }
 }
 
+/// Calls `f` with each struct version in turn, newest first, moving on to the next one only
+/// while the driver reports `IncompatibleStructVersion`.
+///
+/// Returns `IncompatibleStructVersion` if every version is rejected or `versions` is empty.
+pub fn with_version_fallback<T, F: FnMut(u32) -> Result<T>>(versions: &[u32], mut f: F) -> Result<T> {
+    for &version in versions {
+        match f(version) {
+            Err(Status::IncompatibleStructVersion) => (),
+            res => return res,
+        }
+    }
+
+    Err(Status::IncompatibleStructVersion)
+}
+
 /// Error type indicating a raw value is out of the range of known enum values.
 #[derive(Debug, Copy, Clone, Default)]
 pub struct ArgumentRangeError;
@@ -6,15 +6,28 @@ macro_rules! nv_declare_handle {
     ) => {
         $(#[$meta])*
         #[derive(Copy, Clone, Debug)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         #[allow(dead_code)]
         #[repr(transparent)]
-        pub struct $name(*const ::std::os::raw::c_void);
+        pub struct $name(
+            #[cfg_attr(feature = "serde", serde(with = "crate::serde_impl::pointer"))]
+            *const ::std::os::raw::c_void
+        );
 
         impl Default for $name {
             fn default() -> Self {
                 $name(::std::ptr::null())
             }
         }
+
+        impl $name {
+            pub fn is_null(&self) -> bool {
+                self.0.is_null()
+            }
+        }
+
+        #[cfg(feature = "mock")]
+        impl crate::mock::MockArg for $name { }
     };
 }
 
@@ -47,11 +60,42 @@ macro_rules! nvstruct {
             $($tt:tt)*
         }
     ) => {
+        nvstruct! { @fields [$(#[$meta])*] $name [] $($tt)* }
+    };
+    // Arrays and raw pointers are not covered by serde's own impls, so their fields get adapters.
+    (@fields $meta:tt $name:ident [$($out:tt)*]
+        $(#[$fmeta:meta])* $vis:vis $field:ident: [$ty:ty; $len:expr] $(, $($rest:tt)*)?
+    ) => {
+        nvstruct! { @fields $meta $name [$($out)*
+            $(#[$fmeta])*
+            #[cfg_attr(feature = "serde", serde(with = "crate::serde_impl::array"))]
+            $vis $field: [$ty; $len],
+        ] $($($rest)*)? }
+    };
+    (@fields $meta:tt $name:ident [$($out:tt)*]
+        $(#[$fmeta:meta])* $vis:vis $field:ident: *$mutability:ident $ty:ty $(, $($rest:tt)*)?
+    ) => {
+        nvstruct! { @fields $meta $name [$($out)*
+            $(#[$fmeta])*
+            #[cfg_attr(feature = "serde", serde(with = "crate::serde_impl::pointer"))]
+            $vis $field: *$mutability $ty,
+        ] $($($rest)*)? }
+    };
+    (@fields $meta:tt $name:ident [$($out:tt)*]
+        $(#[$fmeta:meta])* $vis:vis $field:ident: $ty:ty $(, $($rest:tt)*)?
+    ) => {
+        nvstruct! { @fields $meta $name [$($out)*
+            $(#[$fmeta])*
+            $vis $field: $ty,
+        ] $($($rest)*)? }
+    };
+    (@fields [$(#[$meta:meta])*] $name:ident [$($out:tt)*]) => {
         $(#[$meta])*
         #[repr(C)]
         #[derive(Copy, Clone, Debug)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub struct $name {
-            $($tt)*
+            $($out)*
         }
 
         impl $name {
@@ -67,12 +111,30 @@ macro_rules! nvenum {
     (
         $(#[$meta:meta])*
         pub enum $enum:ident / $enum_name:ident {
-            $(
-                $(#[$metai:meta])*
-                $symbol:ident / $name:ident = $value:expr,
-            )*
+            $($tt:tt)*
         }
     ) => {
+        nvenum! { @variants [$(#[$meta])* pub enum $enum / $enum_name] [] [] $($tt)* }
+    };
+    // A `#[default]` variant attribute is stripped and remembered for the `Default` impl.
+    (@variants $head:tt [$($out:tt)*] []
+        $(#[doc = $doc:literal])* #[default] $(#[$metai:meta])*
+        $symbol:ident / $name:ident = $value:expr, $($rest:tt)*
+    ) => {
+        nvenum! { @variants $head [$($out)* $(#[doc = $doc])* $(#[$metai])* $symbol / $name = $value,] [$name] $($rest)* }
+    };
+    (@variants $head:tt [$($out:tt)*] $default:tt
+        $(#[$metai:meta])*
+        $symbol:ident / $name:ident = $value:expr, $($rest:tt)*
+    ) => {
+        nvenum! { @variants $head [$($out)* $(#[$metai])* $symbol / $name = $value,] $default $($rest)* }
+    };
+    (@variants [$(#[$meta:meta])* pub enum $enum:ident / $enum_name:ident] [
+        $(
+            $(#[$metai:meta])*
+            $symbol:ident / $name:ident = $value:expr,
+        )*
+    ] [$($default:ident)?]) => {
         $(#[$meta])*
         pub type $enum = ::std::os::raw::c_int;
         $(
@@ -114,12 +176,36 @@ macro_rules! nvenum {
             }
         }
 
-        impl Into<$enum> for $enum_name {
-            fn into(self) -> $enum {
-                self as _
+        impl ::std::convert::TryFrom<$enum> for $enum_name {
+            type Error = crate::ArgumentRangeError;
+
+            fn try_from(raw: $enum) -> ::std::result::Result<Self, crate::ArgumentRangeError> {
+                Self::from_raw(raw)
+            }
+        }
+
+        impl From<$enum_name> for $enum {
+            fn from(v: $enum_name) -> Self {
+                v as _
+            }
+        }
+
+        /// The `#[default]` variant, otherwise the zero-valued (or first) one
+        impl Default for $enum_name {
+            fn default() -> Self {
+                nvenum!(@default $enum_name [$($default)?])
             }
         }
     };
+    (@default $enum_name:ident [$default:ident]) => {
+        $enum_name::$default
+    };
+    (@default $enum_name:ident []) => {
+        match $enum_name::from_raw(0) {
+            Ok(v) => v,
+            Err(_) => $enum_name::values().next().unwrap(),
+        }
+    };
 }
 
 #[macro_export]
@@ -152,12 +238,19 @@ macro_rules! nvbits {
             }
         }
 
+        impl $enum_name {
+            /// Iterates over the individual flags that are set.
+            pub fn iter(&self) -> Self {
+                *self
+            }
+        }
+
         impl Iterator for $enum_name {
             type Item = Self;
 
             fn next(&mut self) -> Option<Self::Item> {
                 $(
-                    if self.contains($enum_name::$name) {
+                    if !$enum_name::$name.is_empty() && self.contains($enum_name::$name) {
                         self.remove($enum_name::$name);
                         Some($enum_name::$name)
                     } else
@@ -217,6 +310,14 @@ macro_rules! nvapi_fn {
         pub unsafe fn $fn($($arg: $arg_ty),*) -> $ret {
             static CACHE: ::std::sync::atomic::AtomicUsize = ::std::sync::atomic::AtomicUsize::new(0);
 
+            #[cfg(feature = "mock")]
+            {
+                crate::mock::record(crate::nvid::Api::$fn, vec![$(crate::mock::MockArg::mock_input(&$arg)),*]);
+                if let Some(status) = crate::mock::dispatch(crate::nvid::Api::$fn, &[$(crate::mock::MockArg::mock_output(&$arg)),*]) {
+                    return status
+                }
+            }
+
             match crate::nvapi::query_interface(crate::nvid::Api::$fn.id(), &CACHE) {
                 Ok(ptr) => ::std::mem::transmute::<_, extern "C" fn($($arg: $arg_ty),*) -> $ret>(ptr)($($arg),*),
                 Err(e) => e.raw(),
@@ -0,0 +1,134 @@
+//! An in-memory NVAPI backend for testing without a driver.
+//!
+//! With the `mock` feature enabled, every NVAPI function first checks for a response registered
+//! with `set_response` or `set_status`. Functions without one fall through to the real driver.
+//!
+//! A response is written to the output (`*mut`) arguments of the call in order, each taking as many
+//! bytes as its pointee is large, until the response runs out. For example a response for
+//! `NvAPI_EnumPhysicalGPUs` holds `NVAPI_MAX_PHYSICAL_GPUS` handles followed by the `u32` count.
+//! Untyped `*mut c_void` outputs only take a single byte.
+//!
+//! Every call is recorded, so `last_call` can check the values passed to the driver.
+//!
+//! Responses are global to the process, so tests that share an API must not run concurrently.
+
+use std::collections::BTreeMap;
+use std::mem::size_of;
+use std::sync::Mutex;
+use std::{ptr, slice};
+use crate::nvid::Api;
+use crate::status::{NvAPI_Status, Status};
+
+struct Response {
+    status: Status,
+    bytes: Vec<u8>,
+}
+
+static RESPONSES: Mutex<BTreeMap<Api, Response>> = Mutex::new(BTreeMap::new());
+static CALLS: Mutex<BTreeMap<Api, Vec<Vec<u8>>>> = Mutex::new(BTreeMap::new());
+
+fn insert(api: Api, response: Response) {
+    RESPONSES.lock().unwrap_or_else(|e| e.into_inner()).insert(api, response);
+}
+
+/// Makes `api` succeed, filling its output arguments with `bytes`.
+pub fn set_response(api: Api, bytes: Vec<u8>) {
+    insert(api, Response { status: Status::Ok, bytes })
+}
+
+/// Makes `api` fail with `status` without touching its arguments.
+pub fn set_status(api: Api, status: Status) {
+    insert(api, Response { status, bytes: Vec::new() })
+}
+
+/// Lets `api` fall through to the driver again.
+pub fn remove_response(api: Api) {
+    RESPONSES.lock().unwrap_or_else(|e| e.into_inner()).remove(&api);
+}
+
+/// Removes every registered response and recorded call.
+pub fn clear() {
+    RESPONSES.lock().unwrap_or_else(|e| e.into_inner()).clear();
+    CALLS.lock().unwrap_or_else(|e| e.into_inner()).clear();
+}
+
+/// The arguments of the last call to `api`, in their in-memory representation.
+///
+/// Only plain integer and float arguments are recorded, pointers and handles are left empty.
+pub fn last_call(api: Api) -> Option<Vec<Vec<u8>>> {
+    CALLS.lock().unwrap_or_else(|e| e.into_inner()).get(&api).cloned()
+}
+
+/// The in-memory representation of `value`, for building a response out of NVAPI structs.
+pub fn struct_bytes<T: Copy>(value: &T) -> Vec<u8> {
+    unsafe { slice::from_raw_parts(value as *const T as *const u8, size_of::<T>()) }.to_vec()
+}
+
+/// An argument of an NVAPI function, which may be written to by a mock response.
+pub trait MockArg {
+    /// The buffer behind an output argument.
+    fn mock_output(&self) -> Option<(*mut u8, usize)> {
+        None
+    }
+
+    /// The value of an input argument, as recorded for `last_call`.
+    fn mock_input(&self) -> Vec<u8> {
+        Vec::new()
+    }
+}
+
+impl<T> MockArg for *mut T {
+    fn mock_output(&self) -> Option<(*mut u8, usize)> {
+        if self.is_null() {
+            None
+        } else {
+            Some((*self as *mut u8, size_of::<T>()))
+        }
+    }
+}
+
+impl<T> MockArg for *const T { }
+
+macro_rules! mock_arg_values {
+    ($($ty:ty),*) => {
+        $(
+            impl MockArg for $ty {
+                fn mock_input(&self) -> Vec<u8> {
+                    self.to_ne_bytes().to_vec()
+                }
+            }
+        )*
+    };
+}
+
+mock_arg_values! { u8, u16, u32, u64, i8, i16, i32, i64, usize, isize, f32, f64 }
+
+/// Records the arguments of a call for `last_call`.
+#[doc(hidden)]
+pub fn record(api: Api, inputs: Vec<Vec<u8>>) {
+    CALLS.lock().unwrap_or_else(|e| e.into_inner()).insert(api, inputs);
+}
+
+/// Returns the status of a registered response after writing it to `outputs`, or `None` if the
+/// call should go to the driver.
+///
+/// # Safety
+///
+/// Every output must be valid for writes of its length.
+#[doc(hidden)]
+pub unsafe fn dispatch(api: Api, outputs: &[Option<(*mut u8, usize)>]) -> Option<NvAPI_Status> {
+    let responses = RESPONSES.lock().unwrap_or_else(|e| e.into_inner());
+    let response = responses.get(&api)?;
+
+    let mut bytes = &response.bytes[..];
+    for &(output, len) in outputs.iter().flatten() {
+        if bytes.is_empty() {
+            break
+        }
+        let len = len.min(bytes.len());
+        ptr::copy_nonoverlapping(bytes.as_ptr(), output, len);
+        bytes = &bytes[len..];
+    }
+
+    Some(response.status.raw())
+}
@@ -0,0 +1,698 @@
+#![allow(non_upper_case_globals)]
+
+use crate::status::NvAPI_Status;
+use crate::handles::{NvLogicalGpuHandle, NvPhysicalGpuHandle};
+use crate::types::{NV_RECT, NVAPI_MAX_DISPLAYS};
+
+pub const NVAPI_MAX_MOSAIC_DISPLAY_ROWS: usize = 8;
+pub const NVAPI_MAX_MOSAIC_DISPLAY_COLUMNS: usize = 8;
+pub const NVAPI_MAX_MOSAIC_TOPOS: usize = 16;
+
+/// Maximum number of displays in a Mosaic grid.
+pub const NV_MOSAIC_MAX_DISPLAYS: usize = 64;
+
+/// Maximum number of display settings reported in `NV_MOSAIC_SUPPORTED_TOPO_INFO`.
+pub const NV_MOSAIC_DISPLAY_SETTINGS_MAX: usize = 40;
+
+pub const NV_MOSAIC_TOPO_VALIDITY_VALID: NV_MOSAIC_TOPO_VALIDITY = 0x00000000;
+
+nvbits! {
+    /// Bitfield in `NV_MOSAIC_TOPO_DETAILS::validityMask`
+    pub enum NV_MOSAIC_TOPO_VALIDITY / MosaicTopoValidity {
+        /// A GPU required by the topology is not present
+        NV_MOSAIC_TOPO_VALIDITY_MISSING_GPU / MISSING_GPU = 0x00000001,
+        /// A display required by the topology is not connected
+        NV_MOSAIC_TOPO_VALIDITY_MISSING_DISPLAY / MISSING_DISPLAY = 0x00000002,
+        /// The connected displays are of different types
+        NV_MOSAIC_TOPO_VALIDITY_MIXED_DISPLAY_TYPES / MIXED_DISPLAY_TYPES = 0x00000004,
+    }
+}
+
+nvstruct! {
+    /// Per-display layout entry of `NV_MOSAIC_TOPO_DETAILS`.
+    pub struct NV_MOSAIC_TOPO_GPU_LAYOUT_CELL {
+        /// Physical GPU to be used in the topology (0 if GPU missing)
+        pub hPhysicalGPU: NvPhysicalGpuHandle,
+        /// Connected display target (0 if no display connected)
+        pub displayOutputId: u32,
+        /// Pixels of overlap on left of target: (+overlap, -gap)
+        pub overlapX: i32,
+        /// Pixels of overlap on top of target: (+overlap, -gap)
+        pub overlapY: i32,
+    }
+}
+
+nvstruct! {
+    /// This structure defines the topology details.
+    pub struct NV_MOSAIC_TOPO_DETAILS {
+        /// Version of this structure
+        pub version: u32,
+        /// Logical GPU for this topology
+        pub hLogicalGPU: NvLogicalGpuHandle,
+        /// 0 means topology is valid with the current hardware.
+        /// If not 0, inspect bits against NV_MOSAIC_TOPO_VALIDITY_*.
+        pub validityMask: NV_MOSAIC_TOPO_VALIDITY,
+        /// Number of displays in a row
+        pub rowCount: u32,
+        /// Number of displays in a column
+        pub colCount: u32,
+        pub gpuLayout: [[NV_MOSAIC_TOPO_GPU_LAYOUT_CELL; NVAPI_MAX_MOSAIC_DISPLAY_COLUMNS]; NVAPI_MAX_MOSAIC_DISPLAY_ROWS],
+    }
+}
+
+impl NV_MOSAIC_TOPO_DETAILS {
+    /// The reasons the topology is not valid with the current hardware, ignoring unknown bits.
+    pub fn validity(&self) -> MosaicTopoValidity {
+        MosaicTopoValidity::from_bits_truncate(self.validityMask)
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.validityMask == NV_MOSAIC_TOPO_VALIDITY_VALID
+    }
+}
+
+const NV_MOSAIC_TOPO_DETAILS_SIZE: usize = std::mem::size_of::<NV_MOSAIC_TOPO_DETAILS>();
+
+nvversion! { NVAPI_MOSAIC_TOPO_DETAILS_VER(NV_MOSAIC_TOPO_DETAILS = NV_MOSAIC_TOPO_DETAILS_SIZE, 1) }
+
+nvenum! {
+    /// These values refer to the different types of Mosaic topologies that are possible.  When
+    /// getting the supported Mosaic topologies, you can specify one of these types to narrow down
+    /// the returned list to only those that match the given type.
+    pub enum NV_MOSAIC_TOPO_TYPE / MosaicTopoType {
+        /// All mosaic topologies
+        NV_MOSAIC_TOPO_TYPE_ALL / All = 0,
+        /// Basic Mosaic topologies
+        NV_MOSAIC_TOPO_TYPE_BASIC / Basic = 1,
+        /// Passive Stereo topologies
+        NV_MOSAIC_TOPO_TYPE_PASSIVE_STEREO / PassiveStereo = 2,
+        /// Not supported at this time
+        NV_MOSAIC_TOPO_TYPE_SCALED_CLONE / ScaledClone = 3,
+        /// Not supported at this time
+        NV_MOSAIC_TOPO_TYPE_PASSIVE_STEREO_SCALED_CLONE / PassiveStereoScaledClone = 4,
+    }
+}
+
+nvenum_display! {
+    MosaicTopoType => _
+}
+
+pub const NV_MOSAIC_TOPO_BEGIN_BASIC: NV_MOSAIC_TOPO = NV_MOSAIC_TOPO_1x2_BASIC;
+pub const NV_MOSAIC_TOPO_END_BASIC: NV_MOSAIC_TOPO = NV_MOSAIC_TOPO_7x1_BASIC + 10;
+pub const NV_MOSAIC_TOPO_BEGIN_PASSIVE_STEREO: NV_MOSAIC_TOPO = NV_MOSAIC_TOPO_1x2_PASSIVE_STEREO;
+pub const NV_MOSAIC_TOPO_END_PASSIVE_STEREO: NV_MOSAIC_TOPO = NV_MOSAIC_TOPO_2x2_PASSIVE_STEREO + 4;
+pub const NV_MOSAIC_TOPO_BEGIN_PASSIVE_STEREO_SCALED_CLONE: NV_MOSAIC_TOPO = NV_MOSAIC_TOPO_1x1_PASSIVE_STEREO_SCALED_CLONE;
+pub const NV_MOSAIC_TOPO_END_PASSIVE_STEREO_SCALED_CLONE: NV_MOSAIC_TOPO = NV_MOSAIC_TOPO_1x1_PASSIVE_STEREO_SCALED_CLONE + 4;
+pub const NV_MOSAIC_TOPO_BEGIN_SCALED_CLONE: NV_MOSAIC_TOPO = NV_MOSAIC_TOPO_1x2_SCALED_CLONE;
+pub const NV_MOSAIC_TOPO_END_SCALED_CLONE: NV_MOSAIC_TOPO = NV_MOSAIC_TOPO_2x1_SCALED_CLONE + 4;
+pub const NV_MOSAIC_TOPO_MAX: usize = NV_MOSAIC_TOPO_END_SCALED_CLONE as usize + 1;
+
+nvenum! {
+    /// The complete list of supported Mosaic topologies.
+    ///
+    /// The naming is "NV_MOSAIC_TOPO_rxc(_type)", where r is the number of rows
+    /// and c is the number of columns.
+    pub enum NV_MOSAIC_TOPO / MosaicTopo {
+        /// Not a Mosaic Topology
+        NV_MOSAIC_TOPO_NONE / None = 0,
+        NV_MOSAIC_TOPO_1x2_BASIC / T1x2_Basic = 1,
+        NV_MOSAIC_TOPO_2x1_BASIC / T2x1_Basic = 2,
+        NV_MOSAIC_TOPO_1x3_BASIC / T1x3_Basic = 3,
+        NV_MOSAIC_TOPO_3x1_BASIC / T3x1_Basic = 4,
+        NV_MOSAIC_TOPO_1x4_BASIC / T1x4_Basic = 5,
+        NV_MOSAIC_TOPO_4x1_BASIC / T4x1_Basic = 6,
+        NV_MOSAIC_TOPO_2x2_BASIC / T2x2_Basic = 7,
+        NV_MOSAIC_TOPO_2x3_BASIC / T2x3_Basic = 8,
+        NV_MOSAIC_TOPO_2x4_BASIC / T2x4_Basic = 9,
+        NV_MOSAIC_TOPO_3x2_BASIC / T3x2_Basic = 10,
+        NV_MOSAIC_TOPO_4x2_BASIC / T4x2_Basic = 11,
+        NV_MOSAIC_TOPO_1x5_BASIC / T1x5_Basic = 12,
+        NV_MOSAIC_TOPO_1x6_BASIC / T1x6_Basic = 13,
+        NV_MOSAIC_TOPO_7x1_BASIC / T7x1_Basic = 14,
+        NV_MOSAIC_TOPO_1x2_PASSIVE_STEREO / T1x2_PassiveStereo = 25,
+        NV_MOSAIC_TOPO_2x1_PASSIVE_STEREO / T2x1_PassiveStereo = 26,
+        NV_MOSAIC_TOPO_1x3_PASSIVE_STEREO / T1x3_PassiveStereo = 27,
+        NV_MOSAIC_TOPO_3x1_PASSIVE_STEREO / T3x1_PassiveStereo = 28,
+        NV_MOSAIC_TOPO_1x4_PASSIVE_STEREO / T1x4_PassiveStereo = 29,
+        NV_MOSAIC_TOPO_4x1_PASSIVE_STEREO / T4x1_PassiveStereo = 30,
+        NV_MOSAIC_TOPO_2x2_PASSIVE_STEREO / T2x2_PassiveStereo = 31,
+        NV_MOSAIC_TOPO_1x1_PASSIVE_STEREO_SCALED_CLONE / T1x1_PassiveStereoScaledClone = 36,
+        NV_MOSAIC_TOPO_1x2_SCALED_CLONE / T1x2_ScaledClone = 41,
+        NV_MOSAIC_TOPO_2x1_SCALED_CLONE / T2x1_ScaledClone = 42,
+    }
+}
+
+nvenum_display! {
+    MosaicTopo => {
+        None = "None",
+        T1x2_Basic = "1x2 (Basic)",
+        T2x1_Basic = "2x1 (Basic)",
+        T1x3_Basic = "1x3 (Basic)",
+        T3x1_Basic = "3x1 (Basic)",
+        T1x4_Basic = "1x4 (Basic)",
+        T4x1_Basic = "4x1 (Basic)",
+        T2x2_Basic = "2x2 (Basic)",
+        T2x3_Basic = "2x3 (Basic)",
+        T2x4_Basic = "2x4 (Basic)",
+        T3x2_Basic = "3x2 (Basic)",
+        T4x2_Basic = "4x2 (Basic)",
+        T1x5_Basic = "1x5 (Basic)",
+        T1x6_Basic = "1x6 (Basic)",
+        T7x1_Basic = "7x1 (Basic)",
+        T1x2_PassiveStereo = "1x2 Passive Stereo",
+        T2x1_PassiveStereo = "2x1 Passive Stereo",
+        T1x3_PassiveStereo = "1x3 Passive Stereo",
+        T3x1_PassiveStereo = "3x1 Passive Stereo",
+        T1x4_PassiveStereo = "1x4 Passive Stereo",
+        T4x1_PassiveStereo = "4x1 Passive Stereo",
+        T2x2_PassiveStereo = "2x2 Passive Stereo",
+        T1x1_PassiveStereoScaledClone = "1x1 Passive Stereo Scaled Clone",
+        T1x2_ScaledClone = "1x2 Scaled Clone",
+        T2x1_ScaledClone = "2x1 Scaled Clone",
+    }
+}
+
+nvstruct! {
+    /// This is a topology brief structure.  It tells you what you need to know about
+    /// a topology at a high level. A list of these is returned when you query for the
+    /// supported Mosaic information.
+    ///
+    /// If you need more detailed information about the topology, call
+    /// NvAPI_Mosaic_GetTopoGroup() with the topology value from this structure.
+    pub struct NV_MOSAIC_TOPO_BRIEF {
+        /// Version of this structure
+        pub version: u32,
+        /// The topology
+        pub topo: NV_MOSAIC_TOPO,
+        /// 1 if topo is enabled, else 0
+        pub enabled: u32,
+        /// 1 if topo *can* be enabled, else 0
+        pub isPossible: u32,
+    }
+}
+
+nvversion! { NVAPI_MOSAIC_TOPO_BRIEF_VER(NV_MOSAIC_TOPO_BRIEF = 4 * 4, 1) }
+
+impl NV_MOSAIC_TOPO_BRIEF {
+    /// The typed topology of this brief.
+    pub fn topo_enum(&self) -> Result<MosaicTopo, crate::ArgumentRangeError> {
+        MosaicTopo::from_raw(self.topo)
+    }
+
+    /// Whether the topology drives separate left and right eye grids.
+    pub fn is_passive_stereo(&self) -> bool {
+        (NV_MOSAIC_TOPO_BEGIN_PASSIVE_STEREO..NV_MOSAIC_TOPO_END_PASSIVE_STEREO).contains(&self.topo)
+    }
+}
+
+nvstruct! {
+    /// Basic per-display settings that are used in setting/getting the Mosaic mode
+    pub struct NV_MOSAIC_DISPLAY_SETTING_V1 {
+        /// Version of this structure
+        pub version: u32,
+        /// Per-display width
+        pub width: u32,
+        /// Per-display height
+        pub height: u32,
+        /// Bits per pixel
+        pub bpp: u32,
+        /// Display frequency
+        pub freq: u32,
+    }
+}
+
+nvstruct! {
+    pub struct NV_MOSAIC_DISPLAY_SETTING_V2 {
+        pub v1: NV_MOSAIC_DISPLAY_SETTING_V1,
+        /// Display frequency in x1k
+        pub rrx1k: u32,
+    }
+}
+
+nvinherit! { NV_MOSAIC_DISPLAY_SETTING_V2(v1: NV_MOSAIC_DISPLAY_SETTING_V1) }
+
+pub type NV_MOSAIC_DISPLAY_SETTING = NV_MOSAIC_DISPLAY_SETTING_V2;
+
+nvversion! { NVAPI_MOSAIC_DISPLAY_SETTING_VER1(NV_MOSAIC_DISPLAY_SETTING_V1 = 4 * 5, 1) }
+nvversion! { NVAPI_MOSAIC_DISPLAY_SETTING_VER2(NV_MOSAIC_DISPLAY_SETTING_V2 = 4 * 6, 2) }
+nvversion! { NVAPI_MOSAIC_DISPLAY_SETTING_VER = NVAPI_MOSAIC_DISPLAY_SETTING_VER2 }
+
+nvstruct! {
+    /// This structure is used to contain a list of supported Mosaic topologies
+    /// along with the display settings that can be used.
+    pub struct NV_MOSAIC_SUPPORTED_TOPO_INFO_V1 {
+        /// Version of this structure
+        pub version: u32,
+        /// Number of topologies in below array
+        pub topoBriefsCount: u32,
+        /// List of supported topologies with only brief details
+        pub topoBriefs: [NV_MOSAIC_TOPO_BRIEF; NV_MOSAIC_TOPO_MAX],
+        /// Number of display settings in below array
+        pub displaySettingsCount: u32,
+        /// List of per display settings possible
+        pub displaySettings: [NV_MOSAIC_DISPLAY_SETTING_V1; NV_MOSAIC_DISPLAY_SETTINGS_MAX],
+    }
+}
+
+nvstruct! {
+    pub struct NV_MOSAIC_SUPPORTED_TOPO_INFO_V2 {
+        /// Version of this structure
+        pub version: u32,
+        /// Number of topologies in below array
+        pub topoBriefsCount: u32,
+        /// List of supported topologies with only brief details
+        pub topoBriefs: [NV_MOSAIC_TOPO_BRIEF; NV_MOSAIC_TOPO_MAX],
+        /// Number of display settings in below array
+        pub displaySettingsCount: u32,
+        /// List of per display settings possible
+        pub displaySettings: [NV_MOSAIC_DISPLAY_SETTING_V2; NV_MOSAIC_DISPLAY_SETTINGS_MAX],
+    }
+}
+
+pub type NV_MOSAIC_SUPPORTED_TOPO_INFO = NV_MOSAIC_SUPPORTED_TOPO_INFO_V2;
+
+impl NV_MOSAIC_SUPPORTED_TOPO_INFO_V2 {
+    /// The per display settings that are filled in.
+    pub fn display_settings(&self) -> &[NV_MOSAIC_DISPLAY_SETTING_V2] {
+        &self.displaySettings[..(self.displaySettingsCount as usize).min(NV_MOSAIC_DISPLAY_SETTINGS_MAX)]
+    }
+}
+
+nvversion! { NVAPI_MOSAIC_SUPPORTED_TOPO_INFO_VER1(NV_MOSAIC_SUPPORTED_TOPO_INFO_V1 = 4 * 3 + 16 * NV_MOSAIC_TOPO_MAX + 4 * 5 * NV_MOSAIC_DISPLAY_SETTINGS_MAX, 1) }
+nvversion! { NVAPI_MOSAIC_SUPPORTED_TOPO_INFO_VER2(NV_MOSAIC_SUPPORTED_TOPO_INFO_V2 = 4 * 3 + 16 * NV_MOSAIC_TOPO_MAX + 4 * 6 * NV_MOSAIC_DISPLAY_SETTINGS_MAX, 2) }
+nvversion! { NVAPI_MOSAIC_SUPPORTED_TOPO_INFO_VER = NVAPI_MOSAIC_SUPPORTED_TOPO_INFO_VER2 }
+
+nvstruct! {
+    /// This structure defines a group of topologies that work together to create one
+    /// overall layout.  All of the supported topologies are represented with this
+    /// structure.
+    ///
+    /// For example, a 'Passive Stereo' topology would be represented with this
+    /// structure, and would have separate topology details for the left and right eyes.
+    /// The count would be 2.  A 'Basic' topology is also represented by this structure,
+    /// with a count of 1.
+    pub struct NV_MOSAIC_TOPO_GROUP {
+        /// Version of this structure
+        pub version: u32,
+        /// The brief details of this topo
+        pub brief: NV_MOSAIC_TOPO_BRIEF,
+        /// Number of topos in array below
+        pub count: u32,
+        pub topos: [NV_MOSAIC_TOPO_DETAILS; NVAPI_MAX_MOSAIC_TOPOS],
+    }
+}
+
+const NV_MOSAIC_TOPO_GROUP_SIZE: usize = std::mem::size_of::<NV_MOSAIC_TOPO_GROUP>();
+
+nvversion! { NVAPI_MOSAIC_TOPO_GROUP_VER(NV_MOSAIC_TOPO_GROUP = NV_MOSAIC_TOPO_GROUP_SIZE, 1) }
+
+impl NV_MOSAIC_TOPO_GROUP {
+    /// The topologies that are part of this group.
+    pub fn topos(&self) -> &[NV_MOSAIC_TOPO_DETAILS] {
+        &self.topos[..(self.count as usize).min(NVAPI_MAX_MOSAIC_TOPOS)]
+    }
+}
+
+/// Index of the left eye topology in a passive stereo `NV_MOSAIC_TOPO_GROUP`.
+pub const NV_MOSAIC_TOPO_IDX_DEFAULT: usize = 0;
+pub const NV_MOSAIC_TOPO_IDX_LEFT_EYE: usize = 0;
+pub const NV_MOSAIC_TOPO_IDX_RIGHT_EYE: usize = 1;
+pub const NV_MOSAIC_TOPO_NUM_EYES: usize = 2;
+
+nvenum! {
+    /// Display rotation, used by `NV_MOSAIC_GRID_TOPO_DISPLAY`.
+    pub enum NV_ROTATE / Rotate {
+        NV_ROTATE_0 / Degrees0 = 0,
+        NV_ROTATE_90 / Degrees90 = 1,
+        NV_ROTATE_180 / Degrees180 = 2,
+        NV_ROTATE_270 / Degrees270 = 3,
+        NV_ROTATE_IGNORED / Ignored = 4,
+    }
+}
+
+nvenum! {
+    pub enum NV_PIXEL_SHIFT_TYPE / PixelShiftType {
+        /// No pixel shift will be applied to this display.
+        NV_PIXEL_SHIFT_TYPE_NO_PIXEL_SHIFT / NoPixelShift = 0,
+        /// This display will be used to scanout top left pixels in 2x2 PixelShift configuration
+        NV_PIXEL_SHIFT_TYPE_2x2_TOP_LEFT_PIXELS / TopLeft2x2 = 1,
+        /// This display will be used to scanout bottom right pixels in 2x2 PixelShift configuration
+        NV_PIXEL_SHIFT_TYPE_2x2_BOTTOM_RIGHT_PIXELS / BottomRight2x2 = 2,
+        /// This display will be used to scanout top right pixels in 2x2 PixelShift configuration
+        NV_PIXEL_SHIFT_TYPE_2x2_TOP_RIGHT_PIXELS / TopRight2x2 = 4,
+        /// This display will be used to scanout bottom left pixels in 2x2 PixelShift configuration
+        NV_PIXEL_SHIFT_TYPE_2x2_BOTTOM_LEFT_PIXELS / BottomLeft2x2 = 8,
+    }
+}
+
+nvstruct! {
+    pub struct NV_MOSAIC_GRID_TOPO_DISPLAY_V1 {
+        /// DisplayID of the display
+        pub displayId: u32,
+        /// (+overlap, -gap)
+        pub overlapX: i32,
+        /// (+overlap, -gap)
+        pub overlapY: i32,
+        /// Rotation of display
+        pub rotation: NV_ROTATE,
+        /// Reserved, must be 0
+        pub cloneGroup: u32,
+    }
+}
+
+nvstruct! {
+    pub struct NV_MOSAIC_GRID_TOPO_DISPLAY_V2 {
+        /// Version of this structure
+        pub version: u32,
+        /// DisplayID of the display
+        pub displayId: u32,
+        /// (+overlap, -gap)
+        pub overlapX: i32,
+        /// (+overlap, -gap)
+        pub overlapY: i32,
+        /// Rotation of display
+        pub rotation: NV_ROTATE,
+        /// Reserved, must be 0
+        pub cloneGroup: u32,
+        /// Type of the pixel shift enabled display
+        pub pixelShiftType: NV_PIXEL_SHIFT_TYPE,
+    }
+}
+
+pub type NV_MOSAIC_GRID_TOPO_DISPLAY = NV_MOSAIC_GRID_TOPO_DISPLAY_V2;
+
+nvversion! { NV_MOSAIC_GRID_TOPO_DISPLAY_VER(NV_MOSAIC_GRID_TOPO_DISPLAY_V2 = 4 * 7, 2) }
+
+nvbits! {
+    /// Bitfield in `NV_MOSAIC_GRID_TOPO::flags`
+    pub enum NV_MOSAIC_GRID_TOPO_FLAG / MosaicGridFlags {
+        /// Enable bezel correction when applying the grid
+        NV_MOSAIC_GRID_TOPO_FLAG_APPLY_WITH_BEZEL_CORRECT / APPLY_WITH_BEZEL_CORRECT = 1 << 0,
+        /// Enable as immersive gaming instead of Mosaic SLI (for Quadro-boards only)
+        NV_MOSAIC_GRID_TOPO_FLAG_IMMERSIVE_GAMING / IMMERSIVE_GAMING = 1 << 1,
+        /// Enable as Base Mosaic (Panoramic) instead of Mosaic SLI (for NVS and Quadro-boards only)
+        NV_MOSAIC_GRID_TOPO_FLAG_BASE_MOSAIC / BASE_MOSAIC = 1 << 2,
+        /// If necessary, reloading the driver is permitted (for Vista and above only).
+        /// Will not be persisted.
+        NV_MOSAIC_GRID_TOPO_FLAG_DRIVER_RELOAD_ALLOWED / DRIVER_RELOAD_ALLOWED = 1 << 3,
+        /// Enable SLI acceleration on the primary display while in single-wide mode (For Immersive Gaming only).
+        /// Will not be persisted.
+        NV_MOSAIC_GRID_TOPO_FLAG_ACCELERATE_PRIMARY_DISPLAY / ACCELERATE_PRIMARY_DISPLAY = 1 << 4,
+        /// Enable Pixel shift (V2 only)
+        NV_MOSAIC_GRID_TOPO_FLAG_PIXEL_SHIFT / PIXEL_SHIFT = 1 << 5,
+    }
+}
+
+nvstruct! {
+    pub struct NV_MOSAIC_GRID_TOPO_V1 {
+        /// Version of this structure
+        pub version: u32,
+        /// Number of rows
+        pub rows: u32,
+        /// Number of columns
+        pub columns: u32,
+        /// Number of display details
+        pub displayCount: u32,
+        /// Bitfield of `NV_MOSAIC_GRID_TOPO_FLAG_*`
+        pub flags: u32,
+        /// Displays are done as [(row * columns) + column]
+        pub displays: [NV_MOSAIC_GRID_TOPO_DISPLAY_V1; NV_MOSAIC_MAX_DISPLAYS],
+        /// Display settings
+        pub displaySettings: NV_MOSAIC_DISPLAY_SETTING_V1,
+    }
+}
+
+nvstruct! {
+    pub struct NV_MOSAIC_GRID_TOPO_V2 {
+        /// Version of this structure
+        pub version: u32,
+        /// Number of rows
+        pub rows: u32,
+        /// Number of columns
+        pub columns: u32,
+        /// Number of display details
+        pub displayCount: u32,
+        /// Bitfield of `NV_MOSAIC_GRID_TOPO_FLAG_*`
+        pub flags: u32,
+        /// Displays are done as [(row * columns) + column]
+        pub displays: [NV_MOSAIC_GRID_TOPO_DISPLAY_V2; NV_MOSAIC_MAX_DISPLAYS],
+        /// Display settings
+        pub displaySettings: NV_MOSAIC_DISPLAY_SETTING_V1,
+    }
+}
+
+impl NV_MOSAIC_GRID_TOPO_V1 {
+    pub fn flags(&self) -> MosaicGridFlags {
+        MosaicGridFlags::from_bits_truncate(self.flags)
+    }
+
+    /// Replaces the known flags, leaving any unknown bits as they are.
+    pub fn set_flags(&mut self, flags: MosaicGridFlags) {
+        self.flags = (self.flags & !MosaicGridFlags::all().bits()) | flags.bits();
+    }
+
+    pub fn has_flag(&self, flag: u32) -> bool {
+        self.flags & flag != 0
+    }
+
+    pub fn set_flag(&mut self, flag: u32, value: bool) {
+        if value {
+            self.flags |= flag;
+        } else {
+            self.flags &= !flag;
+        }
+    }
+
+    /// The displays that are part of this grid.
+    pub fn displays(&self) -> &[NV_MOSAIC_GRID_TOPO_DISPLAY_V1] {
+        &self.displays[..(self.displayCount as usize).min(NV_MOSAIC_MAX_DISPLAYS)]
+    }
+}
+
+impl NV_MOSAIC_GRID_TOPO_V2 {
+    pub fn flags(&self) -> MosaicGridFlags {
+        MosaicGridFlags::from_bits_truncate(self.flags)
+    }
+
+    /// Replaces the known flags, leaving any unknown bits as they are.
+    pub fn set_flags(&mut self, flags: MosaicGridFlags) {
+        self.flags = (self.flags & !MosaicGridFlags::all().bits()) | flags.bits();
+    }
+
+    pub fn has_flag(&self, flag: u32) -> bool {
+        self.flags & flag != 0
+    }
+
+    pub fn set_flag(&mut self, flag: u32, value: bool) {
+        if value {
+            self.flags |= flag;
+        } else {
+            self.flags &= !flag;
+        }
+    }
+
+    /// The displays that are part of this grid.
+    pub fn displays(&self) -> &[NV_MOSAIC_GRID_TOPO_DISPLAY_V2] {
+        &self.displays[..(self.displayCount as usize).min(NV_MOSAIC_MAX_DISPLAYS)]
+    }
+}
+
+/// Upgrades a grid returned by drivers that only support V1, leaving pixel shift disabled.
+impl From<NV_MOSAIC_GRID_TOPO_V1> for NV_MOSAIC_GRID_TOPO_V2 {
+    fn from(v1: NV_MOSAIC_GRID_TOPO_V1) -> Self {
+        let mut v2 = NV_MOSAIC_GRID_TOPO_V2::zeroed();
+        v2.version = NV_MOSAIC_GRID_TOPO_VER2;
+        v2.rows = v1.rows;
+        v2.columns = v1.columns;
+        v2.displayCount = v1.displayCount;
+        v2.flags = v1.flags;
+        for (display, d1) in v2.displays.iter_mut().zip(v1.displays.iter()) {
+            display.version = NV_MOSAIC_GRID_TOPO_DISPLAY_VER;
+            display.displayId = d1.displayId;
+            display.overlapX = d1.overlapX;
+            display.overlapY = d1.overlapY;
+            display.rotation = d1.rotation;
+            display.cloneGroup = d1.cloneGroup;
+        }
+        v2.displaySettings = v1.displaySettings;
+        v2
+    }
+}
+
+pub type NV_MOSAIC_GRID_TOPO = NV_MOSAIC_GRID_TOPO_V2;
+
+nvversion! { NV_MOSAIC_GRID_TOPO_VER1(NV_MOSAIC_GRID_TOPO_V1 = 4 * 5 + 4 * 5 * NV_MOSAIC_MAX_DISPLAYS + 4 * 5, 1) }
+nvversion! { NV_MOSAIC_GRID_TOPO_VER2(NV_MOSAIC_GRID_TOPO_V2 = 4 * 5 + 4 * 7 * NV_MOSAIC_MAX_DISPLAYS + 4 * 5, 2) }
+nvversion! { NV_MOSAIC_GRID_TOPO_VER = NV_MOSAIC_GRID_TOPO_VER2 }
+
+/// Do not change the current GPU topology. If the NO_DRIVER_RELOAD bit is not
+/// specified, then it may still require a driver reload.
+pub const NV_MOSAIC_SETDISPLAYTOPO_FLAG_CURRENT_GPU_TOPOLOGY: u32 = 1 << 0;
+/// Do not allow a driver reload. That is, stick with the same master GPU as well as the
+/// same SLI configuration.
+pub const NV_MOSAIC_SETDISPLAYTOPO_FLAG_NO_DRIVER_RELOAD: u32 = 1 << 1;
+/// When choosing a GPU topology, choose the topology with the best performance.
+/// Without this flag, it will choose the topology that uses the smallest number
+/// of GPU's.
+pub const NV_MOSAIC_SETDISPLAYTOPO_FLAG_MAXIMIZE_PERFORMANCE: u32 = 1 << 2;
+/// Do not return an error if no configuration will work with all of the grids.
+pub const NV_MOSAIC_SETDISPLAYTOPO_FLAG_ALLOW_INVALID: u32 = 1 << 3;
+
+nvstruct! {
+    pub struct NV_MOSAIC_DISPLAY_TOPO_STATUS_DISPLAY {
+        /// The DisplayID of this display.
+        pub displayId: u32,
+        /// Any of the NV_MOSAIC_DISPLAYCAPS_PROBLEM_* flags.
+        pub errorFlags: u32,
+        /// Any of the NV_MOSAIC_DISPLAYTOPO_WARNING_* flags.
+        pub warningFlags: u32,
+        /// This display can be rotated (bit 0)
+        pub supportsRotation: u32,
+    }
+}
+
+nvstruct! {
+    pub struct NV_MOSAIC_DISPLAY_TOPO_STATUS {
+        /// Version of this structure
+        pub version: u32,
+        /// Any of the NV_MOSAIC_DISPLAYTOPO_ERROR_* flags.
+        pub errorFlags: u32,
+        /// Any of the NV_MOSAIC_DISPLAYTOPO_WARNING_* flags.
+        pub warningFlags: u32,
+        /// The number of valid entries in the displays array.
+        pub displayCount: u32,
+        pub displays: [NV_MOSAIC_DISPLAY_TOPO_STATUS_DISPLAY; NVAPI_MAX_DISPLAYS],
+    }
+}
+
+nvversion! { NV_MOSAIC_DISPLAY_TOPO_STATUS_VER(NV_MOSAIC_DISPLAY_TOPO_STATUS = 4 * 4 + 4 * 4 * NVAPI_MAX_DISPLAYS, 1) }
+
+nvapi_fn! {
+    pub type Mosaic_GetSupportedTopoInfoFn = extern "C" fn(pSupportedTopoInfo: *mut NV_MOSAIC_SUPPORTED_TOPO_INFO, type_: NV_MOSAIC_TOPO_TYPE) -> NvAPI_Status;
+
+    /// This API returns information on the topologies and display resolutions
+    /// supported by Mosaic mode.
+    ///
+    /// NOTE: Not all topologies returned can be set immediately.
+    /// See 'OUT' Notes below.
+    pub unsafe fn NvAPI_Mosaic_GetSupportedTopoInfo;
+}
+
+nvapi_fn! {
+    pub type Mosaic_GetTopoGroupFn = extern "C" fn(pTopoBrief: *mut NV_MOSAIC_TOPO_BRIEF, pTopoGroup: *mut NV_MOSAIC_TOPO_GROUP) -> NvAPI_Status;
+
+    /// This API returns a structure filled with the details
+    /// of the specified Mosaic topology.
+    pub unsafe fn NvAPI_Mosaic_GetTopoGroup;
+}
+
+nvapi_fn! {
+    pub type Mosaic_GetOverlapLimitsFn = extern "C" fn(pTopoBrief: *mut NV_MOSAIC_TOPO_BRIEF, pDisplaySetting: *mut NV_MOSAIC_DISPLAY_SETTING, pMinOverlapX: *mut i32, pMaxOverlapX: *mut i32, pMinOverlapY: *mut i32, pMaxOverlapY: *mut i32) -> NvAPI_Status;
+
+    /// This API returns the X and Y overlap limits required if
+    /// the given Mosaic topology and display settings are to be used.
+    pub unsafe fn NvAPI_Mosaic_GetOverlapLimits;
+}
+
+nvapi_fn! {
+    pub type Mosaic_SetCurrentTopoFn = extern "C" fn(pTopoBrief: *mut NV_MOSAIC_TOPO_BRIEF, pDisplaySetting: *mut NV_MOSAIC_DISPLAY_SETTING, overlapX: i32, overlapY: i32, enable: u32) -> NvAPI_Status;
+
+    /// This API sets the Mosaic topology and performs a mode switch
+    /// using the given display settings.
+    ///
+    /// If NVAPI_OK is returned, the current Mosaic topology was set
+    /// correctly.  Any other status returned means the
+    /// topology was not set, and remains what it was before this
+    /// function was called.
+    pub unsafe fn NvAPI_Mosaic_SetCurrentTopo;
+}
+
+nvapi_fn! {
+    pub type Mosaic_GetCurrentTopoFn = extern "C" fn(pTopoBrief: *mut NV_MOSAIC_TOPO_BRIEF, pDisplaySetting: *mut NV_MOSAIC_DISPLAY_SETTING, pOverlapX: *mut i32, pOverlapY: *mut i32) -> NvAPI_Status;
+
+    /// This API returns information for the current Mosaic topology.
+    /// This includes topology, display settings, and overlap values.
+    ///
+    /// You can call NvAPI_Mosaic_GetTopoGroup() with the topology
+    /// if you require more information.
+    ///
+    /// If there isn't a current topology, then pTopoBrief->topo will
+    /// be NV_MOSAIC_TOPO_NONE.
+    pub unsafe fn NvAPI_Mosaic_GetCurrentTopo;
+}
+
+nvapi_fn! {
+    pub type Mosaic_EnableCurrentTopoFn = extern "C" fn(enable: u32) -> NvAPI_Status;
+
+    /// This API enables or disables the current Mosaic topology
+    /// based on the setting of the incoming 'enable' parameter.
+    ///
+    /// An "enable" setting enables the current (previously set) Mosaic topology.
+    /// Note that when the current Mosaic topology is retrieved, it must have an isPossible value of 1 or
+    /// an error will occur.
+    ///
+    /// A "disable" setting disables the current Mosaic topology.
+    /// The topology information will persist, even across reboots.
+    /// To re-enable the Mosaic topology, call this function
+    /// again with the enable parameter set to 1.
+    pub unsafe fn NvAPI_Mosaic_EnableCurrentTopo;
+}
+
+nvapi_fn! {
+    pub type Mosaic_SetDisplayGridsFn = extern "C" fn(pGridTopologies: *mut NV_MOSAIC_GRID_TOPO, gridCount: u32, setTopoFlags: u32) -> NvAPI_Status;
+
+    /// Sets a new display topology, replacing any existing topologies
+    /// that use the same displays.
+    ///
+    /// This function will look for an SLI configuration that will
+    /// allow the display topology to work.
+    ///
+    /// To revert to a single display, specify that display as a 1x1
+    /// grid.
+    pub unsafe fn NvAPI_Mosaic_SetDisplayGrids;
+}
+
+nvapi_fn! {
+    pub type Mosaic_ValidateDisplayGridsFn = extern "C" fn(setTopoFlags: u32, pGridTopologies: *mut NV_MOSAIC_GRID_TOPO, pTopoStatus: *mut NV_MOSAIC_DISPLAY_TOPO_STATUS, gridCount: u32) -> NvAPI_Status;
+
+    /// Determines if a list of grid topologies is valid. It will choose an SLI
+    /// configuration in the same way that NvAPI_Mosaic_SetDisplayGrids() does.
+    ///
+    /// On return, each element in the pTopoStatus array will contain any errors or
+    /// warnings about each grid topology. If any error flags are set, then the topology
+    /// is not valid. If any warning flags are set, then the topology is valid, but
+    /// sub-optimal.
+    pub unsafe fn NvAPI_Mosaic_ValidateDisplayGrids;
+}
+
+nvapi_fn! {
+    pub type Mosaic_EnumDisplayModesFn = extern "C" fn(pGridTopology: *mut NV_MOSAIC_GRID_TOPO, pDisplaySettings: *mut NV_MOSAIC_DISPLAY_SETTING, pDisplayCount: *mut u32) -> NvAPI_Status;
+
+    /// Determines the set of available display modes for a given grid topology.
+    ///
+    /// If pDisplaySettings is NULL, then pDisplayCount will receive the total number of modes
+    /// that are available.
+    pub unsafe fn NvAPI_Mosaic_EnumDisplayModes;
+}
+
+nvapi_fn! {
+    pub type Mosaic_EnumDisplayGridsFn = extern "C" fn(pGridTopologies: *mut NV_MOSAIC_GRID_TOPO, pGridCount: *mut u32) -> NvAPI_Status;
+
+    /// Enumerates the current active grid topologies. This includes Mosaic, IG, and
+    /// Panoramic topologies, as well as single displays.
+    ///
+    /// If pGridTopologies is NULL, then pGridCount will be set to the number of active
+    /// grid topologies.
+    pub unsafe fn NvAPI_Mosaic_EnumDisplayGrids;
+}
+
+nvapi_fn! {
+    pub type Mosaic_GetDisplayViewportsByResolutionFn = extern "C" fn(displayId: u32, srcWidth: u32, srcHeight: u32, viewports: *mut [NV_RECT; NV_MOSAIC_MAX_DISPLAYS], bezelCorrected: *mut u8) -> NvAPI_Status;
+
+    /// This API returns the viewports that would be applied on
+    /// the requested display.
+    ///
+    /// srcWidth and srcHeight are the source resolution; pass 0 for both to
+    /// use the current resolution of the display.
+    pub unsafe fn NvAPI_Mosaic_GetDisplayViewportsByResolution;
+}
@@ -57,8 +57,8 @@ pub fn nvapi_QueryInterface(id: u32) -> crate::Result<usize> {
     }
 }
 
-#[cfg(macos)]
-pub fn nvapi_QueryInterface(id: u32) -> crate::Result<usize> {
+#[cfg(not(any(windows, target_os = "linux")))]
+pub fn nvapi_QueryInterface(_id: u32) -> crate::Result<usize> {
     // TODO: Apparently nvapi is available for macOS?
     Err(Status::LibraryNotFound)
 }
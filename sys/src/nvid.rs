@@ -72,6 +72,8 @@ NvAPI_SetInfoFrameState = 0x67efd887,
 NvAPI_GetInfoFrameState = 0x41511594,
 NvAPI_Disp_InfoFrameControl = 0x6067af3f,
 NvAPI_Disp_ColorControl = 0x92f9d80d,
+NvAPI_Disp_HdrColorControl = 0x351da224,
+NvAPI_Disp_GetHdrCapabilities = 0x84f2a8df,
 NvAPI_DISP_GetVirtualModeData = 0x3230d69a,
 NvAPI_DISP_OverrideDisplayModeList = 0x0291bff2,
 NvAPI_GetDisplayDriverMemoryInfo = 0x774aa982,
@@ -98,6 +100,7 @@ NvAPI_D3D_Query_GetData = 0xf8b53c69,
 NvAPI_D3D_Query_GetDataSize = 0xf2a54796,
 NvAPI_D3D_Query_GetType = 0x4aceeaf7,
 NvAPI_D3D_RegisterApp = 0xd44d3c4e,
+NvAPI_D3D_RegisterDevice = 0x8c02c4d0,
 NvAPI_D3D9_CreatePathContextNV = 0xa342f682,
 NvAPI_D3D9_DestroyPathContextNV = 0x667c2929,
 NvAPI_D3D9_CreatePathNV = 0x71329df3,
@@ -220,6 +223,7 @@ NvAPI_GPU_GetPhysicalFrameBufferSize = 0x46fbeb03,
 NvAPI_GPU_GetVirtualFrameBufferSize = 0x5a04b644,
 NvAPI_GPU_GetQuadroStatus = 0xe332fa47,
 NvAPI_GPU_GetBoardInfo = 0x22d54523,
+NvAPI_GPU_GetAdapterIdFromPhysicalGpu = 0x0ff07fde,
 NvAPI_GPU_GetRamType = 0x57f7caac,
 NvAPI_GPU_GetFBWidthAndLocation = 0x11104158,
 NvAPI_GPU_GetAllClockFrequencies = 0xdcb616c3,
@@ -274,6 +278,7 @@ NvAPI_GPU_ClientPowerPoliciesGetInfo = 0x34206d86,
 NvAPI_GPU_ClientPowerPoliciesGetStatus = 0x70916171,
 NvAPI_GPU_ClientPowerPoliciesSetStatus = 0xad95f5ed,
 NvAPI_GPU_WorkstationFeatureSetup = 0x6c1f3fe4,
+NvAPI_GPU_WorkstationFeatureQuery = 0x004537df,
 NvAPI_SYS_GetChipSetInfo = 0x53dabbca,
 NvAPI_SYS_GetLidAndDockInfo = 0xcda14d8a,
 NvAPI_OGL_ExpertModeSet = 0x3805ef7a,
@@ -443,6 +448,7 @@ NvAPI_GPS_SetThermalLimit = 0xc07e210f,
 NvAPI_GPS_GetPerfSensors = 0x271c1109,
 NvAPI_SYS_GetDisplayIdFromGpuAndOutputId = 0x08f2bab4,
 NvAPI_SYS_GetGpuAndOutputIdFromDisplayId = 0x112ba1a5,
+NvAPI_SYS_GetPhysicalGpuFromDisplayId = 0x9ea74659,
 NvAPI_DISP_GetDisplayIdByDisplayName = 0xae457190,
 NvAPI_DISP_GetGDIPrimaryDisplayId = 0x1e9d8a31,
 NvAPI_DISP_GetDisplayConfig = 0x11abccf8,
@@ -0,0 +1,56 @@
+//! Field adapters used by the serde derives of `nvstruct!` and `nv_declare_handle!`.
+
+/// Arrays of any length, as serde only implements arrays of up to 32 elements.
+pub mod array {
+    use serde::{Serialize, Serializer, Deserialize, Deserializer};
+    use serde::de::Error;
+
+    pub fn serialize<S: Serializer, T: Serialize, const N: usize>(array: &[T; N], serializer: S) -> Result<S::Ok, S::Error> {
+        array[..].serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>, T: Deserialize<'de>, const N: usize>(deserializer: D) -> Result<[T; N], D::Error> {
+        let values = Vec::<T>::deserialize(deserializer)?;
+        let len = values.len();
+        values.try_into()
+            .map_err(|_| D::Error::invalid_length(len, &format!("an array of length {}", N).as_str()))
+    }
+}
+
+/// Raw pointers and handles, as their address.
+pub mod pointer {
+    use serde::{Serialize, Serializer, Deserialize, Deserializer};
+
+    pub trait Pointer {
+        fn address(&self) -> usize;
+        fn from_address(address: usize) -> Self;
+    }
+
+    impl<T> Pointer for *const T {
+        fn address(&self) -> usize {
+            *self as usize
+        }
+
+        fn from_address(address: usize) -> Self {
+            address as Self
+        }
+    }
+
+    impl<T> Pointer for *mut T {
+        fn address(&self) -> usize {
+            *self as usize
+        }
+
+        fn from_address(address: usize) -> Self {
+            address as Self
+        }
+    }
+
+    pub fn serialize<S: Serializer, P: Pointer>(pointer: &P, serializer: S) -> Result<S::Ok, S::Error> {
+        pointer.address().serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>, P: Pointer>(deserializer: D) -> Result<P, D::Error> {
+        usize::deserialize(deserializer).map(P::from_address)
+    }
+}
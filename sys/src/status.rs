@@ -1,7 +1,94 @@
 use std::{fmt, error};
 use std::convert::Infallible;
 
-nvenum! {
+/// Like `nvenum!`, but with an `Unknown` variant that keeps codes missing from the headers.
+macro_rules! nvstatus {
+    (
+        $(#[$meta:meta])*
+        pub enum $enum:ident / $enum_name:ident {
+            $(
+                $(#[$metai:meta])*
+                $symbol:ident / $name:ident = $value:expr,
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        pub type $enum = ::std::os::raw::c_int;
+        $(
+            $(#[$metai])*
+            pub const $symbol: $enum = $value as _;
+        )*
+
+        $(#[$meta])*
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[derive(Debug, Copy, Clone, PartialOrd, Ord, PartialEq, Eq, Hash)]
+        pub enum $enum_name {
+            $(
+                $(#[$metai])*
+                $name,
+            )*
+            /// A code this crate does not know about, as returned by the driver
+            Unknown($enum),
+        }
+
+        impl $enum_name {
+            /// Fails for codes that would be `Unknown`, see `from_code` to keep them.
+            pub fn from_raw(raw: $enum) -> ::std::result::Result<Self, crate::ArgumentRangeError> {
+                match raw {
+                    $(
+                        $symbol => Ok($enum_name::$name),
+                    )*
+                    _ => Err(Default::default()),
+                }
+            }
+
+            /// Converts any raw code, keeping unrecognized ones as `Unknown`.
+            pub fn from_code(raw: $enum) -> Self {
+                Self::from_raw(raw).unwrap_or($enum_name::Unknown(raw))
+            }
+
+            pub fn raw(&self) -> $enum {
+                match *self {
+                    $(
+                        $enum_name::$name => $symbol,
+                    )*
+                    $enum_name::Unknown(raw) => raw,
+                }
+            }
+
+            /// The known status codes.
+            pub fn values() -> impl Iterator<Item=Self> {
+                [
+                    $(
+                        $enum_name::$name
+                    ),*
+                ].into_iter()
+            }
+        }
+
+        impl ::std::convert::TryFrom<$enum> for $enum_name {
+            type Error = crate::ArgumentRangeError;
+
+            fn try_from(raw: $enum) -> ::std::result::Result<Self, crate::ArgumentRangeError> {
+                Self::from_raw(raw)
+            }
+        }
+
+        impl From<$enum_name> for $enum {
+            fn from(v: $enum_name) -> Self {
+                v.raw()
+            }
+        }
+
+        impl Default for $enum_name {
+            fn default() -> Self {
+                $enum_name::Ok
+            }
+        }
+    };
+}
+
+nvstatus! {
     /// NvAPI Status Values
     ///
     /// All NvAPI functions return one of these codes.
@@ -279,15 +366,182 @@ nvenum! {
     }
 }
 
+impl Status {
+    /// A short description of the status code, taken from the nvapi headers.
+    pub fn message(&self) -> &'static str {
+        match *self {
+            Status::Unknown(..) => "Unknown NVAPI status",
+            Status::Ok => "Success. Request is completed.",
+            Status::Error => "Generic error",
+            Status::LibraryNotFound => "NVAPI support library cannot be loaded.",
+            Status::NoImplementation => "not implemented in current driver installation",
+            Status::ApiNotInitialized => "NvAPI_Initialize has not been called (successfully)",
+            Status::InvalidArgument => "The argument/parameter value is not valid or NULL.",
+            Status::NvidiaDeviceNotFound => "No NVIDIA display driver, or NVIDIA GPU driving a display, was found.",
+            Status::EndEnumeration => "No more items to enumerate",
+            Status::InvalidHandle => "Invalid handle",
+            Status::IncompatibleStructVersion => "An argument's structure version is not supported",
+            Status::HandleInvalidated => "The handle is no longer valid (likely due to GPU or display re-configuration)",
+            Status::OpenglContextNotCurrent => "No NVIDIA OpenGL context is current (but needs to be)",
+            Status::InvalidPointer => "An invalid pointer, usually NULL, was passed as a parameter",
+            Status::NoGlExpert => "OpenGL Expert is not supported by the current drivers",
+            Status::InstrumentationDisabled => "OpenGL Expert is supported, but driver instrumentation is currently disabled",
+            Status::NoGlNsight => "OpenGL does not support Nsight",
+            Status::ExpectedLogicalGpuHandle => "Expected a logical GPU handle for one or more parameters",
+            Status::ExpectedPhysicalGpuHandle => "Expected a physical GPU handle for one or more parameters",
+            Status::ExpectedDisplayHandle => "Expected an NV display handle for one or more parameters",
+            Status::InvalidCombination => "The combination of parameters is not valid.",
+            Status::NotSupported => "Requested feature is not supported in the selected GPU",
+            Status::PortidNotFound => "No port ID was found for the I2C transaction",
+            Status::ExpectedUnattachedDisplayHandle => "Expected an unattached display handle as one of the input parameters.",
+            Status::InvalidPerfLevel => "Invalid perf level",
+            Status::DeviceBusy => "Device is busy; request not fulfilled",
+            Status::NvPersistFileNotFound => "NV persist file is not found",
+            Status::PersistDataNotFound => "NV persist data is not found",
+            Status::ExpectedTvDisplay => "Expected a TV output display",
+            Status::ExpectedTvDisplayOnDconnector => "Expected a TV output on the D Connector - HDTV_EIAJ4120.",
+            Status::NoActiveSliTopology => "SLI is not active on this device.",
+            Status::SliRenderingModeNotallowed => "Setup of SLI rendering mode is not possible right now.",
+            Status::ExpectedDigitalFlatPanel => "Expected a digital flat panel.",
+            Status::ArgumentExceedMaxSize => "Argument exceeds the expected size.",
+            Status::DeviceSwitchingNotAllowed => "Inhibit is ON due to one of the flags in NV_GPU_DISPLAY_CHANGE_INHIBIT or SLI active.",
+            Status::TestingClocksNotSupported => "Testing of clocks is not supported.",
+            Status::UnknownUnderscanConfig => "The specified underscan config is from an unknown source (e.g. INF)",
+            Status::TimeoutReconfiguringGpuTopo => "Timeout while reconfiguring GPUs",
+            Status::DataNotFound => "Requested data was not found",
+            Status::ExpectedAnalogDisplay => "Expected an analog display",
+            Status::NoVidlink => "No SLI video bridge is present",
+            Status::RequiresReboot => "NVAPI requires a reboot for the settings to take effect",
+            Status::InvalidHybridMode => "The function is not supported with the current Hybrid mode.",
+            Status::MixedTargetTypes => "The target types are not all the same",
+            Status::Syswow64NotSupported => "The function is not supported from 32-bit on a 64-bit system.",
+            Status::ImplicitSetGpuTopologyChangeNotAllowed => "There is no implicit GPU topology active. Use NVAPI_SetHybridMode to change topology.",
+            Status::RequestUserToCloseNonMigratableApps => "Prompt the user to close all non-migratable applications.",
+            Status::OutOfMemory => "Could not allocate sufficient memory to complete the call.",
+            Status::WasStillDrawing => "The previous operation that is transferring information to or from this surface is incomplete.",
+            Status::FileNotFound => "The file was not found.",
+            Status::TooManyUniqueStateObjects => "There are too many unique instances of a particular type of state object.",
+            Status::InvalidCall => "The method call is invalid. For example, a method's parameter may not be a valid pointer.",
+            Status::D3d101LibraryNotFound => "d3d10_1.dll cannot be loaded.",
+            Status::FunctionNotFound => "Couldn't find the function in the loaded DLL.",
+            Status::InvalidUserPrivilege => "Current User is not Admin.",
+            Status::ExpectedNonPrimaryDisplayHandle => "The handle corresponds to GDIPrimary.",
+            Status::ExpectedComputeGpuHandle => "Setting Physx GPU requires that the GPU is compute-capable.",
+            Status::StereoNotInitialized => "The Stereo part of NVAPI failed to initialize completely. Check if the stereo driver is installed.",
+            Status::StereoRegistryAccessFailed => "Access to stereo-related registry keys or values has failed.",
+            Status::StereoRegistryProfileTypeNotSupported => "The given registry profile type is not supported.",
+            Status::StereoRegistryValueNotSupported => "The given registry value is not supported.",
+            Status::StereoNotEnabled => "Stereo is not enabled and the function needed it to execute completely.",
+            Status::StereoNotTurnedOn => "Stereo is not turned on and the function needed it to execute completely.",
+            Status::StereoInvalidDeviceInterface => "Invalid device interface.",
+            Status::StereoParameterOutOfRange => "Separation percentage or JPEG image capture quality is out of [0-100] range.",
+            Status::StereoFrustumAdjustModeNotSupported => "The given frustum adjust mode is not supported.",
+            Status::TopoNotPossible => "The mosaic topology is not possible given the current state of the hardware.",
+            Status::ModeChangeFailed => "An attempt to do a display resolution mode change has failed.",
+            Status::D3d11LibraryNotFound => "d3d11.dll/d3d11_beta.dll cannot be loaded.",
+            Status::InvalidAddress => "Address is outside of valid range.",
+            Status::StringTooSmall => "The pre-allocated string is too small to hold the result.",
+            Status::MatchingDeviceNotFound => "The input does not match any of the available devices.",
+            Status::DriverRunning => "Driver is running.",
+            Status::DriverNotrunning => "Driver is not running.",
+            Status::ErrorDriverReloadRequired => "A driver reload is required to apply these settings.",
+            Status::SetNotAllowed => "Intended setting is not allowed.",
+            Status::AdvancedDisplayTopologyRequired => "Information can't be returned due to \"advanced display topology\".",
+            Status::SettingNotFound => "Setting is not found.",
+            Status::SettingSizeTooLarge => "Setting size is too large.",
+            Status::TooManySettingsInProfile => "There are too many settings for a profile.",
+            Status::ProfileNotFound => "Profile is not found.",
+            Status::ProfileNameInUse => "Profile name is duplicated.",
+            Status::ProfileNameEmpty => "Profile name is empty.",
+            Status::ExecutableNotFound => "Application not found in the Profile.",
+            Status::ExecutableAlreadyInUse => "Application already exists in the other profile.",
+            Status::DatatypeMismatch => "Data Type mismatch",
+            Status::ProfileRemoved => "The profile passed as parameter has been removed and is no longer valid.",
+            Status::UnregisteredResource => "An unregistered resource was passed as a parameter.",
+            Status::IdOutOfRange => "The DisplayId corresponds to a display which is not within the normal outputId range.",
+            Status::DisplayconfigValidationFailed => "Display topology is not valid so the driver cannot do a mode set on this configuration.",
+            Status::DpmstChanged => "Display Port Multi-Stream topology has been changed.",
+            Status::InsufficientBuffer => "Input buffer is insufficient to hold the contents.",
+            Status::AccessDenied => "No access to the caller.",
+            Status::MosaicNotActive => "The requested action cannot be performed without Mosaic being enabled.",
+            Status::ShareResourceRelocated => "The surface is relocated away from video memory.",
+            Status::RequestUserToDisableDwm => "The user should disable DWM before calling NvAPI.",
+            Status::D3dDeviceLost => "D3D device status is D3DERR_DEVICELOST or D3DERR_DEVICENOTRESET - the user has to reset the device.",
+            Status::InvalidConfiguration => "The requested action cannot be performed in the current state.",
+            Status::StereoHandshakeNotDone => "Call failed as stereo handshake not completed.",
+            Status::ExecutablePathIsAmbiguous => "The path provided was too short to determine the correct NVDRS_APPLICATION",
+            Status::DefaultStereoProfileIsNotDefined => "Default stereo profile is not currently defined",
+            Status::DefaultStereoProfileDoesNotExist => "Default stereo profile does not exist",
+            Status::ClusterAlreadyExists => "A cluster is already defined with the given configuration.",
+            Status::DpmstDisplayIdExpected => "The input display id is not that of a multi stream enabled connector or a display device in a multi stream topology",
+            Status::InvalidDisplayId => "The input display id is not valid or the monitor associated to it does not support the current operation",
+            Status::StreamIsOutOfSync => "While playing secure audio stream, stream goes out of sync",
+            Status::IncompatibleAudioDriver => "Older audio driver version than required",
+            Status::ValueAlreadySet => "Value already set, setting again not allowed.",
+            Status::Timeout => "Requested operation timed out",
+            Status::GpuWorkstationFeatureIncomplete => "The requested workstation feature set has incomplete driver internal allocation resources",
+            Status::StereoInitActivationNotDone => "Call failed because InitActivation was not called.",
+            Status::SyncNotActive => "The requested action cannot be performed without Sync being enabled.",
+            Status::SyncMasterNotFound => "The requested action cannot be performed without Sync Master being enabled.",
+            Status::InvalidSyncTopology => "Invalid displays passed in the NV_GSYNC_DISPLAY pointer.",
+            Status::EcidSignAlgoUnsupported => "The specified signing algorithm is not supported. Either an incorrect value was entered or the current installed driver/hardware does not support the input value.",
+            Status::EcidKeyVerificationFailed => "The encrypted public key verification has failed.",
+            Status::FirmwareOutOfDate => "The device's firmware is out of date.",
+            Status::FirmwareRevisionNotSupported => "The device's firmware is not supported.",
+            Status::LicenseCallerAuthenticationFailed => "The caller is not authorized to modify the License.",
+            Status::D3dDeviceNotRegistered => "The user tried to use a deferred context without registering the device first",
+            Status::ResourceNotAcquired => "Head or SourceId was not reserved for the VR Display before doing the Modeset.",
+            Status::TimingNotSupported => "Provided timing is not supported.",
+            Status::HdcpEncryptionFailed => "HDCP Encryption Failed for the device. Would be applicable when the device is HDCP Capable.",
+            Status::PclkLimitationFailed => "Provided mode is over sink device pclk limitation.",
+            Status::NoConnectorFound => "No connector on GPU found.",
+            Status::HdcpDisabled => "When a non-HDCP capable HMD is connected, we would inform user by this code.",
+            Status::ApiInUse => "Atleast an API is still being called",
+            Status::NvidiaDisplayNotFound => "No display found on Nvidia GPU(s).",
+            Status::PrivSecViolation => "Priv security violation, improper access to a secured register.",
+            Status::IncorrectVendor => "NVAPI cannot be called by this vendor",
+            Status::DisplayInUse => "DirectMode Display is already in use",
+            Status::UnsupportedConfigNonHdcpHmd => "The Config is having Non-NVidia GPU with Non-HDCP HMD connected",
+            Status::MaxDisplayLimitReached => "GPU's Max Display Limit has Reached",
+            Status::InvalidDirectModeDisplay => "DirectMode not Enabled on the Display",
+            Status::GpuInDebugMode => "GPU is in debug mode, OC is NOT allowed.",
+        }
+    }
+}
+
+/// Asks the driver to describe a raw status code, including codes `Status` does not know about.
+///
+/// Returns `None` if NVAPI is unavailable or does not recognize the code either.
+pub fn driver_error_message(status: NvAPI_Status) -> Option<String> {
+    let mut desc = crate::types::short_string();
+    match unsafe { crate::nvapi::NvAPI_GetErrorMessage(status, &mut desc) } {
+        NVAPI_OK => Some(crate::types::short_string_to_string(&desc)).filter(|desc| !desc.is_empty()),
+        _ => None,
+    }
+}
+
+/// Describes a raw status code, preferring the message of a known `Status` and falling back to
+/// the driver's own description of unrecognized codes.
+pub fn status_message(status: NvAPI_Status) -> String {
+    match Status::from_raw(status) {
+        Ok(known) => known.message().into(),
+        Err(..) => driver_error_message(status)
+            .unwrap_or_else(|| format!("Unknown NVAPI status {}", status)),
+    }
+}
+
 impl error::Error for Status {
     fn description(&self) -> &str {
-        "NVAPI Error"
+        self.message()
     }
 }
 
 impl fmt::Display for Status {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        fmt::Debug::fmt(self, f)
+        match *self {
+            Status::Unknown(raw) => write!(f, "{} (Unknown({}))", status_message(raw), raw),
+            _ => write!(f, "{} ({:?})", self.message(), self),
+        }
     }
 }
 
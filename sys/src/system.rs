@@ -0,0 +1,95 @@
+use crate::status::NvAPI_Status;
+use crate::handles::NvPhysicalGpuHandle;
+use std::os::raw::c_char;
+use crate::types::NVAPI_SHORT_STRING_MAX;
+
+nvbits! {
+    pub enum NV_CHIPSET_INFO_FLAGS / ChipsetInfoFlags {
+        NV_CHIPSET_INFO_HYBRID / HYBRID = 0x00000001,
+    }
+}
+
+nvstruct! {
+    pub struct NV_CHIPSET_INFO_v1 {
+        /// structure version
+        pub version: u32,
+        /// vendor ID
+        pub vendorId: u32,
+        /// device ID
+        pub deviceId: u32,
+        /// vendor Name
+        pub szVendorName: [c_char; NVAPI_SHORT_STRING_MAX],
+        /// device Name
+        pub szChipsetName: [c_char; NVAPI_SHORT_STRING_MAX],
+    }
+}
+
+nvstruct! {
+    pub struct NV_CHIPSET_INFO_v2 {
+        pub v1: NV_CHIPSET_INFO_v1,
+        /// Chipset info flags - obsolete
+        pub flags: NV_CHIPSET_INFO_FLAGS,
+    }
+}
+nvinherit! { NV_CHIPSET_INFO_v2(v1: NV_CHIPSET_INFO_v1) }
+
+nvstruct! {
+    pub struct NV_CHIPSET_INFO_v3 {
+        pub v2: NV_CHIPSET_INFO_v2,
+        /// subsystem vendor ID
+        pub subSysVendorId: u32,
+        /// subsystem device ID
+        pub subSysDeviceId: u32,
+        /// subsystem vendor Name
+        pub szSubSysVendorName: [c_char; NVAPI_SHORT_STRING_MAX],
+    }
+}
+nvinherit! { NV_CHIPSET_INFO_v3(v2: NV_CHIPSET_INFO_v2) }
+
+nvstruct! {
+    pub struct NV_CHIPSET_INFO_v4 {
+        pub v3: NV_CHIPSET_INFO_v3,
+        /// Host bridge vendor identification
+        pub HBvendorId: u32,
+        /// Host bridge device identification
+        pub HBdeviceId: u32,
+        /// Host bridge subsystem vendor identification
+        pub HBsubSysVendorId: u32,
+        /// Host bridge subsystem device identification
+        pub HBsubSysDeviceId: u32,
+        /// Host bridge vendor name
+        pub szHBVendorName: [c_char; NVAPI_SHORT_STRING_MAX],
+    }
+}
+nvinherit! { NV_CHIPSET_INFO_v4(v3: NV_CHIPSET_INFO_v3) }
+
+pub type NV_CHIPSET_INFO = NV_CHIPSET_INFO_v4;
+
+nvversion! { NV_CHIPSET_INFO_VER_1(NV_CHIPSET_INFO_v1 = 4 * 3 + 64 * 2, 1) }
+nvversion! { NV_CHIPSET_INFO_VER_2(NV_CHIPSET_INFO_v2 = 4 * 4 + 64 * 2, 2) }
+nvversion! { NV_CHIPSET_INFO_VER_3(NV_CHIPSET_INFO_v3 = 4 * 6 + 64 * 3, 3) }
+nvversion! { NV_CHIPSET_INFO_VER_4(NV_CHIPSET_INFO_v4 = 4 * 10 + 64 * 4, 4) }
+nvversion! { NV_CHIPSET_INFO_VER = NV_CHIPSET_INFO_VER_4 }
+
+nvapi_fn! {
+    pub type SYS_GetChipSetInfoFn = extern "C" fn(pChipSetInfo: *mut NV_CHIPSET_INFO) -> NvAPI_Status;
+
+    /// This function returns information about the system's chipset.
+    pub unsafe fn NvAPI_SYS_GetChipSetInfo;
+}
+
+nvapi_fn! {
+    pub type SYS_GetPhysicalGpuFromDisplayIdFn = extern "C" fn(displayId: u32, hPhysicalGpu: *mut NvPhysicalGpuHandle) -> NvAPI_Status;
+
+    /// This API converts a display ID to a physical GPU handle.
+    pub unsafe fn NvAPI_SYS_GetPhysicalGpuFromDisplayId;
+}
+
+nvapi_fn! {
+    pub type SYS_GetGpuAndOutputIdFromDisplayIdFn = extern "C" fn(displayId: u32, hPhysicalGpu: *mut NvPhysicalGpuHandle, outputId: *mut u32) -> NvAPI_Status;
+
+    /// This API converts a display ID to a physical GPU handle and output ID.
+    ///
+    /// The output ID is a single-bit mask identifying the physical output the display is driven by.
+    pub unsafe fn NvAPI_SYS_GetGpuAndOutputIdFromDisplayId;
+}
@@ -1,5 +1,6 @@
 use std::os::raw::c_char;
 use std::mem::size_of;
+use std::fmt;
 
 pub type NvBool = u8;
 
@@ -8,6 +9,7 @@ pub const NV_FALSE: NvBool = 0;
 
 /// A boolean containing reserved bits
 #[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BoolU32(pub u32);
 
 impl BoolU32 {
@@ -29,6 +31,42 @@ nvstruct! {
     }
 }
 
+impl NV_RECT {
+    pub fn width(&self) -> u32 {
+        self.right.saturating_sub(self.left)
+    }
+
+    pub fn height(&self) -> u32 {
+        self.bottom.saturating_sub(self.top)
+    }
+
+    /// Whether the rectangle covers no area, as unused viewport entries do.
+    pub fn is_empty(&self) -> bool {
+        self.width() == 0 || self.height() == 0
+    }
+}
+
+/// Formatted as `WIDTHxHEIGHT+LEFT+TOP`, e.g. `1920x1080+1920+0`.
+impl fmt::Display for NV_RECT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}x{}+{}+{}", self.width(), self.height(), self.left, self.top)
+    }
+}
+
+/// `(left, top, right, bottom)`
+impl From<(u32, u32, u32, u32)> for NV_RECT {
+    fn from((left, top, right, bottom): (u32, u32, u32, u32)) -> Self {
+        NV_RECT { left, top, right, bottom }
+    }
+}
+
+/// `(left, top, right, bottom)`
+impl From<NV_RECT> for (u32, u32, u32, u32) {
+    fn from(rect: NV_RECT) -> Self {
+        (rect.left, rect.top, rect.right, rect.bottom)
+    }
+}
+
 pub const NVAPI_GENERIC_STRING_MAX: usize = 4096;
 pub const NVAPI_LONG_STRING_MAX: usize = 256;
 pub const NVAPI_SHORT_STRING_MAX: usize = 64;
@@ -100,6 +138,15 @@ pub fn string() -> NvAPI_String {
     [0; NVAPI_GENERIC_STRING_MAX]
 }
 
+/// Converts a string buffer filled in by NVAPI, up to the first NUL or the end of the buffer.
+///
+/// Invalid UTF-8 is replaced rather than rejected.
+pub fn short_string_to_string(s: &[c_char]) -> String {
+    let len = s.iter().position(|&c| c == 0).unwrap_or(s.len());
+    let bytes: Vec<u8> = s[..len].iter().map(|&c| c as u8).collect();
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
 /// NvAPI Version Definition
 ///
 /// Maintain per structure specific version, meant to be a `const fn`.
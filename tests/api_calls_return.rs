@@ -6,10 +6,19 @@
 
 extern crate nvapi;
 
+#[test]
+fn system_info() {
+    if nvapi::initialize().is_ok() {
+        if let Ok(info) = nvapi::system_info() {
+            println!("{}", info);
+            assert_eq!(nvapi::system_info(), Ok(info));
+        }
+    }
+}
 
 #[test]
 fn physicalgpu_display_ids_connected() {
-    if let Ok(_) = nvapi::initialize() {
+    if nvapi::initialize().is_ok() {
         if let Ok(gpus) = nvapi::PhysicalGpu::enumerate() {
             for gpu in gpus {
                 // Bug: if there are zero connected displays this may crash.
@@ -22,11 +31,499 @@ fn physicalgpu_display_ids_connected() {
 
 #[test]
 fn physicalgpu_display_ids_all() {
-    if let Ok(_) = nvapi::initialize() {
+    if nvapi::initialize().is_ok() {
         if let Ok(gpus) = nvapi::PhysicalGpu::enumerate() {
             for gpu in gpus {
                 // Bug: if there are zero connected displays this may crash.
-                gpu.display_ids_all();
+                if let Ok(displays) = gpu.display_ids_all() {
+                    let mut counts = std::collections::BTreeMap::new();
+                    for display in displays {
+                        let index = counts.entry(display.connector_type()).or_insert(0);
+                        *index += 1;
+                        println!("{}", display.describe(*index));
+                        if display.is_connected() && display.connector_type() == nvapi::ConnectorType::DisplayPort {
+                            if let Ok(info) = gpu.display_port_info(display.display_id) {
+                                println!("  {}", info);
+                            }
+                        }
+                        if display.is_connected() && display.connector_type() == nvapi::ConnectorType::Hdmi {
+                            if let Ok(info) = gpu.hdmi_support_info(display.display_id) {
+                                println!("  {:?}", info);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+
+#[test]
+fn physicalgpu_connectors() {
+    if nvapi::initialize().is_ok() {
+        if let Ok(gpus) = nvapi::PhysicalGpu::enumerate() {
+            for gpu in gpus {
+                if let Ok(connectors) = gpu.connectors() {
+                    for connector in connectors {
+                        println!("{} ({} displays)", connector, connector.display_ids.len());
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn physicalgpu_enumerate_where_display_capable() {
+    if nvapi::initialize().is_ok() {
+        let gpus = nvapi::PhysicalGpu::enumerate_where(|gpu| {
+            gpu.display_ids_all().map(|ids| !ids.is_empty()).unwrap_or(false)
+        });
+        if let (Ok(filtered), Ok(all)) = (gpus, nvapi::PhysicalGpu::enumerate()) {
+            assert!(filtered.len() <= all.len());
+        }
+    }
+}
+
+#[test]
+fn gpus_cached_until_refresh() {
+    if nvapi::initialize().is_ok() {
+        if let (Ok(first), Ok(second)) = (nvapi::gpus(), nvapi::gpus()) {
+            assert!(std::sync::Arc::ptr_eq(&first, &second));
+            if let Ok(refreshed) = nvapi::refresh_gpus() {
+                assert_eq!(refreshed.len(), first.len());
+            }
+        }
+    }
+}
+
+#[test]
+fn physicalgpu_performance_state() {
+    if nvapi::initialize().is_ok() {
+        if let Ok(gpus) = nvapi::PhysicalGpu::enumerate() {
+            for gpu in gpus {
+                if let Ok(utilizations) = gpu.utilizations() {
+                    println!("{}", utilizations);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn physicalgpu_basic_info() {
+    if nvapi::initialize().is_ok() {
+        if let Ok(gpus) = nvapi::PhysicalGpu::enumerate() {
+            for gpu in gpus {
+                if let (Ok(pci), Ok(bus)) = (gpu.pci_identifiers(), gpu.bus_type()) {
+                    println!("{} on {}", pci, bus);
+                }
+                if let Ok(system_type) = gpu.system_type() {
+                    println!("{}", system_type);
+                }
+                if let Ok(Some(warning)) = gpu.driver_model().map(|model| model.display_warning()) {
+                    println!("{}", warning);
+                }
+                if let Ok(Some((major, minor))) = gpu.cuda_compute_capability() {
+                    println!("Compute {}.{}", major, minor);
+                }
+                if let Ok(true) = gpu.is_quadro() {
+                    println!("Workstation features: {:?}", gpu.workstation_features());
+                }
+                if let Ok(serial) = gpu.board_number() {
+                    assert!(!serial.contains('\0'));
+                    println!("Board {}", serial);
+                }
+                if let Ok(luid) = gpu.adapter_luid() {
+                    println!("LUID {:#018x}", u64::from_le_bytes(luid));
+                }
+                if let (Ok(revision), Ok(version)) = (gpu.vbios_revision(), gpu.vbios_version_string()) {
+                    println!("VBIOS {} ({:#x}, OEM {:?})", version, revision, gpu.vbios_oem_revision());
+                }
+                if let Ok(health) = gpu.pcie_link_health(16) {
+                    println!("{}", health);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn physicalgpu_sensors() {
+    if nvapi::initialize().is_ok() {
+        if let Ok(gpus) = nvapi::PhysicalGpu::enumerate() {
+            for gpu in gpus {
+                if let Ok(Some(power)) = gpu.power_draw_watts(nvapi::Watts(250.0)) {
+                    println!("Power: {} (assuming a 250 W board)", power);
+                }
+                let sensors = gpu.sensors();
+                if let Some(ref utilizations) = sensors.utilizations {
+                    assert!(utilizations.gpu.map(|gpu| gpu <= 100).unwrap_or(true));
+                }
+                println!("{:?}", sensors);
+                if let Ok(Some(headroom)) = gpu.thermal_headroom() {
+                    println!("Thermal: {}", headroom);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn physicalgpu_hardware_info() {
+    if nvapi::initialize().is_ok() {
+        if let Ok(gpus) = nvapi::PhysicalGpu::enumerate() {
+            for gpu in gpus {
+                if let Ok(hdcp) = gpu.hdcp_status() {
+                    println!("HDCP: {}", hdcp);
+                }
+                match gpu.foundry() {
+                    Ok(foundry) => println!("Foundry: {}", foundry),
+                    Err(_) => if let Ok(raw) = gpu.foundry_raw() {
+                        println!("Foundry: unknown ({})", raw);
+                    },
+                }
+                if let Ok(shaders) = gpu.shader_info() {
+                    println!("Shaders: {}", shaders);
+                }
+                if let Ok(memory) = gpu.memory_config() {
+                    println!("Memory: {}", memory);
+                }
+                if let (Ok(physical), Ok(virt)) = (gpu.physical_framebuffer_kb(), gpu.virtual_framebuffer_kb()) {
+                    assert!(virt >= physical);
+                    println!("Framebuffer: {} physical, {} virtual", physical, virt);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn physicalgpu_voltage_info() {
+    if nvapi::initialize().is_ok() {
+        if let Ok(gpus) = nvapi::PhysicalGpu::enumerate() {
+            for gpu in gpus {
+                if let Ok(voltage) = gpu.core_voltage() {
+                    println!("Core: {}", nvapi::Millivolts::from(voltage));
+                }
+                if let Ok(status) = gpu.voltage_domains_status() {
+                    println!("Domains: {}", nvapi::Millivolts::from(status.voltage));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn physicalgpu_arch_info() {
+    if nvapi::initialize().is_ok() {
+        if let Ok(gpus) = nvapi::PhysicalGpu::enumerate() {
+            for gpu in gpus {
+                if let Ok(info) = gpu.arch_info() {
+                    println!("{}: rev {:#x}", info.architecture, info.revision);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn gsync_gpu_sync_info() {
+    if nvapi::initialize().is_ok() {
+        if let Ok(devices) = nvapi::GSyncDevice::get_sync_devices() {
+            for device in devices {
+                if let (Ok(present), Ok(locked)) = (device.house_sync_present(), device.house_sync_locked()) {
+                    println!("House sync {}, {}", if present { "present" } else { "absent" }, if locked { "locked" } else { "not locked" });
+                }
+                if let Ok(gpus) = device.get_gpu_sync_info() {
+                    for (i, gpu) in gpus.iter().enumerate() {
+                        if let Ok(connector) = gpu.connector() {
+                            println!("GPU{} on {} connector, {}", i, connector, if gpu.is_synced() { "synced" } else { "not synced" });
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn gsync_group_synced_without_devices() {
+    if nvapi::initialize().is_ok() {
+        match nvapi::GSyncDevice::get_sync_devices() {
+            Ok(ref devices) if !devices.is_empty() => (),
+            _ => assert_eq!(nvapi::gsync_group_synced(), Ok(true)),
+        }
+    }
+}
+
+#[test]
+fn physicalgpu_set_pstate_clock_offset_rejects_out_of_range() {
+    if nvapi::initialize().is_ok() {
+        if let Ok(gpus) = nvapi::PhysicalGpu::enumerate() {
+            for gpu in gpus {
+                let offset = nvapi::KilohertzDelta(i32::MAX);
+                assert_eq!(
+                    gpu.set_pstate_clock_offset(nvapi::PState::P0, nvapi::ClockDomain::Graphics, offset),
+                    Err(nvapi::Status::InvalidArgument)
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn physicalgpu_ecc_status() {
+    if nvapi::initialize().is_ok() {
+        if let Ok(gpus) = nvapi::PhysicalGpu::enumerate() {
+            for gpu in gpus {
+                if let Ok(status) = gpu.ecc_status() {
+                    if !status.supported {
+                        assert!(!status.enabled);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn display_hdr_mode() {
+    if nvapi::initialize().is_ok() {
+        if let Ok(gpus) = nvapi::PhysicalGpu::enumerate() {
+            for gpu in gpus {
+                for id in gpu.display_ids_connected(nvapi::ConnectedIdsFlags::empty()).unwrap_or_default() {
+                    nvapi::Display::hdr_mode(id.display_id);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn physicalgpu_current_vf_point_on_curve() {
+    if nvapi::initialize().is_ok() {
+        if let Ok(gpus) = nvapi::PhysicalGpu::enumerate() {
+            for gpu in gpus {
+                if let (Ok(point), Ok(mask)) = (gpu.current_vf_point(), gpu.vfp_mask()) {
+                    let curve = gpu.vfp_curve(mask.mask).unwrap();
+                    assert!(curve.graphics.iter().any(|&(i, ref entry)| i == point.index && entry.voltage == point.voltage));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn physicalgpu_is_degraded() {
+    if nvapi::initialize().is_ok() {
+        if let Ok(gpus) = nvapi::PhysicalGpu::enumerate() {
+            for gpu in gpus {
+                // Only a GPU with a working driver reports its pstates.
+                if gpu.pstates().map(|p| !p.pstates.is_empty()).unwrap_or(false) {
+                    assert_eq!(gpu.is_degraded(), Ok(false));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn physicalgpu_edid() {
+    if nvapi::initialize().is_ok() {
+        if let Ok(gpus) = nvapi::PhysicalGpu::enumerate() {
+            for gpu in gpus {
+                for id in gpu.display_ids_connected(nvapi::ConnectedIdsFlags::empty()).unwrap_or_default() {
+                    if let Ok(edid) = gpu.edid(id.display_id.into()) {
+                        assert_eq!(edid.len() % 128, 0);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn display_vibrance() {
+    if nvapi::initialize().is_ok() {
+        if let Ok(gpus) = nvapi::PhysicalGpu::enumerate() {
+            for gpu in gpus {
+                for id in gpu.display_ids_connected(nvapi::ConnectedIdsFlags::empty()).unwrap_or_default() {
+                    if let Ok(vibrance) = nvapi::Display::vibrance(id.display_id) {
+                        assert_eq!(nvapi::Display::set_vibrance(id.display_id, vibrance.range.max + 1), Err(nvapi::Status::InvalidArgument));
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn physicalgpu_fan_rpm() {
+    if nvapi::initialize().is_ok() {
+        if let Ok(gpus) = nvapi::PhysicalGpu::enumerate() {
+            for gpu in gpus {
+                if let Ok(count) = gpu.fan_count() {
+                    for index in 0..count.max(1) {
+                        if let Ok(rpm) = gpu.fan_rpm(index) {
+                            // 0 for a stopped or absent fan
+                            assert!(rpm < 20000);
+                            println!("Fan {}: {} RPM", index, rpm);
+                        }
+                    }
+                    assert_eq!(gpu.fan_rpm(count.max(1)), Err(nvapi::Status::InvalidArgument));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn physicalgpu_clock_frequencies() {
+    if nvapi::initialize().is_ok() {
+        if let Ok(gpus) = nvapi::PhysicalGpu::enumerate() {
+            for gpu in gpus {
+                if let Ok(clocks) = gpu.clock_frequencies(nvapi::ClockFrequencyType::Current) {
+                    if let Some(graphics) = clocks.graphics() {
+                        println!("Graphics {}", graphics);
+                    }
+                    assert_eq!(clocks.memory(), clocks.get(&nvapi::ClockDomain::Memory).cloned());
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn physicalgpu_max_boost_clock_mhz() {
+    if nvapi::initialize().is_ok() {
+        if let Ok(gpus) = nvapi::PhysicalGpu::enumerate() {
+            for gpu in gpus {
+                let current = gpu.clock_frequencies(nvapi::ClockFrequencyType::Current).ok()
+                    .and_then(|clocks| clocks.graphics());
+                if let (Ok(max), Some(current)) = (gpu.max_boost_clock_mhz(), current) {
+                    assert!(max >= current.0 / 1000);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn physicalgpu_thermal_sensor_labels() {
+    if nvapi::initialize().is_ok() {
+        if let Ok(gpus) = nvapi::PhysicalGpu::enumerate() {
+            for gpu in gpus {
+                if let (Ok(labels), Ok(sensors)) = (gpu.thermal_sensor_labels(), gpu.thermal_settings(None)) {
+                    assert_eq!(labels.len(), sensors.len());
+                    for (index, target, controller) in labels {
+                        println!("{}: {} ({})", index, target, controller);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn physicalgpu_memory_bandwidth() {
+    if nvapi::initialize().is_ok() {
+        if let Ok(gpus) = nvapi::PhysicalGpu::enumerate() {
+            for gpu in gpus {
+                if let Some(bandwidth) = gpu.memory_bandwidth() {
+                    println!("~{:.0} GB/s", bandwidth);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn chipset_info() {
+    if nvapi::initialize().is_ok() {
+        if let Ok(info) = nvapi::chipset_info() {
+            println!("{} ({:04x}:{:04x})", info, info.vendor_id, info.device_id);
+        }
+    }
+}
+
+#[test]
+fn logicalgpu_physical_gpus() {
+    if nvapi::initialize().is_ok() {
+        if let Ok(gpus) = nvapi::LogicalGpu::enumerate() {
+            for gpu in gpus {
+                if let Ok(physical) = gpu.physical_gpus() {
+                    for physical in physical {
+                        assert!(physical.logical_gpu().is_ok());
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn display_primary() {
+    if nvapi::initialize().is_ok() {
+        if let Ok(primary) = nvapi::primary_display_id() {
+            assert_eq!(nvapi::Display::is_primary(primary), Ok(true));
+        }
+    }
+}
+
+#[test]
+fn display_config_round_trip() {
+    if nvapi::initialize().is_ok() {
+        if let Ok(config) = nvapi::Display::get_config() {
+            for path in &config {
+                println!("source {}: {:?} at {:?}, {} targets", path.source_id, path.resolution(), path.position(), path.targets.len());
+            }
+            // Validating the current arrangement does not modeset
+            assert_eq!(nvapi::Display::set_config(&config, nvapi::DisplayConfigFlags::VALIDATE_ONLY), Ok(()));
+        }
+    }
+}
+
+#[test]
+fn physical_gpu_from_display_id() {
+    if nvapi::initialize().is_ok() {
+        if let Ok(gpus) = nvapi::PhysicalGpu::enumerate() {
+            for gpu in gpus {
+                if let Ok(ids) = gpu.display_ids_all() {
+                    for id in ids {
+                        assert!(nvapi::physical_gpu_from_display_id(id.display_id).is_ok());
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn physicalgpu_illumination_rejects_out_of_range() {
+    if nvapi::initialize().is_ok() {
+        if let Ok(gpus) = nvapi::PhysicalGpu::enumerate() {
+            for gpu in gpus {
+                if let Ok(zones) = gpu.illumination_zones() {
+                    println!("Illumination: {:?}", zones);
+                }
+                assert_eq!(gpu.set_illumination(nvapi::IlluminationAttribute::LogoBrightness, nvapi::Percentage(101)), Err(nvapi::Status::InvalidArgument));
+            }
+        }
+    }
+}
+
+#[test]
+fn display_monitor_capabilities() {
+    if nvapi::initialize().is_ok() {
+        if let Ok(gpus) = nvapi::PhysicalGpu::enumerate() {
+            for gpu in gpus {
+                for display in gpu.display_ids_connected(nvapi::ConnectedIdsFlags::empty()).unwrap_or_default() {
+                    if let Ok(caps) = nvapi::Display::monitor_capabilities(display.display_id) {
+                        println!("{}: VRR {}, HDR {}", display.display_id, caps.supports_vrr(), caps.supports_hdr());
+                    }
+                }
             }
         }
     }
@@ -0,0 +1,226 @@
+extern crate nvapi;
+
+use nvapi::{Brand, GpuModel, PcieLinkHealth};
+
+#[test]
+fn gpu_model_parse() {
+    let model = GpuModel::parse("NVIDIA GeForce RTX 4090");
+    assert_eq!(model, GpuModel { brand: Brand::GeForce, series: "RTX 4090".into(), is_ti: false, is_super: false });
+
+    let model = GpuModel::parse("NVIDIA GeForce RTX 4070 Ti SUPER");
+    assert_eq!(model, GpuModel { brand: Brand::GeForce, series: "RTX 4070".into(), is_ti: true, is_super: true });
+
+    let model = GpuModel::parse("GeForce GTX 1080 Ti");
+    assert_eq!((model.brand, &model.series[..], model.is_ti), (Brand::GeForce, "GTX 1080", true));
+
+    let model = GpuModel::parse("Quadro P4000");
+    assert_eq!((model.brand, &model.series[..]), (Brand::Quadro, "P4000"));
+
+    assert_eq!(GpuModel::parse("NVIDIA RTX A6000").brand, Brand::Quadro);
+    assert_eq!(GpuModel::parse("NVIDIA TITAN RTX").brand, Brand::GeForce);
+    assert_eq!(GpuModel::parse("NVIDIA A100-PCIE-40GB").brand, Brand::Tesla);
+    assert_eq!(GpuModel::parse("Tesla V100-SXM2-16GB").series, "V100-SXM2-16GB");
+    assert_eq!(GpuModel::parse("").brand, Brand::Unknown);
+}
+
+#[test]
+fn memory_bandwidth_gddr6x() {
+    let bandwidth = nvapi::memory_bandwidth(384, nvapi::RamType::GDDR6X, nvapi::Kilohertz(10_501_000)).unwrap();
+    assert_eq!(bandwidth.round(), 1008.0);
+    assert_eq!(nvapi::memory_bandwidth(384, nvapi::RamType::Unknown, nvapi::Kilohertz(10_501_000)), None);
+}
+
+#[test]
+fn pcie_link_health_downgraded() {
+    let health = PcieLinkHealth::new(8, 16);
+    assert!(health.is_downgraded);
+    assert_eq!(health.to_string(), "PCIe link running at x8, expected x16");
+
+    assert!(!PcieLinkHealth::new(16, 16).is_downgraded);
+}
+
+#[test]
+fn cuda_compute_capability_from_arch() {
+    assert_eq!(nvapi::cuda_compute_capability(0x190, 0x2), Some((8, 9)));
+    assert_eq!(nvapi::cuda_compute_capability(0x170, 0x0), Some((8, 0)));
+    assert_eq!(nvapi::cuda_compute_capability(0x170, 0x2), Some((8, 6)));
+    assert_eq!(nvapi::cuda_compute_capability(0x50, 0x0), None);
+    assert_eq!(nvapi::cuda_compute_capability(0xdead, 0x0), None);
+}
+
+#[test]
+fn shader_info_display() {
+    let info = nvapi::ShaderInfo { cuda_cores: Some(16384), shader_pipes: None, shader_sub_pipes: Some(1), sm_count: Some(128) };
+    assert_eq!(info.to_string(), "16384 CUDA cores, 128 SMs, 1 shader sub-pipes");
+    assert_eq!(nvapi::ShaderInfo::default().to_string(), "Unknown");
+    assert_eq!(nvapi::cuda_cores_per_sm(8, 9), Some(128));
+}
+
+#[test]
+fn memory_config_display() {
+    let config = nvapi::MemoryConfig {
+        ram_type: Some(nvapi::RamType::GDDR6X),
+        ram_maker: Some(nvapi::RamMaker::Micron),
+        bus_width: Some(384),
+        bank_count: None,
+        partition_count: Some(12),
+    };
+    assert_eq!(config.to_string(), "GDDR6X (Micron), 384-bit, 12 partitions");
+}
+
+#[test]
+fn display_info_describe() {
+    let display = nvapi::DisplayInfo {
+        connector: nvapi::MonitorConnectorType::DisplayPort,
+        display_id: nvapi::DisplayId(0x80061086),
+        flags: nvapi::DisplayIdsFlags::CONNECTED | nvapi::DisplayIdsFlags::ACTIVE,
+    };
+    assert_eq!(display.connector_type(), nvapi::ConnectorType::DisplayPort);
+    assert_eq!(display.describe(1), "DP-1 connected active");
+    assert_eq!(display.to_string(), "DP connected active");
+
+    let panel = nvapi::DisplayInfo {
+        connector: nvapi::MonitorConnectorType::Lvds,
+        flags: nvapi::DisplayIdsFlags::DYNAMIC,
+        ..display
+    };
+    assert_eq!(panel.describe(2), "Internal-2 disconnected dynamic");
+    assert_eq!(nvapi::DisplayId::from(panel), nvapi::DisplayId(0x80061086));
+}
+
+#[test]
+fn connector_label() {
+    let connector = nvapi::Connector {
+        index: 0,
+        connector_type: nvapi::ConnectorType::Hdmi,
+        output_id: 0x100,
+        display_ids: vec![nvapi::DisplayId(0x80061086)],
+    };
+    assert_eq!(connector.to_string(), "Connector 0: HDMI");
+}
+
+#[test]
+fn hdcp_status_from_raw() {
+    use nvapi::sys::gpu::{NV_GPU_GET_HDCP_SUPPORT_STATUS, NV_GPU_HDCP_FUSE_STATE_ENABLED, NV_GPU_HDCP_KEY_SOURCE_CRYPTO_ROM, NV_GPU_HDCP_KEY_SOURCE_STATE_PRESENT, NV_GPU_HDCP_KEY_SOURCE_STATE_ABSENT};
+    use nvapi::RawConversion;
+
+    let mut data = NV_GPU_GET_HDCP_SUPPORT_STATUS::zeroed();
+    data.hdcpFuseState = NV_GPU_HDCP_FUSE_STATE_ENABLED;
+    data.hdcpKeySource = NV_GPU_HDCP_KEY_SOURCE_CRYPTO_ROM;
+    data.hdcpKeySourceState = NV_GPU_HDCP_KEY_SOURCE_STATE_PRESENT;
+    let status = data.convert_raw().unwrap();
+    assert!(status.is_supported());
+    assert_eq!(status.to_string(), "Supported (keys in Crypto ROM)");
+
+    data.hdcpKeySourceState = NV_GPU_HDCP_KEY_SOURCE_STATE_ABSENT;
+    assert!(!data.convert_raw().unwrap().is_supported());
+}
+
+#[test]
+fn display_port_info_display() {
+    use nvapi::RawConversion;
+
+    let mut data = nvapi::sys::gpu::display::NV_DISPLAY_PORT_INFO::zeroed();
+    data.dpcd_ver = 0x14;
+    data.curLinkRate = nvapi::DpLinkRate::Hbr3.raw();
+    data.maxLinkRate = nvapi::DpLinkRate::Hbr3.raw();
+    data.curLaneCount = nvapi::DpLaneCount::Four.raw();
+    data.maxLaneCount = nvapi::DpLaneCount::Four.raw();
+    data.bpc = nvapi::DpBpc::Bpc10.raw();
+    data.flags = 1;
+    assert!(data.isDp());
+
+    let info = data.convert_raw().unwrap();
+    assert_eq!(info.color_format, nvapi::DpColorFormat::Rgb);
+    assert_eq!(info.to_string(), "DP 1.4 HBR3 x4, 10 bpc");
+}
+
+#[test]
+fn display_port_info_keeps_unknown_link_rate() {
+    use nvapi::RawConversion;
+
+    let mut data = nvapi::sys::gpu::display::NV_DISPLAY_PORT_INFO::zeroed();
+    data.dpcd_ver = 0x20;
+    data.curLinkRate = 0x64;
+    data.maxLinkRate = nvapi::DpLinkRate::Hbr3.raw();
+    data.curLaneCount = nvapi::DpLaneCount::Four.raw();
+    data.maxLaneCount = nvapi::DpLaneCount::Four.raw();
+    data.bpc = nvapi::DpBpc::Bpc10.raw();
+
+    let info = data.convert_raw().unwrap();
+    assert_eq!(info.link_rate, nvapi::DisplayPortLinkRate::Unknown(0x64));
+    assert_eq!(info.max_link_rate, nvapi::DisplayPortLinkRate::Hbr3);
+    assert_eq!(info.to_string(), "DP 2.0 Unknown (100) x4, 10 bpc");
+}
+
+#[test]
+fn hdmi_support_info_from_raw() {
+    use nvapi::RawConversion;
+
+    let mut data = nvapi::sys::gpu::display::NV_HDMI_SUPPORT_INFO::zeroed();
+    data.flags = 0x1 | 0x10 | 0x80;
+    data.EDID861ExtRev = 3;
+    assert!(data.isMonHDMI());
+
+    let info = data.convert_raw().unwrap();
+    assert!(info.gpu_hdmi_capable && info.ycbcr422);
+    assert!(!info.ycbcr444 && !info.basic_audio);
+    assert_eq!(info.edid_861_revision, 3);
+}
+
+#[test]
+fn system_type_is_mobile() {
+    assert!(nvapi::SystemType::Laptop.is_mobile());
+    assert!(!nvapi::SystemType::Desktop.is_mobile());
+    assert_eq!(nvapi::SystemType::Laptop.to_string(), "Laptop");
+    assert_eq!(nvapi::SystemType::default(), nvapi::SystemType::Unknown);
+}
+
+#[test]
+fn driver_model_decode() {
+    use nvapi::DriverModel;
+
+    assert_eq!(DriverModel::from_raw(0x1100), DriverModel::Wddm { major: 1, minor: 1 });
+    assert_eq!(DriverModel::from_raw(0x2000).to_string(), "WDDM 2.00");
+    assert!(DriverModel::from_raw(0x2000).is_wddm());
+
+    assert_eq!(DriverModel::from_raw(0x2000).wddm_version(), Some((2, 0)));
+    assert_eq!(DriverModel::from_raw(0x2000).display_warning(), None);
+
+    let tcc = DriverModel::from_raw(1);
+    assert_eq!(tcc, DriverModel::Tcc);
+    assert_eq!(tcc.wddm_version(), None);
+    assert_eq!(tcc.display_warning().unwrap(), "GPU is in TCC mode; display APIs unavailable");
+
+    assert_eq!(DriverModel::from_raw(2), DriverModel::Mcdm);
+    assert_eq!(DriverModel::from_raw(0), DriverModel::Unknown(0));
+    assert_eq!(DriverModel::from_raw(1).raw(), 1);
+}
+
+#[test]
+#[allow(deprecated)]
+fn driver_model_deprecated_shims() {
+    use nvapi::DriverModel;
+
+    let model = DriverModel::new(0x1100);
+    assert_eq!(model.value(), 0x1100);
+    assert_eq!(model.wddm(), (1, 1));
+    assert_eq!(DriverModel::new(0x2000).wddm(), (2, 0));
+}
+
+#[test]
+fn pci_identifiers_device_id() {
+    let pci = nvapi::PciIdentifiers {
+        device_id: 0x2684_10de,
+        subsystem_id: 0x1675_1043,
+        revision_id: 0xa1,
+        ext_device_id: 0x2684,
+    };
+    assert_eq!(pci.pci_vendor_id(), 0x10de);
+    assert_eq!(pci.pci_device_id(), 0x2684);
+    assert!(pci.matches_device(0x2684));
+    assert!(!pci.matches_device(0x2704));
+    // The board vendor, ASUS
+    assert_eq!(pci.vendor_id(), 0x1043);
+    assert_eq!(pci.to_string(), "268410de - 16751043 - 00002684 - a1");
+}
@@ -0,0 +1,94 @@
+extern crate nvapi;
+
+use nvapi::GSyncDevice;
+
+#[test]
+#[ignore = "changes the genlock configuration of every attached sync device"]
+fn gsync_set_all_masters() {
+    nvapi::initialize().unwrap();
+
+    // Pick the first masterable display of every sync device.
+    let mut mapping = Vec::new();
+    for device in GSyncDevice::get_sync_devices().unwrap() {
+        let board_id = device.board_id().unwrap() as u64;
+        let displays = device.get_displays().unwrap();
+        if let Some(display) = displays.iter().find(|d| d.is_masterable()) {
+            mapping.push((board_id, display.displayId));
+        }
+    }
+
+    nvapi::gsync_set_all_masters(&mapping).unwrap();
+    assert_eq!(nvapi::gsync_group_synced(), Ok(true));
+}
+
+#[test]
+fn display_sync_state_try_from_raw() {
+    use std::convert::TryFrom;
+    use nvapi::sys::gsync::{DisplaySyncState, NVAPI_GSYNC_DISPLAY_SYNC_STATE_MASTER};
+
+    fn is_master(raw: i32) -> Result<bool, nvapi::sys::ArgumentRangeError> {
+        Ok(DisplaySyncState::try_from(raw)? == DisplaySyncState::Master)
+    }
+
+    assert!(is_master(NVAPI_GSYNC_DISPLAY_SYNC_STATE_MASTER).unwrap());
+    assert_eq!(i32::from(DisplaySyncState::Master), NVAPI_GSYNC_DISPLAY_SYNC_STATE_MASTER);
+    assert!(is_master(-42).is_err());
+}
+
+#[test]
+fn sync_delay_adjustment_reports_clamping() {
+    let mut data = nvapi::sys::gsync::NV_GSYNC_DELAY::zeroed();
+    data.numLines = 1125;
+    data.numPixels = 8;
+    assert_eq!(data.maxLines(), 0);
+
+    let exact = nvapi::SyncDelayAdjustment::new(nvapi::SyncDelay::new(1125, 8), &data, 0);
+    assert!(!exact.is_clamped());
+    let clamped = nvapi::SyncDelayAdjustment::new(nvapi::SyncDelay::new(5000, 3), &data, 0);
+    assert!(clamped.is_clamped());
+    assert_eq!(clamped.adjusted, nvapi::SyncDelay::new(1125, 8));
+}
+
+#[test]
+fn set_sync_state_settings_rejects_two_masters() {
+    use nvapi::DisplaySyncState;
+
+    // Fails before reaching the driver, so any handle will do
+    let device = GSyncDevice::new(Default::default());
+    let settings = [
+        (0x80061086, DisplaySyncState::Master),
+        (0x80061087, DisplaySyncState::Master),
+        (0x80061088, DisplaySyncState::Slave),
+    ];
+    assert_eq!(device.set_sync_state_settings(&settings, 0), Err(nvapi::Status::InvalidArgument));
+}
+
+#[test]
+fn sync_status_bools() {
+    let mut raw = nvapi::sys::gsync::NV_GSYNC_STATUS::zeroed();
+    raw.bIsSynced = 1;
+    raw.bIsSyncSignalAvailable = 1;
+
+    let status = nvapi::SyncStatus(raw);
+    assert!(status.is_synced());
+    assert!(!status.is_stereo_synced());
+    assert!(status.signal_available());
+}
+
+#[test]
+fn gsync_bitfield_struct_layout() {
+    use std::mem::size_of;
+    use nvapi::sys::gsync::{NV_GSYNC_DISPLAY, NV_GSYNC_GPU};
+
+    // isMasterable and isSynced share a u32 with their reserved bits
+    assert_eq!(size_of::<NV_GSYNC_DISPLAY>(), 16);
+    #[cfg(target_pointer_width = "64")]
+    assert_eq!(size_of::<NV_GSYNC_GPU>(), 40);
+
+    let mut display = NV_GSYNC_DISPLAY::zeroed();
+    display.flags = 0x1;
+    assert!(display.is_masterable());
+    let mut gpu = NV_GSYNC_GPU::zeroed();
+    gpu.flags = 0xfffffffe;
+    assert!(!gpu.is_synced());
+}
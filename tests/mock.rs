@@ -0,0 +1,322 @@
+#![cfg(feature = "mock")]
+
+extern crate nvapi;
+
+use std::mem::size_of;
+use std::sync::{Mutex, MutexGuard};
+use nvapi::sys::{mock, Api};
+use nvapi::{Mosaic, MosaicTopo, PhysicalGpu, Status, SystemType};
+
+// Responses are global, so the tests in this file take turns
+static LOCK: Mutex<()> = Mutex::new(());
+
+fn mock_gpu() -> MutexGuard<'static, ()> {
+    let guard = LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    mock::clear();
+    mock::set_response(Api::NvAPI_Initialize, Vec::new());
+
+    let mut handles = vec![0u8; size_of::<nvapi::sys::handles::NvPhysicalGpuHandle>() * nvapi::sys::types::NVAPI_MAX_PHYSICAL_GPUS];
+    handles[..size_of::<usize>()].copy_from_slice(&0x1000usize.to_ne_bytes());
+    handles.extend(1u32.to_ne_bytes());
+    mock::set_response(Api::NvAPI_EnumPhysicalGPUs, handles);
+
+    guard
+}
+
+/// Registers a single sync device whose topology holds `displays` as (ID, masterable, state).
+///
+/// Outputs only take a single struct, so all displays after the first one read back as zeroed.
+fn mock_sync_device(displays: &[(u32, bool, nvapi::DisplaySyncState)]) {
+    let mut handles = vec![0u8; size_of::<nvapi::sys::handles::NvGSyncDeviceHandle>() * nvapi::sys::types::NVAPI_MAX_GSYNC_DEVICES];
+    handles[..size_of::<usize>()].copy_from_slice(&0x2000usize.to_ne_bytes());
+    handles.extend(1u32.to_ne_bytes());
+    mock::set_response(Api::NvAPI_GSync_EnumSyncDevices, handles);
+
+    let mut caps = nvapi::sys::gsync::NV_GSYNC_CAPABILITIES::zeroed();
+    caps.v1.boardId = 7;
+    mock::set_response(Api::NvAPI_GSync_QueryCapabilities, mock::struct_bytes(&caps));
+
+    // The display count for the first query, then the displays for the second one. The version
+    // of the first display doubles as the count of the first query.
+    let mut topology = (displays.len() as u32).to_ne_bytes().to_vec();
+    for &(id, masterable, state) in displays {
+        let mut display = nvapi::sys::gsync::NV_GSYNC_DISPLAY::zeroed();
+        display.version = displays.len() as u32;
+        display.displayId = id;
+        display.flags = masterable as u32;
+        display.syncState = state.raw();
+        topology.extend(mock::struct_bytes(&display));
+    }
+    mock::set_response(Api::NvAPI_GSync_GetTopology, topology);
+}
+
+#[test]
+fn mock_physical_gpu() {
+    let _guard = mock_gpu();
+    let mut name = b"NVIDIA GeForce RTX 4090 Laptop GPU".to_vec();
+    name.push(0);
+    mock::set_response(Api::NvAPI_GPU_GetFullName, name);
+    mock::set_response(Api::NvAPI_GPU_GetSystemType, SystemType::Laptop.raw().to_ne_bytes().to_vec());
+
+    nvapi::initialize().unwrap();
+    let gpus = PhysicalGpu::enumerate().unwrap();
+    assert_eq!(gpus.len(), 1);
+    assert_eq!(gpus[0].full_name().unwrap(), "NVIDIA GeForce RTX 4090 Laptop GPU");
+    assert_eq!(gpus[0].is_mobile(), Ok(true));
+}
+
+#[test]
+fn mock_status_is_returned() {
+    let _guard = mock_gpu();
+    mock::set_status(Api::NvAPI_GPU_GetSystemType, Status::NotSupported);
+
+    let gpus = PhysicalGpu::enumerate().unwrap();
+    assert_eq!(gpus[0].system_type(), Err(Status::NotSupported));
+}
+
+#[test]
+fn mock_mosaic_topology() {
+    let _guard = mock_gpu();
+    let mut brief = nvapi::sys::mosaic::NV_MOSAIC_TOPO_BRIEF::zeroed();
+    brief.topo = MosaicTopo::T2x2_Basic.raw();
+    brief.enabled = 1;
+    let mut settings = nvapi::sys::mosaic::NV_MOSAIC_DISPLAY_SETTING::zeroed();
+    settings.width = 3840;
+    settings.height = 2160;
+    settings.rrx1k = 59_940;
+
+    let mut response = mock::struct_bytes(&brief);
+    response.extend(mock::struct_bytes(&settings));
+    response.extend((-64i32).to_ne_bytes());
+    response.extend(0i32.to_ne_bytes());
+    mock::set_response(Api::NvAPI_Mosaic_GetCurrentTopo, response);
+
+    let (brief, settings, overlap_x, overlap_y) = Mosaic::get_current_topology().unwrap();
+    assert_eq!(brief.topo_enum().unwrap(), MosaicTopo::T2x2_Basic);
+    assert_eq!((settings.width, settings.height, settings.rrx1k), (3840, 2160, 59_940));
+    assert_eq!((overlap_x, overlap_y), (-64, 0));
+}
+
+#[test]
+fn mock_core_count_not_supported() {
+    let _guard = mock_gpu();
+    mock::set_status(Api::NvAPI_GPU_GetGpuCoreCount, Status::NotSupported);
+
+    let gpus = PhysicalGpu::enumerate().unwrap();
+    assert_eq!(gpus[0].core_count(), Err(Status::NotSupported));
+    assert_eq!(gpus[0].core_count_opt(), Ok(None));
+
+    mock::set_response(Api::NvAPI_GPU_GetGpuCoreCount, 16384u32.to_ne_bytes().to_vec());
+    assert_eq!(gpus[0].core_count_opt(), Ok(Some(16384)));
+}
+
+#[test]
+fn mock_unknown_foundry() {
+    let _guard = mock_gpu();
+    mock::set_response(Api::NvAPI_GPU_GetFoundry, 1i32.to_ne_bytes().to_vec());
+
+    let gpus = PhysicalGpu::enumerate().unwrap();
+    assert_eq!(gpus[0].foundry(), Ok(nvapi::Foundry::TSMC));
+
+    mock::set_response(Api::NvAPI_GPU_GetFoundry, 42i32.to_ne_bytes().to_vec());
+    assert_eq!(gpus[0].foundry(), Err(Status::ArgumentExceedMaxSize));
+    assert_eq!(gpus[0].foundry_raw(), Ok(42));
+}
+
+#[test]
+fn mock_short_name_is_cached() {
+    let _guard = mock_gpu();
+    mock::set_status(Api::NvAPI_GPU_GetShortName, Status::NotSupported);
+
+    let gpus = PhysicalGpu::enumerate().unwrap();
+    assert_eq!(gpus[0].short_name(), Err(Status::NotSupported));
+
+    let mut name = b"AD102".to_vec();
+    name.push(0);
+    mock::set_response(Api::NvAPI_GPU_GetShortName, name);
+    assert_eq!(gpus[0].short_name(), Ok("AD102".into()));
+
+    // Later calls don't reach the driver
+    mock::set_status(Api::NvAPI_GPU_GetShortName, Status::Error);
+    for _ in 0..3 {
+        assert_eq!(gpus[0].short_name(), Ok("AD102".into()));
+    }
+}
+
+#[test]
+fn mock_gsync_house_sync() {
+    let _guard = mock_gpu();
+    mock_sync_device(&[]);
+
+    let mut params = nvapi::sys::gsync::NV_GSYNC_STATUS_PARAMS::zeroed();
+    params.v1.houseSyncIncoming = 50;
+    mock::set_response(Api::NvAPI_GSync_GetStatusParameters, mock::struct_bytes(&params));
+
+    let devices = nvapi::GSyncDevice::get_sync_devices().unwrap();
+    assert_eq!(devices[0].house_sync_present(), Ok(false));
+    assert_eq!(devices[0].house_sync_locked(), Ok(false));
+
+    params.v1.bHouseSync = 1;
+    mock::set_response(Api::NvAPI_GSync_GetStatusParameters, mock::struct_bytes(&params));
+    assert_eq!(devices[0].house_sync_present(), Ok(true));
+
+    // No GPUs in the topology
+    mock::set_response(Api::NvAPI_GSync_GetTopology, 0u32.to_ne_bytes().to_vec());
+    assert_eq!(devices[0].house_sync_locked(), Ok(false));
+}
+
+#[test]
+fn mock_gsync_sync_status_all() {
+    let _guard = mock_gpu();
+    // Without GPUs in the topology there is nothing to query
+    mock::set_response(Api::NvAPI_GSync_GetTopology, 0u32.to_ne_bytes().to_vec());
+    mock::set_status(Api::NvAPI_GSync_GetSyncStatus, Status::Error);
+
+    let device = nvapi::GSyncDevice::new(Default::default());
+    assert!(device.sync_status_all().is_empty());
+
+    mock::set_status(Api::NvAPI_GSync_GetTopology, Status::NvidiaDeviceNotFound);
+    assert!(device.sync_status_all().is_empty());
+
+    // The GPU count, followed by the GPU itself for the second query
+    let mut gpu = nvapi::sys::gsync::NV_GSYNC_GPU::zeroed();
+    gpu.hPhysicalGpu = *nvapi::PhysicalGpu::enumerate().unwrap()[0].handle();
+    let mut topology = 1u32.to_ne_bytes().to_vec();
+    topology.extend(mock::struct_bytes(&gpu));
+    mock::set_response(Api::NvAPI_GSync_GetTopology, topology);
+    assert!(device.sync_status_all().is_empty());
+
+    let mut status = nvapi::sys::gsync::NV_GSYNC_STATUS::zeroed();
+    status.bIsSynced = 1;
+    mock::set_response(Api::NvAPI_GSync_GetSyncStatus, mock::struct_bytes(&status));
+    let statuses = device.sync_status_all();
+    assert_eq!(statuses.len(), 1);
+    assert!(statuses[0].1.is_synced());
+    assert!(!statuses[0].1.signal_available());
+}
+
+#[test]
+fn mock_gsync_set_all_masters_keeps_unlisted_devices() {
+    use nvapi::DisplaySyncState;
+
+    let _guard = mock_gpu();
+    mock_sync_device(&[
+        (0x100, true, DisplaySyncState::Master),
+        (0x101, false, DisplaySyncState::Slave),
+    ]);
+    mock::set_response(Api::NvAPI_GSync_SetSyncStateSettings, Vec::new());
+
+    // The device isn't listed, but its displays must still be passed to the driver
+    let none: &[(u64, u32)] = &[];
+    assert_eq!(nvapi::gsync_set_all_masters(none), Ok(()));
+    let call = mock::last_call(Api::NvAPI_GSync_SetSyncStateSettings).unwrap();
+    assert_eq!(call[0], 2u32.to_ne_bytes());
+
+    assert_eq!(nvapi::gsync_set_all_masters(&[(7, 0x100)]), Ok(()));
+    let call = mock::last_call(Api::NvAPI_GSync_SetSyncStateSettings).unwrap();
+    assert_eq!(call[0], 2u32.to_ne_bytes());
+
+    assert_eq!(nvapi::gsync_set_all_masters(&[(7, 0x101)]), Err(Status::InvalidArgument));
+    assert_eq!(nvapi::gsync_set_all_masters(&[(8, 0x100)]), Err(Status::InvalidArgument));
+}
+
+#[test]
+fn mock_gsync_set_sync_state_settings_merges_topology() {
+    use nvapi::DisplaySyncState;
+
+    let _guard = mock_gpu();
+    mock_sync_device(&[
+        (0x100, true, DisplaySyncState::Slave),
+        (0x101, true, DisplaySyncState::Slave),
+        (0x102, true, DisplaySyncState::Slave),
+    ]);
+    mock::set_response(Api::NvAPI_GSync_SetSyncStateSettings, Vec::new());
+
+    // Every display of the topology reaches the driver, not just the one being changed
+    let device = nvapi::GSyncDevice::get_sync_devices().unwrap().remove(0);
+    assert_eq!(device.set_sync_state_settings(&[(0x100, DisplaySyncState::Master)], 0x1), Ok(()));
+    let call = mock::last_call(Api::NvAPI_GSync_SetSyncStateSettings).unwrap();
+    assert_eq!(call[0], 3u32.to_ne_bytes());
+    assert_eq!(call[2], 1u32.to_ne_bytes());
+
+    assert_eq!(device.set_sync_state_settings(&[(0x103, DisplaySyncState::Slave)], 0), Err(Status::InvalidArgument));
+
+    mock_sync_device(&[(0x100, false, DisplaySyncState::Slave)]);
+    assert_eq!(device.set_sync_state_settings(&[(0x100, DisplaySyncState::Master)], 0), Err(Status::InvalidArgument));
+}
+
+#[test]
+fn mock_unknown_status_is_kept() {
+    let _guard = mock_gpu();
+    mock::set_status(Api::NvAPI_GPU_GetFullName, Status::Unknown(-999));
+
+    let gpus = PhysicalGpu::enumerate().unwrap();
+    assert_eq!(gpus[0].full_name(), Err(Status::Unknown(-999)));
+}
+
+#[test]
+fn mock_scene_applies_display_config() {
+    let _guard = mock_gpu();
+    mock::set_status(Api::NvAPI_DISP_GetDisplayConfig, Status::NotSupported);
+    // A single empty grid; its version doubles as the count of the first query
+    let mut grid = nvapi::sys::mosaic::NV_MOSAIC_GRID_TOPO::zeroed();
+    grid.version = 1;
+    let mut grids = mock::struct_bytes(&grid);
+    grids.extend(1u32.to_ne_bytes());
+    mock::set_response(Api::NvAPI_Mosaic_EnumDisplayGrids, grids);
+    mock::set_response(Api::NvAPI_Mosaic_SetDisplayGrids, Vec::new());
+    mock::set_response(Api::NvAPI_DISP_SetDisplayConfig, Vec::new());
+
+    let mut scene = nvapi::capture_scene().unwrap();
+    assert!(scene.display_config.is_empty());
+    assert_eq!(scene.mosaic.len(), 1);
+
+    nvapi::apply_scene(&scene).unwrap();
+    assert!(mock::last_call(Api::NvAPI_DISP_SetDisplayConfig).is_none());
+
+    let mut source_mode = nvapi::sys::dispcontrol::NV_DISPLAYCONFIG_SOURCE_MODE_INFO::zeroed();
+    source_mode.position.x = 1920;
+    scene.display_config.push(nvapi::DisplayConfigPath {
+        source_id: 0,
+        source_mode,
+        targets: Vec::new(),
+        non_nvidia_adapter: false,
+    });
+    nvapi::apply_scene(&scene).unwrap();
+    let call = mock::last_call(Api::NvAPI_DISP_SetDisplayConfig).unwrap();
+    assert_eq!(call[0], 1u32.to_ne_bytes());
+    assert_eq!(call[2], 0u32.to_ne_bytes());
+}
+
+#[test]
+fn mock_display_viewports_by_raw_id() {
+    let _guard = mock_gpu();
+    let mut viewports = [nvapi::sys::NV_RECT::zeroed(); nvapi::sys::mosaic::NV_MOSAIC_MAX_DISPLAYS];
+    viewports[0] = (0, 0, 1920, 1080).into();
+    viewports[1] = (1920, 0, 3840, 1080).into();
+    let mut response = mock::struct_bytes(&viewports);
+    response.extend(1u32.to_ne_bytes());
+    mock::set_response(Api::NvAPI_Mosaic_GetDisplayViewportsByResolution, response);
+
+    let (rects, bezel_corrected) = Mosaic::get_current_display_viewports(0x80061086u32).unwrap();
+    assert_eq!(rects.len(), 2);
+    assert!(bezel_corrected);
+    let single = Mosaic::get_display_viewports_by_resolution_single(0x80061086u32, 1920, 1080).unwrap();
+    assert_eq!(single.to_string(), "1920x1080+0+0");
+}
+
+#[test]
+fn mock_set_cooler_level_targets_one_cooler() {
+    let _guard = mock_gpu();
+    let mut settings = nvapi::sys::gpu::cooler::private::NV_GPU_COOLER_SETTINGS::zeroed();
+    settings.count = 2;
+    mock::set_response(Api::NvAPI_GPU_GetCoolerSettings, mock::struct_bytes(&settings));
+    mock::set_response(Api::NvAPI_GPU_SetCoolerLevels, Vec::new());
+
+    let gpu = PhysicalGpu::enumerate().unwrap().remove(0);
+    assert_eq!(gpu.set_cooler_level(1, nvapi::CoolerPolicy::Manual, 150), Ok(()));
+    let call = mock::last_call(Api::NvAPI_GPU_SetCoolerLevels).unwrap();
+    assert_eq!(call[1], 1u32.to_ne_bytes());
+
+    assert_eq!(gpu.set_cooler_level(2, nvapi::CoolerPolicy::Manual, 50), Err(Status::InvalidArgument));
+}
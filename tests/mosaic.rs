@@ -0,0 +1,245 @@
+extern crate nvapi;
+
+use nvapi::{Axis, Mosaic, MosaicTopo, MosaicTopoValidity};
+use nvapi::sys::NV_RECT;
+
+#[test]
+fn mosaic_grid_topo_displays_len_is_clamped() {
+    let mut grid = nvapi::sys::mosaic::NV_MOSAIC_GRID_TOPO_V1::zeroed();
+    assert!(grid.displays().is_empty());
+    grid.displayCount = u32::MAX;
+    assert_eq!(grid.displays().len(), nvapi::sys::mosaic::NV_MOSAIC_MAX_DISPLAYS);
+
+    let mut grid = nvapi::sys::mosaic::NV_MOSAIC_GRID_TOPO_V2::zeroed();
+    grid.displayCount = 2;
+    assert_eq!(grid.displays().len(), 2);
+    grid.displayCount = u32::MAX;
+    assert_eq!(grid.displays().len(), nvapi::sys::mosaic::NV_MOSAIC_MAX_DISPLAYS);
+}
+
+#[test]
+#[ignore = "performs a mode set for every overlap value"]
+fn mosaic_sweep_overlap() {
+    nvapi::initialize().unwrap();
+    let (brief, settings, _, _) = Mosaic::get_current_topology().unwrap();
+    let (limits, _) = Mosaic::get_overlap_limits(&brief, &settings).unwrap();
+    let step = ((limits.max - limits.min) / 2).max(1) as u32;
+
+    let sweep = Mosaic::sweep_overlap(&brief, &settings, Axis::X, step).unwrap();
+    assert!(!sweep.is_empty());
+    for (overlap, viewports) in sweep {
+        assert!(overlap >= limits.min && overlap <= limits.max);
+        assert!(viewports.iter().all(|viewport| !viewport.is_empty()));
+        println!("{}: {:?}", overlap, viewports);
+    }
+}
+
+#[test]
+#[ignore = "disables and restores the active Mosaic topology"]
+fn mosaic_with_disabled_topology() {
+    nvapi::initialize().unwrap();
+    let (before, _, _, _) = Mosaic::get_current_topology().unwrap();
+
+    let enabled = Mosaic::with_disabled_topology(|| {
+        Mosaic::get_current_topology().unwrap().0.enabled
+    }).unwrap();
+    assert_eq!(enabled, 0);
+
+    let (after, _, _, _) = Mosaic::get_current_topology().unwrap();
+    assert_eq!((after.topo, after.enabled), (before.topo, before.enabled));
+}
+
+#[test]
+fn mosaic_topo_group_topos_len() {
+    let mut group = nvapi::sys::mosaic::NV_MOSAIC_TOPO_GROUP::zeroed();
+    assert!(group.topos().is_empty());
+
+    group.count = nvapi::sys::mosaic::NV_MOSAIC_TOPO_NUM_EYES as u32;
+    assert_eq!(group.topos().len(), group.count as usize);
+
+    group.count = u32::MAX;
+    assert_eq!(group.topos().len(), nvapi::sys::mosaic::NVAPI_MAX_MOSAIC_TOPOS);
+}
+
+#[test]
+fn nv_rect_tuple_round_trip() {
+    let rect = NV_RECT::from((0, 0, 1920, 1080));
+    assert_eq!((rect.left, rect.top, rect.right, rect.bottom), (0, 0, 1920, 1080));
+    assert_eq!(<(u32, u32, u32, u32)>::from(rect), (0, 0, 1920, 1080));
+}
+
+#[test]
+fn nv_rect_size() {
+    let rect = NV_RECT::from((1920, 0, 3840, 1080));
+    assert_eq!((rect.width(), rect.height()), (1920, 1080));
+    assert!(!rect.is_empty());
+    assert_eq!(rect.to_string(), "1920x1080+1920+0");
+    assert!(NV_RECT::zeroed().is_empty());
+}
+
+#[test]
+fn mosaic_topo_display() {
+    let mut brief = nvapi::sys::mosaic::NV_MOSAIC_TOPO_BRIEF::zeroed();
+    brief.topo = nvapi::sys::mosaic::NV_MOSAIC_TOPO_2x2_BASIC;
+    assert_eq!(brief.topo_enum().unwrap().to_string(), "2x2 (Basic)");
+    assert_eq!(MosaicTopo::T1x2_PassiveStereo.to_string(), "1x2 Passive Stereo");
+    assert_eq!(MosaicTopo::None.to_string(), "None");
+}
+
+#[test]
+fn mosaic_build_grid() {
+    let mut settings = nvapi::sys::mosaic::NV_MOSAIC_DISPLAY_SETTING::zeroed();
+    settings.width = 1920;
+    settings.height = 1080;
+    let grid = Mosaic::build_grid(2, 2, &[1, 2, 3, 4], &settings, 0).unwrap();
+    assert_eq!(grid.displayCount, 4);
+    assert_eq!(grid.displays().iter().map(|d| d.displayId).collect::<Vec<_>>(), [1, 2, 3, 4]);
+    assert_eq!(grid.displays[4].version, 0);
+    assert_eq!(grid.displaySettings.width, 1920);
+
+    assert_eq!(Mosaic::build_grid(2, 2, &[1, 2, 3], &settings, 0).err(), Some(nvapi::Status::InvalidArgument));
+    assert_eq!(Mosaic::build_grid(8, 9, &[0; 72], &settings, 0).err(), Some(nvapi::Status::InvalidArgument));
+}
+
+#[test]
+fn mosaic_display_refresh_rates_match() {
+    if nvapi::initialize().is_ok() {
+        let single_grid = Mosaic::enum_display_grids()
+            .map(|grids| grids.iter().filter(|grid| grid.displayCount > 1).count() == 1)
+            .unwrap_or(false);
+        if single_grid {
+            let rates = Mosaic::display_refresh_rates().unwrap();
+            assert!(rates.iter().all(|&(_, rate)| rate == rates[0].1));
+        }
+    }
+}
+
+#[test]
+fn test_mosaic_get_topology_details() {
+    if nvapi::initialize().is_ok() {
+        let (brief, _, _, _) = match Mosaic::get_current_topology() {
+            Ok(current) => current,
+            Err(_) => return,
+        };
+        if let Ok(group) = Mosaic::get_topology_details(&brief) {
+            for details in group.topos() {
+                let validity = details.validity();
+                assert_eq!(details.is_valid(), validity.is_empty());
+                println!("{}x{}: {:?}", details.rowCount, details.colCount, validity.collect::<Vec<_>>());
+            }
+        }
+    }
+}
+
+#[test]
+fn mosaic_topo_validity_flags() {
+    let mut details = nvapi::sys::mosaic::NV_MOSAIC_TOPO_DETAILS::zeroed();
+    assert!(details.is_valid());
+    details.validityMask = nvapi::sys::mosaic::NV_MOSAIC_TOPO_VALIDITY_MISSING_DISPLAY;
+    assert!(!details.is_valid());
+    assert_eq!(details.validity().collect::<Vec<_>>(), [MosaicTopoValidity::MISSING_DISPLAY]);
+}
+
+#[test]
+fn mosaic_set_passive_stereo_rejects_basic_topo() {
+    let mut brief = nvapi::sys::mosaic::NV_MOSAIC_TOPO_BRIEF::zeroed();
+    brief.topo = MosaicTopo::T1x2_Basic.raw();
+    assert!(!brief.is_passive_stereo());
+    let settings = nvapi::sys::mosaic::NV_MOSAIC_DISPLAY_SETTING::zeroed();
+    assert_eq!(Mosaic::set_passive_stereo(&brief, &settings, 0, 0), Err(nvapi::Status::InvalidArgument));
+
+    brief.topo = MosaicTopo::T2x2_PassiveStereo.raw();
+    assert!(brief.is_passive_stereo());
+}
+
+#[test]
+fn enums_default_to_zero_or_marked_variant() {
+    use nvapi::sys::dispcontrol::MonitorCapsType;
+    use nvapi::sys::gsync::DisplaySyncState;
+
+    #[derive(Default)]
+    struct State {
+        topo: MosaicTopo,
+        sync: DisplaySyncState,
+        caps: MonitorCapsType,
+    }
+
+    let state = State::default();
+    assert_eq!(state.topo, MosaicTopo::None);
+    assert_eq!(state.sync, DisplaySyncState::Unsynced);
+    assert_eq!(state.caps, MonitorCapsType::Generic);
+}
+
+#[test]
+fn mosaic_find_display_setting_fractional_rate() {
+    let mut info = nvapi::sys::mosaic::NV_MOSAIC_SUPPORTED_TOPO_INFO::zeroed();
+    let modes = [(3840, 2160, 32, 120, 120_000), (3840, 2160, 32, 119, 119_880), (3840, 2160, 16, 119, 119_880), (3840, 2160, 32, 60, 0), (1920, 1080, 32, 144, 144_000)];
+    for (setting, &(width, height, bpp, freq, rrx1k)) in info.displaySettings.iter_mut().zip(&modes) {
+        setting.width = width;
+        setting.height = height;
+        setting.bpp = bpp;
+        setting.freq = freq;
+        setting.rrx1k = rrx1k;
+    }
+    info.displaySettingsCount = modes.len() as u32;
+
+    let setting = Mosaic::find_display_setting(&info, 3840, 2160, 119.88).unwrap();
+    assert_eq!((setting.rrx1k, setting.bpp), (119_880, 32));
+    assert_eq!(Mosaic::find_display_setting(&info, 3840, 2160, 119.9).unwrap().rrx1k, 120_000);
+    // Settings without rrx1k fall back to whole Hz
+    assert_eq!(Mosaic::find_display_setting(&info, 3840, 2160, 59.94).unwrap().freq, 60);
+    assert!(Mosaic::find_display_setting(&info, 3840, 2160, 144.0).is_none());
+    assert!(Mosaic::find_display_setting(&info, 2560, 1440, 0.0).is_none());
+}
+
+#[test]
+fn mosaic_viewports_cover_every_tile() {
+    if nvapi::initialize().is_ok() {
+        if let Some((display_id, _)) = Mosaic::display_refresh_rates().ok().and_then(|rates| rates.into_iter().next()) {
+            let (viewports, bezel_corrected) = Mosaic::get_current_display_viewports(display_id).unwrap();
+            println!("{} viewports (bezel corrected: {})", viewports.len(), bezel_corrected);
+            assert!(viewports.len() > 1);
+            let single = Mosaic::get_display_viewports_by_resolution_single(display_id, 0, 0).unwrap();
+            assert_eq!(<(u32, u32, u32, u32)>::from(single), viewports[0].into());
+        }
+    }
+}
+
+#[test]
+#[ignore = "re-applies the current Mosaic grids"]
+fn mosaic_apply_current_grids_validated() {
+    nvapi::initialize().unwrap();
+    let mut grids = Mosaic::enum_display_grids().unwrap();
+    let flags = nvapi::sys::mosaic::NV_MOSAIC_SETDISPLAYTOPO_FLAG_CURRENT_GPU_TOPOLOGY;
+    if let Err(status) = Mosaic::apply_grids_validated(&mut grids, flags).unwrap() {
+        let errors: Vec<_> = status.iter().map(|s| s.errorFlags).collect();
+        panic!("current grids failed validation: {:x?}", errors);
+    }
+}
+
+#[test]
+fn mosaic_clamp_overlap() {
+    if nvapi::initialize().is_ok() {
+        if let Ok((brief, settings, x, y)) = Mosaic::get_current_topology() {
+            if let Ok((clamped_x, clamped_y, clamped)) = Mosaic::clamp_overlap(&brief, &settings, x, y) {
+                assert!(!clamped);
+                assert_eq!((clamped_x, clamped_y), (x, y));
+            }
+        }
+    }
+}
+
+#[test]
+fn mosaic_grid_flags() {
+    use nvapi::MosaicGridFlags;
+
+    let mut grid = nvapi::sys::mosaic::NV_MOSAIC_GRID_TOPO::zeroed();
+    grid.flags = nvapi::sys::mosaic::NV_MOSAIC_GRID_TOPO_FLAG_BASE_MOSAIC | 1 << 31;
+    assert_eq!(grid.flags().collect::<Vec<_>>(), [MosaicGridFlags::BASE_MOSAIC]);
+
+    grid.set_flags(MosaicGridFlags::APPLY_WITH_BEZEL_CORRECT | MosaicGridFlags::PIXEL_SHIFT);
+    assert_eq!(grid.flags().iter().collect::<Vec<_>>(), [MosaicGridFlags::APPLY_WITH_BEZEL_CORRECT, MosaicGridFlags::PIXEL_SHIFT]);
+    assert!(!grid.has_flag(nvapi::sys::mosaic::NV_MOSAIC_GRID_TOPO_FLAG_BASE_MOSAIC));
+    // Unknown bits are kept
+    assert_eq!(grid.flags & 1 << 31, 1 << 31);
+}
@@ -0,0 +1,27 @@
+extern crate nvapi;
+
+use nvapi::{PerformanceDecreaseReason, UtilizationDomains};
+
+#[test]
+fn utilization_domains_display() {
+    let utilizations = UtilizationDomains {
+        gpu: Some(42),
+        frame_buffer: Some(10),
+        video_engine: Some(0),
+        bus: Some(3),
+    };
+    assert_eq!(utilizations.to_string(), "GPU 42%, FB 10%, VID 0%, BUS 3%");
+
+    let utilizations = UtilizationDomains { bus: None, ..utilizations };
+    assert_eq!(utilizations.to_string(), "GPU 42%, FB 10%, VID 0%");
+}
+
+#[test]
+fn performance_decrease_reasons() {
+    let reasons = PerformanceDecreaseReason::THERMAL_PROTECTION | PerformanceDecreaseReason::POWER_CONTROL;
+    assert_eq!(reasons.iter().count(), 2);
+    assert_eq!(format!("Throttling: {}", reasons), "Throttling: Thermal, Power Limit");
+
+    assert_eq!(PerformanceDecreaseReason::empty().iter().count(), 0);
+    assert_eq!(PerformanceDecreaseReason::empty().to_string(), "None");
+}
@@ -0,0 +1,10 @@
+extern crate nvapi;
+
+#[test]
+#[ignore = "re-applies the display, Mosaic and GSync configuration of the system"]
+fn apply_captured_scene() {
+    nvapi::initialize().unwrap();
+
+    let scene = nvapi::capture_scene().unwrap();
+    nvapi::apply_scene(&scene).unwrap();
+}
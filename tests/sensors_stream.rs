@@ -0,0 +1,20 @@
+#![cfg(feature = "tokio")]
+
+extern crate nvapi;
+
+use std::time::Duration;
+use tokio_stream::StreamExt;
+
+#[tokio::test]
+async fn physicalgpu_sensors_stream() {
+    if nvapi::initialize().is_ok() {
+        if let Ok(gpus) = nvapi::PhysicalGpu::enumerate() {
+            for gpu in gpus {
+                let mut sensors = Box::pin(gpu.sensors_stream(Duration::from_millis(50)).take(2));
+                while let Some(sensors) = sensors.next().await {
+                    println!("{:?}", sensors);
+                }
+            }
+        }
+    }
+}
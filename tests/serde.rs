@@ -0,0 +1,48 @@
+#![cfg(feature = "serde")]
+
+extern crate nvapi;
+extern crate serde_json;
+
+use nvapi::sys::{gpu::thermal, gsync, mosaic, handles};
+
+fn round_trip<T: serde::Serialize + serde::de::DeserializeOwned>(value: &T) {
+    let json = serde_json::to_string(value).unwrap();
+    let value: T = serde_json::from_str(&json).unwrap();
+    assert_eq!(serde_json::to_string(&value).unwrap(), json);
+}
+
+#[test]
+fn serde_mosaic_grid_topo() {
+    let mut grid = mosaic::NV_MOSAIC_GRID_TOPO::zeroed();
+    grid.version = mosaic::NV_MOSAIC_GRID_TOPO_VER;
+    grid.rows = 1;
+    grid.columns = 2;
+    grid.displayCount = 2;
+    grid.displays[0].displayId = 0x80061082;
+    grid.displays[1].displayId = 0x80061083;
+    grid.displaySettings.width = 1920;
+    round_trip(&grid);
+}
+
+#[test]
+fn serde_gsync_status() {
+    let mut status = gsync::NV_GSYNC_STATUS::zeroed();
+    status.version = gsync::NV_GSYNC_STATUS_VER;
+    status.bIsSynced = 1;
+    round_trip(&status);
+}
+
+#[test]
+fn serde_thermal_settings() {
+    let mut settings = thermal::NV_GPU_THERMAL_SETTINGS::zeroed();
+    settings.version = thermal::NV_GPU_THERMAL_SETTINGS_VER;
+    settings.count = 1;
+    settings.sensor[0].currentTemp = 45;
+    round_trip(&settings);
+}
+
+#[test]
+fn serde_handle_as_address() {
+    let handle = handles::NvPhysicalGpuHandle::default();
+    assert_eq!(serde_json::to_string(&handle).unwrap(), "0");
+}
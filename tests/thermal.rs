@@ -0,0 +1,28 @@
+extern crate nvapi;
+
+use nvapi::{Celsius, ThermalHeadroom, ThermalSensor, ThermalTarget};
+
+#[test]
+fn thermal_sensor_display() {
+    let sensor = ThermalSensor {
+        target: ThermalTarget::Gpu,
+        current: Celsius(45),
+    };
+    assert_eq!(sensor.to_string(), "Core: 45 \u{b0}C");
+    assert!(Celsius(45) > Celsius::from(40));
+    assert_eq!(Celsius(100).fahrenheit(), 212.0);
+}
+
+#[test]
+fn thermal_headroom_display() {
+    let headroom = ThermalHeadroom {
+        current: Celsius(71),
+        limit: Celsius(83),
+        throttling: false,
+    };
+    assert_eq!(headroom.margin(), Celsius(12));
+    assert_eq!(headroom.to_string(), "12 \u{b0}C headroom, not throttling");
+
+    let hot = ThermalHeadroom { current: Celsius(85), throttling: true, ..headroom };
+    assert_eq!(hot.to_string(), "-2 \u{b0}C headroom, throttling");
+}
@@ -0,0 +1,121 @@
+extern crate nvapi;
+
+use std::os::raw::c_char;
+use nvapi::sys::short_string_to_string;
+
+#[test]
+fn short_string_stops_at_nul() {
+    let mut buf = nvapi::sys::types::short_string();
+    for (c, &b) in buf.iter_mut().zip(b"GeForce\0garbage") {
+        *c = b as c_char;
+    }
+    assert_eq!(short_string_to_string(&buf), "GeForce");
+
+    let unterminated = [b'G' as c_char; 4];
+    assert_eq!(short_string_to_string(&unterminated), "GGGG");
+}
+
+#[test]
+fn version_fallback_stops_at_first_compatible_version() {
+    let mut tried = Vec::new();
+    let res = nvapi::sys::with_version_fallback(&[3, 2, 1], |version| {
+        tried.push(version);
+        match version {
+            3 => Err(nvapi::Status::IncompatibleStructVersion),
+            2 => Err(nvapi::Status::NotSupported),
+            _ => Ok(version),
+        }
+    });
+    assert_eq!(res, Err(nvapi::Status::NotSupported));
+    assert_eq!(tried, [3, 2]);
+
+    assert_eq!(nvapi::sys::with_version_fallback(&[], |_| Ok(())), Err(nvapi::Status::IncompatibleStructVersion));
+}
+
+#[test]
+fn display_id_formats_as_hex() {
+    let id = nvapi::DisplayId::from(0x80061086);
+    assert_eq!(id.to_string(), "0x80061086");
+    assert_eq!(format!("{:?}", nvapi::DisplayId(0x100)), "0x00000100");
+    assert_eq!(u32::from(id), 0x80061086);
+}
+
+#[test]
+fn driver_version_parse_and_order() {
+    let version = nvapi::DriverVersion::from_raw(56029);
+    assert_eq!(version, nvapi::DriverVersion::new(560, 29));
+    assert_eq!(version.to_string(), "560.29");
+    assert_eq!(nvapi::DriverVersion::from_raw(55101).to_string(), "551.01");
+    assert_eq!(version.raw(), 56029);
+
+    assert!(nvapi::DriverVersion::new(551, 86) < nvapi::DriverVersion::new(552, 0));
+    assert!(version > nvapi::DriverVersion::new(560, 9));
+}
+
+#[test]
+fn millivolts_from_microvolts() {
+    let core = nvapi::Microvolts(1_050_000);
+    assert_eq!(nvapi::Millivolts::from(core).to_string(), "1050 mV");
+    assert_eq!(nvapi::Millivolts::from(nvapi::Microvolts(1_049_600)), nvapi::Millivolts(1050));
+    assert_eq!(nvapi::Microvolts::from(nvapi::Millivolts(900)), nvapi::Microvolts(900_000));
+}
+
+#[test]
+fn status_displays_message() {
+    let status = nvapi::Status::IncompatibleStructVersion;
+    assert_eq!(status.to_string(), "An argument's structure version is not supported (IncompatibleStructVersion)");
+
+    let err: Box<dyn std::error::Error> = Box::new(nvapi::Status::NvidiaDeviceNotFound);
+    assert!(err.to_string().starts_with("No NVIDIA display driver"));
+}
+
+#[test]
+fn status_message_for_raw_codes() {
+    assert_eq!(nvapi::sys::status::status_message(-9), nvapi::Status::IncompatibleStructVersion.message());
+    // Without a driver, unrecognized codes still get a description
+    assert!(!nvapi::sys::status::status_message(-12345).is_empty());
+}
+
+#[test]
+fn status_result_keeps_unknown_codes() {
+    assert_eq!(nvapi::sys::status_result(-104), Err(nvapi::Status::NotSupported));
+
+    let err = nvapi::sys::status_result(-12345).unwrap_err();
+    assert_eq!(err, nvapi::Status::Unknown(-12345));
+    assert_eq!(err.raw(), -12345);
+    assert_eq!(nvapi::Status::from_raw(-12345).ok(), None);
+    assert!(err.to_string().ends_with("(Unknown(-12345))"));
+    assert!(!nvapi::Status::values().any(|status| status == err));
+}
+
+#[test]
+fn watts_display() {
+    assert_eq!(nvapi::Watts(210.4).to_string(), "210 W");
+    assert_eq!(format!("{:.1}", nvapi::Watts(87.25)), "87.2 W");
+}
+
+#[test]
+fn system_info_display() {
+    let info = nvapi::SystemInfo {
+        driver: nvapi::DriverVersion::new(560, 94),
+        branch: "r560_94".into(),
+        interface: "NVidia Complete Version 1.10".into(),
+    };
+    assert_eq!(info.to_string(), "Driver 560.94 (r560_94), NVidia Complete Version 1.10");
+}
+
+#[test]
+fn initialize_without_driver_reports_library_not_found() {
+    if !nvapi::is_available() {
+        assert_eq!(nvapi::initialize(), Err(nvapi::Status::LibraryNotFound));
+        assert_eq!(nvapi::PhysicalGpu::enumerate().map(|gpus| gpus.len()), Err(nvapi::Status::LibraryNotFound));
+    }
+}
+
+#[test]
+fn range_clamp_value() {
+    let range = nvapi::Range { min: -128, max: 64 };
+    assert_eq!(range.clamp_value(100), 64);
+    assert_eq!(range.clamp_value(-200), -128);
+    assert_eq!(range.clamp_value(0), 0);
+}